@@ -666,6 +666,9 @@ pub fn set(&self, features: &mut Features, span: Span) {
     /// Allows qualified paths in struct expressions, struct patterns and tuple struct patterns.
     (active, more_qualified_paths, "1.54.0", Some(80080), None),
 
+    /// Allows coherence to rely on negative impls, e.g. `impl !Error for &str`.
+    (active, with_negative_coherence, "1.55.0", None, None),
+
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates
     // -------------------------------------------------------------------------
@@ -195,6 +195,12 @@ pub fn extract<'a, F>(check_name: F, attrs: &'a [ast::Attribute]) -> Option<(Sym
     F64,                     sym::f64,                 f64_impl,                   Target::Impl;
     F32Runtime,              sym::f32_runtime,         f32_runtime_impl,           Target::Impl;
     F64Runtime,              sym::f64_runtime,         f64_runtime_impl,           Target::Impl;
+    DynErrorAlloc,           sym::dyn_error_alloc,     dyn_error_alloc_impl,       Target::Impl;
+    DynErrorSendAlloc,       sym::dyn_error_send_alloc, dyn_error_send_alloc_impl, Target::Impl;
+    DynErrorSendSyncAlloc,   sym::dyn_error_send_sync_alloc, dyn_error_send_sync_alloc_impl, Target::Impl;
+    DynErrorStd,             sym::dyn_error_std,       dyn_error_std_impl,         Target::Impl;
+    DynErrorSendStd,         sym::dyn_error_send_std,  dyn_error_send_std_impl,    Target::Impl;
+    DynErrorSendSyncStd,     sym::dyn_error_send_sync_std, dyn_error_send_sync_std_impl, Target::Impl;
 
     Sized,                   sym::sized,               sized_trait,                Target::Trait;
     Unsize,                  sym::unsize,              unsize_trait,               Target::Trait;
@@ -501,6 +501,12 @@
         dropck_eyepatch,
         dropck_parametricity,
         dylib,
+        dyn_error_alloc,
+        dyn_error_send_alloc,
+        dyn_error_send_std,
+        dyn_error_send_sync_alloc,
+        dyn_error_send_sync_std,
+        dyn_error_std,
         dyn_metadata,
         dyn_trait,
         edition_macro_pats,
@@ -1337,6 +1343,7 @@
         width,
         windows,
         windows_subsystem,
+        with_negative_coherence,
         wrapping_add,
         wrapping_mul,
         wrapping_sub,
@@ -7,7 +7,9 @@
 use crate::infer::{CombinedSnapshot, InferOk, TyCtxtInferExt};
 use crate::traits::select::IntercrateAmbiguityCause;
 use crate::traits::SkipLeakCheck;
-use crate::traits::{self, Normalized, Obligation, ObligationCause, SelectionContext};
+use crate::traits::{
+    self, Normalized, Obligation, ObligationCause, PredicateObligation, SelectionContext,
+};
 use rustc_hir::def_id::{DefId, LOCAL_CRATE};
 use rustc_middle::ty::fold::TypeFoldable;
 use rustc_middle::ty::subst::Subst;
@@ -186,6 +188,7 @@ fn overlap_within_probe(
 
     // Are any of the obligations unsatisfiable? If so, no overlap.
     let infcx = selcx.infcx();
+    let negative_coherence = infcx.tcx.features().with_negative_coherence;
     let opt_failing_obligation = a_impl_header
         .predicates
         .iter()
@@ -199,7 +202,10 @@ fn overlap_within_probe(
             predicate: p,
         })
         .chain(obligations)
-        .find(|o| !selcx.predicate_may_hold_fatal(o));
+        .find(|o| {
+            !selcx.predicate_may_hold_fatal(o)
+                || (negative_coherence && negative_impl_exists(selcx, o))
+        });
     // FIXME: the call to `selcx.predicate_may_hold_fatal` above should be ported
     // to the canonical trait query form, `infcx.predicate_may_hold`, once
     // the new system supports intercrate mode (which coherence needs).
@@ -226,6 +232,46 @@ fn overlap_within_probe(
     Some(OverlapResult { impl_header, intercrate_ambiguity_causes, involves_placeholder })
 }
 
+/// Is `o` covered by a negative impl, e.g. `impl !Error for &str`? Such an
+/// impl is a promise by the crate defining the trait that the trait will
+/// never be implemented for those types, so coherence may rely on it even
+/// where the trait-ref is otherwise not knowable.
+///
+/// Only obligations without type inference variables are considered, which
+/// keeps us from committing to any one instantiation of the impls' generics.
+fn negative_impl_exists<'cx, 'tcx>(
+    selcx: &SelectionContext<'cx, 'tcx>,
+    o: &PredicateObligation<'tcx>,
+) -> bool {
+    let infcx = selcx.infcx();
+    let tcx = infcx.tcx;
+
+    let trait_ref = match o.predicate.kind().no_bound_vars() {
+        Some(ty::PredicateKind::Trait(predicate, _)) => predicate.trait_ref,
+        _ => return false,
+    };
+    if trait_ref.has_infer_types_or_consts() {
+        return false;
+    }
+
+    let mut found = false;
+    tcx.for_each_relevant_impl(trait_ref.def_id, trait_ref.self_ty(), |impl_def_id| {
+        if found
+            || tcx.impl_polarity(impl_def_id) != ty::ImplPolarity::Negative
+            || !tcx.predicates_of(impl_def_id).predicates.is_empty()
+        {
+            return;
+        }
+
+        found = infcx.probe(|_| {
+            let impl_substs = infcx.fresh_substs_for_item(DUMMY_SP, impl_def_id);
+            let impl_trait_ref = tcx.impl_trait_ref(impl_def_id).unwrap().subst(tcx, impl_substs);
+            infcx.at(&o.cause, o.param_env).eq(impl_trait_ref, trait_ref).is_ok()
+        });
+    });
+    found
+}
+
 pub fn trait_ref_is_knowable<'tcx>(
     tcx: TyCtxt<'tcx>,
     trait_ref: ty::TraitRef<'tcx>,
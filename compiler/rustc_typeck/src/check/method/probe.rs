@@ -641,6 +641,17 @@ fn assemble_probe(&mut self, self_ty: &Canonical<'tcx, QueryResponse<'tcx, Ty<'t
 
                     self.assemble_inherent_candidates_from_object(generalized_self_ty);
                     self.assemble_inherent_impl_candidates_for_type(p.def_id());
+
+                    for lang_def_id in [
+                        lang_items.dyn_error_alloc_impl(),
+                        lang_items.dyn_error_send_alloc_impl(),
+                        lang_items.dyn_error_send_sync_alloc_impl(),
+                        lang_items.dyn_error_std_impl(),
+                        lang_items.dyn_error_send_std_impl(),
+                        lang_items.dyn_error_send_sync_std_impl(),
+                    ] {
+                        self.assemble_inherent_impl_for_primitive(lang_def_id);
+                    }
                 }
             }
             ty::Adt(def, _) => {
@@ -58,7 +58,20 @@ fn visit_item(&mut self, item: &hir::Item<'_>) {
                 self.check_def_id(item, did);
             }
             ty::Dynamic(ref data, ..) if data.principal_def_id().is_some() => {
-                self.check_def_id(item, data.principal_def_id().unwrap());
+                // `core` defines `dyn Error`, but its `Box`-taking methods
+                // have to live in `alloc`, which knows about `Box`, and its
+                // `Backtrace`-returning methods in `std`.
+                let lib_impls = [
+                    lang_items.dyn_error_alloc_impl(),
+                    lang_items.dyn_error_send_alloc_impl(),
+                    lang_items.dyn_error_send_sync_alloc_impl(),
+                    lang_items.dyn_error_std_impl(),
+                    lang_items.dyn_error_send_std_impl(),
+                    lang_items.dyn_error_send_sync_std_impl(),
+                ];
+                if !lib_impls.contains(&Some(item.def_id.to_def_id())) {
+                    self.check_def_id(item, data.principal_def_id().unwrap());
+                }
             }
             ty::Bool => {
                 self.check_primitive_impl(
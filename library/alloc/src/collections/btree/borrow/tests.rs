@@ -17,3 +17,18 @@ fn test_borrow() {
     }
     assert_eq!(data, 42);
 }
+
+#[test]
+fn test_reborrow() {
+    let mut data = 1;
+    let (r, mut dormant_r) = DormantMutRef::new(&mut data);
+    *r += 1;
+    for _ in 0..3 {
+        let r = unsafe { dormant_r.reborrow() };
+        *r *= 2;
+    }
+    assert_eq!(unsafe { *dormant_r.reborrow_shared() }, 16);
+    let r = unsafe { dormant_r.awaken() };
+    *r += 1;
+    assert_eq!(data, 17);
+}
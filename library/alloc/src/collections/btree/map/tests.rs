@@ -3,6 +3,7 @@
 use super::super::testing::rng::DeterministicRng;
 use super::Entry::{Occupied, Vacant};
 use super::*;
+use crate::alloc::Global;
 use crate::boxed::Box;
 use crate::fmt::Debug;
 use crate::rc::Rc;
@@ -119,8 +120,8 @@ fn compact(&mut self)
         K: Ord,
     {
         let iter = mem::take(self).into_iter();
-        let root = BTreeMap::ensure_is_owned(&mut self.root);
-        root.bulk_push(iter, &mut self.length);
+        let root = BTreeMap::ensure_is_owned(&mut self.root, Global);
+        root.bulk_push(iter, &mut self.length, Global);
     }
 }
 
@@ -2173,3 +2174,175 @@ fn test_insert_remove_intertwined_ord_chaos() {
     }
     map.check_invariants();
 }
+
+#[test]
+fn test_new_in() {
+    use std::alloc::System;
+
+    let size = MIN_INSERTS_HEIGHT_2;
+    let mut map = BTreeMap::new_in(System);
+    for i in 0..size {
+        assert_eq!(map.insert(i, i * 10), None);
+    }
+    match map.entry(size) {
+        Vacant(e) => e.insert(size * 10),
+        Occupied(_) => unreachable!(),
+    };
+    let mut right = map.split_off(&(size / 2));
+    assert_eq!(map.len() + right.len(), size + 1);
+    assert!(right.remove(&(size / 2)).is_some());
+    right.drain_filter(|k, _| k % 2 == 0).for_each(drop);
+    assert!(right.keys().all(|k| k % 2 == 1));
+    let left_len = map.len();
+    map.append(&mut right.clone());
+    assert_eq!(map.len(), left_len + right.len());
+    assert!(map.into_iter().all(|(k, v)| v == k * 10));
+}
+
+#[test]
+fn test_cursor() {
+    let map = BTreeMap::from_iter([(1, 'a'), (2, 'b'), (3, 'c')].iter().copied());
+
+    let mut cur = map.lower_bound(Bound::Unbounded);
+    assert_eq!(cur.key(), Some(&1));
+    cur.move_next();
+    assert_eq!(cur.key_value(), Some((&2, &'b')));
+    assert_eq!(cur.peek_next(), Some((&3, &'c')));
+    assert_eq!(cur.peek_prev(), Some((&1, &'a')));
+    cur.move_next();
+    cur.move_next();
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.peek_next(), Some((&1, &'a')));
+    assert_eq!(cur.peek_prev(), Some((&3, &'c')));
+    cur.move_next();
+    assert_eq!(cur.key(), Some(&1));
+    cur.move_prev();
+    assert_eq!(cur.key(), None);
+    cur.move_prev();
+    assert_eq!(cur.value(), Some(&'c'));
+
+    let mut cur = map.upper_bound(Excluded(&1));
+    assert_eq!(cur.key(), None);
+    cur.move_next();
+    assert_eq!(cur.key(), Some(&1));
+    assert_eq!(map.lower_bound(Excluded(&3)).key(), None);
+    assert_eq!(map.lower_bound(Included(&2)).key(), Some(&2));
+    assert_eq!(map.upper_bound(Included(&2)).key(), Some(&2));
+    assert_eq!(map.upper_bound(Unbounded).key(), Some(&3));
+}
+
+#[test]
+fn test_cursor_empty() {
+    let mut map = BTreeMap::<i32, i32>::new();
+    let mut cur = map.lower_bound(Unbounded);
+    assert_eq!(cur.key(), None);
+    cur.move_next();
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.peek_prev(), None);
+
+    let mut cur = map.upper_bound_mut(Unbounded);
+    assert_eq!(cur.remove_current(), None);
+    cur.insert_after(1, 10);
+    assert_eq!(cur.key_value(), None);
+    assert_eq!(cur.peek_next(), Some((&1, &mut 10)));
+    cur.move_next();
+    assert_eq!(cur.remove_current(), Some((1, 10)));
+    assert_eq!(cur.key(), None);
+    map.check();
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_cursor_mut() {
+    let mut map = BTreeMap::from_iter([(1, 'a'), (3, 'c'), (5, 'e')].iter().copied());
+    let mut cur = map.lower_bound_mut(Excluded(&1));
+    assert_eq!(cur.key(), Some(&3));
+    cur.insert_before(2, 'b');
+    assert_eq!(cur.key(), Some(&3));
+    assert_eq!(cur.peek_prev(), Some((&2, &mut 'b')));
+    cur.insert_after(4, 'd');
+    assert_eq!(cur.peek_next(), Some((&4, &mut 'd')));
+    *cur.value_mut().unwrap() = 'C';
+    assert_eq!(cur.as_cursor().key_value(), Some((&3, &'C')));
+    assert_eq!(cur.remove_current(), Some((3, 'C')));
+    assert_eq!(cur.key(), Some(&4));
+    assert_eq!(cur.remove_current_and_move_back(), Some((4, 'd')));
+    assert_eq!(cur.key(), Some(&2));
+    cur.move_next();
+    cur.move_next();
+    assert_eq!(cur.key(), None);
+    cur.insert_before(6, 'f');
+    cur.insert_after(0, 'z');
+    map.check();
+    assert_eq!(
+        map.into_iter().collect::<Vec<_>>(),
+        [(0, 'z'), (1, 'a'), (2, 'b'), (5, 'e'), (6, 'f')]
+    );
+}
+
+#[test]
+fn test_cursor_mut_key() {
+    let mut map = BTreeMap::from_iter([(1, 'a'), (3, 'c'), (5, 'e')].iter().copied());
+    let mut cur = map.lower_bound_mut(Included(&3));
+    unsafe { *cur.key_mut_unchecked().unwrap() = 4 };
+    assert_eq!(cur.key_value_mut(), Some((&4, &mut 'c')));
+    map.check();
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 4, 5]);
+}
+
+#[test]
+fn test_cursor_mut_insert_large() {
+    // Grow the tree from both ends and from the middle, splitting nodes at every level.
+    let size = MIN_INSERTS_HEIGHT_2 * 2;
+    let mut map = BTreeMap::new();
+    let mut cur = map.lower_bound_mut(Unbounded);
+    for i in 0..size {
+        cur.insert_before(i * 2, i);
+    }
+    assert_eq!(cur.key(), None);
+    cur.move_next();
+    for i in 0..size {
+        assert_eq!(cur.key(), Some(&(i * 2)));
+        cur.insert_after(i * 2 + 1, i);
+        cur.move_next();
+        cur.move_next();
+    }
+    assert_eq!(cur.key(), None);
+    map.check();
+    assert_eq!(map.len(), size * 2);
+    assert!(map.keys().copied().eq(0..size * 2));
+}
+
+#[test]
+fn test_cursor_mut_remove_large() {
+    let size = MIN_INSERTS_HEIGHT_2 * 2;
+    let mut map = BTreeMap::from_iter((0..size).map(|i| (i, i)));
+    let mut cur = map.lower_bound_mut(Included(&(size / 3)));
+    while cur.key().is_some() {
+        if cur.key().unwrap() % 2 == 0 {
+            cur.remove_current();
+        } else {
+            cur.move_next();
+        }
+    }
+    cur.move_prev();
+    while cur.remove_current_and_move_back().is_some() {}
+    map.check();
+    assert!(map.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_cursor_mut_insert_after_misordered() {
+    let mut map = BTreeMap::from_iter([(1, 'a'), (3, 'c')].iter().copied());
+    let mut cur = map.lower_bound_mut(Unbounded);
+    cur.insert_after(3, 'x');
+}
+
+#[test]
+#[should_panic]
+fn test_cursor_mut_insert_before_misordered() {
+    let mut map = BTreeMap::from_iter([(1, 'a'), (3, 'c')].iter().copied());
+    let mut cur = map.upper_bound_mut(Unbounded);
+    cur.insert_before(0, 'x');
+}
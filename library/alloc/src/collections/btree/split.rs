@@ -1,5 +1,6 @@
 use super::node::{ForceResult::*, Root};
 use super::search::SearchResult::*;
+use core::alloc::Allocator;
 use core::borrow::Borrow;
 
 impl<K, V> Root<K, V> {
@@ -28,12 +29,12 @@ pub fn calc_split_length(
     /// and if the ordering of `Q` corresponds to that of `K`.
     /// If `self` respects all `BTreeMap` tree invariants, then both
     /// `self` and the returned tree will respect those invariants.
-    pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
+    pub fn split_off<Q: ?Sized + Ord, A: Allocator + Clone>(&mut self, key: &Q, alloc: A) -> Self
     where
         K: Borrow<Q>,
     {
         let left_root = self;
-        let mut right_root = Root::new_pillar(left_root.height());
+        let mut right_root = Root::new_pillar(left_root.height(), alloc.clone());
         let mut left_node = left_root.borrow_mut();
         let mut right_node = right_root.borrow_mut();
 
@@ -56,16 +57,16 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
             }
         }
 
-        left_root.fix_right_border();
-        right_root.fix_left_border();
+        left_root.fix_right_border(alloc.clone());
+        right_root.fix_left_border(alloc);
         right_root
     }
 
     /// Creates a tree consisting of empty nodes.
-    fn new_pillar(height: usize) -> Self {
-        let mut root = Root::new();
+    fn new_pillar<A: Allocator + Clone>(height: usize, alloc: A) -> Self {
+        let mut root = Root::new(alloc.clone());
         for _ in 0..height {
-            root.push_internal_level();
+            root.push_internal_level(alloc.clone());
         }
         root
     }
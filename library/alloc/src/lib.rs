@@ -94,6 +94,8 @@
 #![feature(dispatch_from_dyn)]
 #![feature(core_intrinsics)]
 #![feature(dropck_eyepatch)]
+#![cfg_attr(not(bootstrap), feature(error_generic_member_access))]
+#![cfg_attr(not(bootstrap), feature(error_in_core))]
 #![feature(exact_size_is_empty)]
 #![feature(exclusive_range_pattern)]
 #![feature(extend_one)]
@@ -110,6 +112,7 @@
 #![feature(lang_items)]
 #![feature(layout_for_ptr)]
 #![feature(negative_impls)]
+#![cfg_attr(not(bootstrap), feature(with_negative_coherence))]
 #![feature(never_type)]
 #![feature(nll)]
 #![feature(nonnull_slice_from_raw_parts)]
@@ -172,6 +175,8 @@ mod boxed {
 }
 pub mod borrow;
 pub mod collections;
+#[cfg(all(not(bootstrap), not(test)))]
+mod error;
 pub mod fmt;
 pub mod prelude;
 pub mod raw_vec;
@@ -210,3 +210,14 @@ fn hmm<'a>(val: &'a mut Weak<&'a str>) -> Weak<&'a str> {
     // `val` dropped here while still borrowed
     // borrow might be used here, when `val` is dropped and runs the `Drop` code for type `std::sync::Weak`
 }
+
+#[test]
+fn arc_new_in_system() {
+    use std::alloc::System;
+
+    let five = Arc::new_in(5, System);
+    let weak = Arc::downgrade(&five);
+    assert_eq!(*weak.upgrade().unwrap(), 5);
+    assert_eq!(Arc::try_unwrap(five), Ok(5));
+    assert!(weak.upgrade().is_none());
+}
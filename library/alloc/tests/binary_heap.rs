@@ -487,3 +487,13 @@ fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
         }
     }
 }
+
+#[test]
+fn test_new_in() {
+    use std::alloc::System;
+
+    let mut heap = BinaryHeap::with_capacity_in(4, System);
+    heap.extend([2, 5, 1, 4, 3].iter().copied());
+    assert_eq!(heap.peek(), Some(&5));
+    assert_eq!(heap.into_sorted_vec(), [1, 2, 3, 4, 5]);
+}
@@ -206,3 +206,14 @@ fn hmm<'a>(val: &'a mut Weak<&'a str>) -> Weak<&'a str> {
     // `val` dropped here while still borrowed
     // borrow might be used here, when `val` is dropped and runs the `Drop` code for type `std::rc::Weak`
 }
+
+#[test]
+fn rc_new_in_system() {
+    use std::alloc::System;
+
+    let five = Rc::new_in(5, System);
+    let weak = Rc::downgrade(&five);
+    assert_eq!(*weak.upgrade().unwrap(), 5);
+    assert_eq!(Rc::try_unwrap(five), Ok(5));
+    assert!(weak.upgrade().is_none());
+}
@@ -853,3 +853,18 @@ fn test_str_concat() {
     let s: String = format!("{}{}", a, b);
     assert_eq!(s.as_bytes()[9], 'd' as u8);
 }
+
+#[test]
+fn test_string_new_in() {
+    use std::alloc::System;
+
+    let mut s = String::new_in(System);
+    s.push_str("hello");
+    s.push(' ');
+    s += "world";
+    assert_eq!(&*s, "hello world");
+    let tail = s.split_off(5);
+    assert_eq!(&*s, "hello");
+    assert_eq!(&*tail, " world");
+    assert_eq!(&*s.into_boxed_str(), "hello");
+}
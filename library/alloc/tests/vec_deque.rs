@@ -1753,3 +1753,16 @@ fn test_from_zero_sized_vec() {
     let queue = VecDeque::from(v);
     assert_eq!(queue.len(), 100);
 }
+
+#[test]
+fn test_new_in() {
+    use std::alloc::System;
+
+    let mut d = VecDeque::with_capacity_in(2, System);
+    d.push_back(2);
+    d.push_front(1);
+    d.push_back(3);
+    assert_eq!(d.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(d.drain(..2).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(d.into_iter().collect::<Vec<_>>(), [3]);
+}
@@ -76,6 +76,25 @@ impl<T, const N: usize> IntoIter<T, N> {
         }
     }
 
+    /// Creates an iterator over the elements in a partially-initialized buffer.
+    ///
+    /// # Safety
+    ///
+    /// - The `buffer[initialized]` elements must all be initialized.
+    /// - The range must be canonical, with `initialized.start <= initialized.end`.
+    /// - The range must be in-bounds for the buffer, with `initialized.end <= N`.
+    ///   (Like how indexing `[0][100..100]` fails despite the range being empty.)
+    ///
+    /// Elements outside `initialized` are never touched, so it is the caller's
+    /// responsibility to make sure they are either uninitialized or otherwise
+    /// taken care of.
+    pub(crate) unsafe fn new_unchecked(
+        buffer: [MaybeUninit<T>; N],
+        initialized: Range<usize>,
+    ) -> Self {
+        Self { data: buffer, alive: initialized }
+    }
+
     /// Returns an immutable slice of all elements that have not been yielded
     /// yet.
     #[stable(feature = "array_value_iter", since = "1.51.0")]
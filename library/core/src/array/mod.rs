@@ -530,7 +530,7 @@ pub fn as_mut_slice(&mut self) -> &mut [T] {
 /// Pulls `N` items from `iter` and returns them as an array. If the iterator
 /// yields fewer than `N` items, this function exhibits undefined behavior.
 ///
-/// See [`collect_into_array`] for more information.
+/// See [`iter_next_chunk`] for more information.
 ///
 ///
 /// # Safety
@@ -548,16 +548,16 @@ pub fn as_mut_slice(&mut self) -> &mut [T] {
     debug_assert!(N <= iter.size_hint().1.unwrap_or(usize::MAX));
     debug_assert!(N <= iter.size_hint().0);
 
-    match collect_into_array(iter) {
-        Some(array) => array,
+    match iter_next_chunk(iter) {
+        Ok(array) => array,
         // SAFETY: covered by the function contract.
-        None => unsafe { crate::hint::unreachable_unchecked() },
+        Err(_) => unsafe { crate::hint::unreachable_unchecked() },
     }
 }
 
 /// Pulls `N` items from `iter` and returns them as an array. If the iterator
-/// yields fewer than `N` items, `None` is returned and all already yielded
-/// items are dropped.
+/// yields fewer than `N` items, `Err` is returned containing an iterator over
+/// the already yielded items.
 ///
 /// Since the iterator is passed as a mutable reference and this function calls
 /// `next` at most `N` times, the iterator can still be used afterwards to
@@ -565,13 +565,15 @@ pub fn as_mut_slice(&mut self) -> &mut [T] {
 ///
 /// If `iter.next()` panicks, all items already yielded by the iterator are
 /// dropped.
-fn collect_into_array<I, const N: usize>(iter: &mut I) -> Option<[I::Item; N]>
+pub(crate) fn iter_next_chunk<I, const N: usize>(
+    iter: &mut I,
+) -> Result<[I::Item; N], IntoIter<I::Item, N>>
 where
     I: Iterator,
 {
     if N == 0 {
         // SAFETY: An empty array is always inhabited and has no validity invariants.
-        return unsafe { Some(mem::zeroed()) };
+        return unsafe { Ok(mem::zeroed()) };
     }
 
     struct Guard<T, const N: usize> {
@@ -612,12 +614,17 @@ fn drop(&mut self) {
             // SAFETY: the condition above asserts that all elements are
             // initialized.
             let out = unsafe { MaybeUninit::array_assume_init(array) };
-            return Some(out);
+            return Ok(out);
         }
     }
 
     // This is only reached if the iterator is exhausted before
-    // `guard.initialized` reaches `N`. Also note that `guard` is dropped here,
-    // dropping all already initialized elements.
-    None
+    // `guard.initialized` reaches `N`. Ownership of the already initialized
+    // elements is handed over to the returned iterator, which drops them.
+    let initialized = guard.initialized;
+    mem::forget(guard);
+
+    // SAFETY: exactly the first `initialized` elements have been written
+    // and none of them has been moved out or dropped.
+    Err(unsafe { IntoIter::new_unchecked(array, 0..initialized) })
 }
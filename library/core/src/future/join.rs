@@ -0,0 +1,101 @@
+use crate::fmt;
+use crate::future::Future;
+use crate::mem;
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// Polls two futures concurrently, resolving to a tuple of both outputs once
+/// both have completed.
+///
+/// Each call to `poll` polls whichever of the two futures has not completed
+/// yet. Joining more than two futures is done by nesting `join` calls.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(future_join)]
+/// # async fn run() {
+/// use std::future;
+///
+/// let a = async { 1 };
+/// let b = future::ready("two");
+///
+/// assert_eq!(future::join(a, b).await, (1, "two"));
+/// # }
+/// ```
+#[unstable(feature = "future_join", issue = "none")]
+pub fn join<A: Future, B: Future>(a: A, b: B) -> Join<A, B> {
+    Join { a: MaybeDone::Future(a), b: MaybeDone::Future(b) }
+}
+
+/// A future that polls two futures concurrently and resolves to both
+/// outputs.
+///
+/// This `struct` is created by [`join()`]. See its documentation for more.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[unstable(feature = "future_join", issue = "none")]
+pub struct Join<A: Future, B: Future> {
+    a: MaybeDone<A>,
+    b: MaybeDone<B>,
+}
+
+#[unstable(feature = "future_join", issue = "none")]
+impl<A: Future, B: Future> fmt::Debug for Join<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Join").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "future_join", issue = "none")]
+impl<A: Future, B: Future> Future for Join<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: both futures are structurally pinned; `MaybeDone` only ever
+        // drops them in place.
+        let this = unsafe { self.get_unchecked_mut() };
+        let a_done = unsafe { Pin::new_unchecked(&mut this.a) }.poll_done(cx);
+        let b_done = unsafe { Pin::new_unchecked(&mut this.b) }.poll_done(cx);
+        if a_done && b_done {
+            Poll::Ready((this.a.take_output(), this.b.take_output()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// The state of one of the futures of a [`Join`].
+enum MaybeDone<F: Future> {
+    Future(F),
+    Done(F::Output),
+    Taken,
+}
+
+impl<F: Future> MaybeDone<F> {
+    /// Polls the future if it has not completed yet, returning whether its
+    /// output is available.
+    fn poll_done(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        // SAFETY: the future is never moved; it is dropped in place when it
+        // is replaced by its output.
+        let this = unsafe { self.get_unchecked_mut() };
+        match this {
+            MaybeDone::Future(future) => match unsafe { Pin::new_unchecked(future) }.poll(cx) {
+                Poll::Ready(output) => {
+                    *this = MaybeDone::Done(output);
+                    true
+                }
+                Poll::Pending => false,
+            },
+            MaybeDone::Done(_) => true,
+            MaybeDone::Taken => panic!("`Join` polled after completion"),
+        }
+    }
+
+    /// Takes the output of a completed future.
+    fn take_output(&mut self) -> F::Output {
+        match mem::replace(self, MaybeDone::Taken) {
+            MaybeDone::Done(output) => output,
+            _ => unreachable!(),
+        }
+    }
+}
@@ -11,9 +11,11 @@
 
 mod future;
 mod into_future;
+mod join;
 mod pending;
 mod poll_fn;
 mod ready;
+mod select;
 
 #[stable(feature = "futures_api", since = "1.36.0")]
 pub use self::future::Future;
@@ -29,6 +31,12 @@
 #[unstable(feature = "future_poll_fn", issue = "72302")]
 pub use poll_fn::{poll_fn, PollFn};
 
+#[unstable(feature = "future_join", issue = "none")]
+pub use join::{join, Join};
+
+#[unstable(feature = "future_select", issue = "none")]
+pub use select::{select, Select};
+
 /// This type is needed because:
 ///
 /// a) Generators cannot implement `for<'a, 'b> Generator<&'a mut Context<'b>>`, so we need to pass
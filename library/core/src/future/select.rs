@@ -0,0 +1,71 @@
+use crate::fmt;
+use crate::future::Future;
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// Polls two futures with the same output type concurrently, resolving to
+/// the output of whichever completes first.
+///
+/// The other future is dropped together with the returned [`Select`]. If
+/// both futures are ready on the same poll, the output of `a` is returned:
+/// `a` is always polled before `b`. Selecting between more than two futures
+/// is done by nesting `select` calls.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(future_select)]
+/// # async fn run() {
+/// use std::future;
+///
+/// let a = future::pending();
+/// let b = future::ready(2);
+///
+/// assert_eq!(future::select(a, b).await, 2);
+/// # }
+/// ```
+#[unstable(feature = "future_select", issue = "none")]
+pub fn select<T, A, B>(a: A, b: B) -> Select<A, B>
+where
+    A: Future<Output = T>,
+    B: Future<Output = T>,
+{
+    Select { a, b }
+}
+
+/// A future that polls two futures concurrently and resolves to the output
+/// of the first one to complete.
+///
+/// This `struct` is created by [`select()`]. See its documentation for more.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[unstable(feature = "future_select", issue = "none")]
+pub struct Select<A, B> {
+    a: A,
+    b: B,
+}
+
+#[unstable(feature = "future_select", issue = "none")]
+impl<A, B> fmt::Debug for Select<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Select").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "future_select", issue = "none")]
+impl<T, A, B> Future for Select<A, B>
+where
+    A: Future<Output = T>,
+    B: Future<Output = T>,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        // SAFETY: both futures are structurally pinned and never moved out
+        // of `self`.
+        let this = unsafe { self.get_unchecked_mut() };
+        if let Poll::Ready(output) = unsafe { Pin::new_unchecked(&mut this.a) }.poll(cx) {
+            return Poll::Ready(output);
+        }
+        unsafe { Pin::new_unchecked(&mut this.b) }.poll(cx)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::array;
+use crate::iter::{ExactSizeIterator, FusedIterator, Iterator};
+
+/// An iterator over `N` elements of the iterator at a time.
+///
+/// The chunks do not overlap. If `N` does not divide the length of the
+/// iterator, then the last up to `N-1` elements will be omitted.
+///
+/// This `struct` is created by the [`array_chunks`][Iterator::array_chunks]
+/// method on [`Iterator`]. See its documentation for more.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_array_chunks", issue = "none")]
+pub struct ArrayChunks<I: Iterator, const N: usize> {
+    iter: I,
+    // Set once the underlying iterator ran out in the middle of a chunk (or
+    // exactly at its start). No more chunks are yielded after that.
+    remainder: Option<array::IntoIter<I::Item, N>>,
+}
+
+impl<I, const N: usize> ArrayChunks<I, N>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    pub(in crate::iter) fn new(iter: I) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        Self { iter, remainder: None }
+    }
+
+    /// Returns an iterator over the remaining elements of the original iterator
+    /// that are not going to be returned by this iterator. The returned
+    /// iterator will yield at most `N-1` elements.
+    ///
+    /// Returns `None` if this iterator has not reached the end of the
+    /// original iterator yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(iter_array_chunks)]
+    ///
+    /// let mut chunks = (1..=5).array_chunks::<2>();
+    /// assert_eq!(chunks.next(), Some([1, 2]));
+    /// assert_eq!(chunks.next(), Some([3, 4]));
+    /// assert_eq!(chunks.next(), None);
+    ///
+    /// let mut rem = chunks.into_remainder().unwrap();
+    /// assert_eq!(rem.next(), Some(5));
+    /// assert_eq!(rem.next(), None);
+    /// ```
+    #[unstable(feature = "iter_array_chunks", issue = "none")]
+    #[inline]
+    pub fn into_remainder(self) -> Option<array::IntoIter<I::Item, N>> {
+        self.remainder
+    }
+}
+
+#[unstable(feature = "iter_array_chunks", issue = "none")]
+impl<I, const N: usize> Iterator for ArrayChunks<I, N>
+where
+    I: Iterator,
+{
+    type Item = [I::Item; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remainder.is_some() {
+            return None;
+        }
+
+        match array::iter_next_chunk(&mut self.iter) {
+            Ok(chunk) => Some(chunk),
+            Err(remainder) => {
+                self.remainder = Some(remainder);
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.remainder.is_some() {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.iter.size_hint();
+
+        (lower / N, upper.map(|n| n / N))
+    }
+}
+
+#[unstable(feature = "iter_array_chunks", issue = "none")]
+impl<I, const N: usize> FusedIterator for ArrayChunks<I, N> where I: Iterator {}
+
+#[unstable(feature = "iter_array_chunks", issue = "none")]
+impl<I, const N: usize> ExactSizeIterator for ArrayChunks<I, N> where I: ExactSizeIterator {}
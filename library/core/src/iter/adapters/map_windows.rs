@@ -0,0 +1,107 @@
+use crate::array;
+use crate::fmt;
+use crate::iter::{FusedIterator, Iterator};
+
+/// An iterator over the mapped windows of another iterator.
+///
+/// This `struct` is created by the [`map_windows`][Iterator::map_windows]
+/// method on [`Iterator`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_map_windows", issue = "none")]
+pub struct MapWindows<I: Iterator, F, const N: usize> {
+    // `None` once the underlying iterator has been exhausted, so that it is
+    // never polled again.
+    iter: Option<I>,
+    f: F,
+    // The most recent window, or `None` if the first window has not been
+    // filled yet.
+    window: Option<[I::Item; N]>,
+}
+
+impl<I: Iterator, F, const N: usize> MapWindows<I, F, N> {
+    #[track_caller]
+    pub(in crate::iter) fn new(iter: I, f: F) -> Self {
+        assert!(N != 0, "window size must be non-zero");
+        Self { iter: Some(iter), f, window: None }
+    }
+}
+
+#[unstable(feature = "iter_map_windows", issue = "none")]
+impl<I, F, R, const N: usize> Iterator for MapWindows<I, F, N>
+where
+    I: Iterator,
+    F: FnMut(&[I::Item; N]) -> R,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<R> {
+        let iter = self.iter.as_mut()?;
+        let window = match self.window.take() {
+            // Slide the window by one: the oldest item is rotated to the end
+            // and then dropped when it is overwritten by the new one.
+            Some(mut window) => iter.next().map(|item| {
+                window.rotate_left(1);
+                window[N - 1] = item;
+                window
+            }),
+            None => array::iter_next_chunk(iter).ok(),
+        };
+
+        if window.is_none() {
+            self.iter = None;
+        }
+        self.window = window;
+        self.window.as_ref().map(&mut self.f)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let iter = match &self.iter {
+            Some(iter) => iter,
+            None => return (0, Some(0)),
+        };
+
+        let (lower, upper) = iter.size_hint();
+        if self.window.is_some() {
+            // Every further item completes exactly one more window.
+            (lower, upper)
+        } else {
+            // The first window needs `N` items, every further item completes
+            // one more window.
+            (lower.saturating_sub(N - 1), upper.map(|n| n.saturating_sub(N - 1)))
+        }
+    }
+}
+
+#[unstable(feature = "iter_map_windows", issue = "none")]
+impl<I, F, R, const N: usize> FusedIterator for MapWindows<I, F, N>
+where
+    I: Iterator,
+    F: FnMut(&[I::Item; N]) -> R,
+{
+}
+
+#[unstable(feature = "iter_map_windows", issue = "none")]
+impl<I, F, const N: usize> Clone for MapWindows<I, F, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self { iter: self.iter.clone(), f: self.f.clone(), window: self.window.clone() }
+    }
+}
+
+#[unstable(feature = "iter_map_windows", issue = "none")]
+impl<I, F, const N: usize> fmt::Debug for MapWindows<I, F, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapWindows")
+            .field("iter", &self.iter)
+            .field("window", &self.window)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,6 +1,7 @@
 use crate::iter::{InPlaceIterable, Iterator};
-use crate::ops::{ControlFlow, Try};
+use crate::ops::{ChangeOutputType, ControlFlow, FromResidual, Residual, Try, TryV2};
 
+mod array_chunks;
 mod chain;
 mod cloned;
 mod copied;
@@ -14,6 +15,7 @@
 mod intersperse;
 mod map;
 mod map_while;
+mod map_windows;
 mod peekable;
 mod rev;
 mod scan;
@@ -48,6 +50,12 @@
 #[unstable(feature = "iter_map_while", reason = "recently added", issue = "68537")]
 pub use self::map_while::MapWhile;
 
+#[unstable(feature = "iter_array_chunks", issue = "none")]
+pub use self::array_chunks::ArrayChunks;
+
+#[unstable(feature = "iter_map_windows", issue = "none")]
+pub use self::map_windows::MapWindows;
+
 #[unstable(feature = "trusted_random_access", issue = "none")]
 pub use self::zip::TrustedRandomAccess;
 
@@ -218,3 +226,61 @@ unsafe impl<I, T, E> InPlaceIterable for ResultShunt<'_, I, E> where
     I: Iterator<Item = Result<T, E>> + InPlaceIterable
 {
 }
+
+/// An iterator adapter that produces output as long as the underlying
+/// iterator produces values where `Try::branch` says to `ControlFlow::Continue`.
+///
+/// If a `ControlFlow::Break` is encountered, the iterator stops and the
+/// residual is stored.
+pub(crate) struct GenericShunt<'a, I, R> {
+    iter: I,
+    residual: &'a mut Option<R>,
+}
+
+/// Process the given iterator as if it yielded the item's `Try::Output`
+/// type instead. Any `Try::Residual`s encountered will stop the inner iterator
+/// and be propagated back to the overall result.
+pub(crate) fn try_process<I, T, R, F, U>(iter: I, mut f: F) -> ChangeOutputType<I::Item, U>
+where
+    I: Iterator<Item: TryV2<Output = T, Residual = R>>,
+    for<'a> F: FnMut(GenericShunt<'a, I, R>) -> U,
+    R: Residual<U>,
+{
+    let mut residual = None;
+    let shunt = GenericShunt { iter, residual: &mut residual };
+    let value = f(shunt);
+    match residual {
+        Some(r) => FromResidual::from_residual(r),
+        None => TryV2::from_output(value),
+    }
+}
+
+impl<I, R> Iterator for GenericShunt<'_, I, R>
+where
+    I: Iterator<Item: TryV2<Residual = R>>,
+{
+    type Item = <I::Item as TryV2>::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.residual.is_some() {
+            return None;
+        }
+
+        match self.iter.next()?.branch() {
+            ControlFlow::Continue(output) => Some(output),
+            ControlFlow::Break(residual) => {
+                *self.residual = Some(residual);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.residual.is_some() {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
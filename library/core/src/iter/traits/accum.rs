@@ -1,4 +1,5 @@
 use crate::iter;
+use crate::num::Saturating;
 use crate::num::Wrapping;
 
 /// Trait to represent types that can be created by summing up an iterator.
@@ -87,6 +88,9 @@ fn product<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
         integer_sum_product!(@impls Wrapping(0), Wrapping(1),
                 #[stable(feature = "wrapping_iter_arith", since = "1.14.0")],
                 $(Wrapping<$a>)*);
+        integer_sum_product!(@impls Saturating(0), Saturating(1),
+                #[unstable(feature = "saturating_int_impl", issue = "none")],
+                $(Saturating<$a>)*);
     );
 }
 
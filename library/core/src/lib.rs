@@ -78,6 +78,10 @@
 #![feature(const_float_bits_conv)]
 #![feature(const_int_unchecked_arith)]
 #![feature(const_inherent_unchecked_arith)]
+#![feature(const_ip)]
+#![feature(const_ipv4)]
+#![feature(const_ipv6)]
+#![feature(const_socketaddr)]
 #![feature(const_mut_refs)]
 #![feature(const_refs_to_cell)]
 #![feature(const_panic)]
@@ -117,6 +121,8 @@
 #![feature(fundamental)]
 #![feature(intra_doc_pointers)]
 #![feature(intrinsics)]
+#![feature(int_log)]
+#![feature(isqrt)]
 #![feature(lang_items)]
 #![feature(link_llvm_intrinsics)]
 #![feature(llvm_asm)]
@@ -240,6 +246,9 @@
 pub mod cmp;
 pub mod convert;
 pub mod default;
+#[cfg(not(bootstrap))]
+#[unstable(feature = "error_in_core", issue = "none")]
+pub mod error;
 pub mod marker;
 pub mod ops;
 
@@ -254,12 +263,16 @@
 pub mod iter;
 #[unstable(feature = "once_cell", issue = "74465")]
 pub mod lazy;
+#[unstable(feature = "ip_in_core", issue = "none")]
+pub mod net;
 pub mod option;
 pub mod panic;
 pub mod panicking;
 pub mod pin;
 pub mod raw;
 pub mod result;
+#[unstable(feature = "portable_simd", issue = "none")]
+pub mod simd;
 #[unstable(feature = "async_stream", issue = "79024")]
 pub mod stream;
 pub mod sync;
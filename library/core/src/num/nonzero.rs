@@ -420,6 +420,88 @@ pub const fn checked_next_power_of_two(self) -> Option<$Ty> {
                         None
                     }
                 }
+
+                /// Returns the base 2 logarithm of the number, rounded down.
+                ///
+                /// This is the same operation as
+                #[doc = concat!("[`", stringify!($Int), "::ilog2`],")]
+                /// except that it has no failure cases to worry about
+                /// since this value can never be zero.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// #![feature(int_log)]
+                #[doc = concat!("# use std::num::", stringify!($Ty), ";")]
+                ///
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(7).unwrap().ilog2(), 2);")]
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(8).unwrap().ilog2(), 3);")]
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(9).unwrap().ilog2(), 3);")]
+                /// ```
+                #[unstable(feature = "int_log", issue = "none")]
+                #[rustc_const_unstable(feature = "int_log", issue = "none")]
+                #[must_use = "this returns the result of the operation, \
+                              without modifying the original"]
+                #[inline]
+                pub const fn ilog2(self) -> u32 {
+                    <$Int>::BITS - 1 - self.leading_zeros()
+                }
+
+                /// Returns the base 10 logarithm of the number, rounded down.
+                ///
+                /// This is the same operation as
+                #[doc = concat!("[`", stringify!($Int), "::ilog10`],")]
+                /// except that it has no failure cases to worry about
+                /// since this value can never be zero.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// #![feature(int_log)]
+                #[doc = concat!("# use std::num::", stringify!($Ty), ";")]
+                ///
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(99).unwrap().ilog10(), 1);")]
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(100).unwrap().ilog10(), 2);")]
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(101).unwrap().ilog10(), 2);")]
+                /// ```
+                #[unstable(feature = "int_log", issue = "none")]
+                #[rustc_const_unstable(feature = "int_log", issue = "none")]
+                #[must_use = "this returns the result of the operation, \
+                              without modifying the original"]
+                #[inline]
+                pub const fn ilog10(self) -> u32 {
+                    self.get().ilog10()
+                }
+
+                /// Returns the square root of the number, rounded down.
+                ///
+                /// The square root of a non-zero value is never zero.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// #![feature(isqrt)]
+                #[doc = concat!("# use std::num::", stringify!($Ty), ";")]
+                ///
+                /// # fn main() { test().unwrap(); }
+                /// # fn test() -> Option<()> {
+                #[doc = concat!("let ten = ", stringify!($Ty), "::new(10)?;")]
+                #[doc = concat!("let three = ", stringify!($Ty), "::new(3)?;")]
+                ///
+                /// assert_eq!(ten.isqrt(), three);
+                /// # Some(())
+                /// # }
+                /// ```
+                #[unstable(feature = "isqrt", issue = "none")]
+                #[rustc_const_unstable(feature = "isqrt", issue = "none")]
+                #[must_use = "this returns the result of the operation, \
+                              without modifying the original"]
+                #[inline]
+                pub const fn isqrt(self) -> $Ty {
+                    // SAFETY: The square root of a value of at least one
+                    // is at least one.
+                    unsafe { $Ty::new_unchecked(self.get().isqrt()) }
+                }
             }
         )+
     }
@@ -201,6 +201,11 @@
 #[unstable(feature = "try_trait_transition", reason = "for bootstrap", issue = "none")]
 pub(crate) use self::try_trait::Try as TryV2;
 
+#[unstable(feature = "try_trait_v2_residual", issue = "none")]
+pub use self::try_trait::Residual;
+
+pub(crate) use self::try_trait::ChangeOutputType;
+
 #[unstable(feature = "generator_trait", issue = "43122")]
 pub use self::generator::{Generator, GeneratorState};
 
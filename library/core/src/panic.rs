@@ -68,6 +68,9 @@ pub struct PanicInfo<'a> {
     payload: &'a (dyn Any + Send),
     message: Option<&'a fmt::Arguments<'a>>,
     location: &'a Location<'a>,
+    thread_name: Option<&'a str>,
+    backtrace: Option<&'a (dyn Any + Send + Sync)>,
+    nested: bool,
 }
 
 impl<'a> PanicInfo<'a> {
@@ -83,7 +86,14 @@ pub fn internal_constructor(
         location: &'a Location<'a>,
     ) -> Self {
         struct NoPayload;
-        PanicInfo { location, message, payload: &NoPayload }
+        PanicInfo {
+            location,
+            message,
+            payload: &NoPayload,
+            thread_name: None,
+            backtrace: None,
+            nested: false,
+        }
     }
 
     #[unstable(
@@ -97,6 +107,39 @@ pub fn set_payload(&mut self, info: &'a (dyn Any + Send)) {
         self.payload = info;
     }
 
+    #[unstable(
+        feature = "panic_internals",
+        reason = "internal details of the implementation of the `panic!` and related macros",
+        issue = "none"
+    )]
+    #[doc(hidden)]
+    #[inline]
+    pub fn set_thread_name(&mut self, name: Option<&'a str>) {
+        self.thread_name = name;
+    }
+
+    #[unstable(
+        feature = "panic_internals",
+        reason = "internal details of the implementation of the `panic!` and related macros",
+        issue = "none"
+    )]
+    #[doc(hidden)]
+    #[inline]
+    pub fn set_backtrace(&mut self, backtrace: Option<&'a (dyn Any + Send + Sync)>) {
+        self.backtrace = backtrace;
+    }
+
+    #[unstable(
+        feature = "panic_internals",
+        reason = "internal details of the implementation of the `panic!` and related macros",
+        issue = "none"
+    )]
+    #[doc(hidden)]
+    #[inline]
+    pub fn set_nested(&mut self, nested: bool) {
+        self.nested = nested;
+    }
+
     /// Returns the payload associated with the panic.
     ///
     /// This will commonly, but not always, be a `&'static str` or [`String`].
@@ -161,6 +204,75 @@ pub fn location(&self) -> Option<&Location<'_>> {
         // deal with that case in std::panicking::default_hook and std::panicking::begin_panic_fmt.
         Some(&self.location)
     }
+
+    /// Returns the name of the thread that panicked, if it has one.
+    ///
+    /// This is filled in by the standard library before it calls the panic
+    /// hook. It is always `None` in a `#[panic_handler]`.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// #![feature(panic_info_ext)]
+    /// use std::panic;
+    ///
+    /// panic::set_hook(Box::new(|panic_info| {
+    ///     let name = panic_info.thread_name().unwrap_or("<unnamed>");
+    ///     eprintln!("thread '{}' panicked", name);
+    /// }));
+    ///
+    /// panic!("Normal panic");
+    /// ```
+    #[unstable(feature = "panic_info_ext", issue = "none")]
+    pub fn thread_name(&self) -> Option<&str> {
+        self.thread_name
+    }
+
+    /// Returns the backtrace captured when the panic started, if any.
+    ///
+    /// Like the [payload], this is a type-erased value. With the standard
+    /// library it is a [`std::backtrace::Backtrace`], which the standard
+    /// library captures before calling a custom panic hook if backtraces are
+    /// enabled through the `RUST_BACKTRACE` environment variable, or if the
+    /// panic [is nested]. A custom hook that forwards to the default hook,
+    /// such as one installed with `std::panic::update_hook` calling the
+    /// previous hook, passes the backtrace along, but the default hook prints
+    /// backtraces on its own and doesn't look at it.
+    ///
+    /// [payload]: PanicInfo::payload
+    /// [is nested]: PanicInfo::is_nested
+    /// [`std::backtrace::Backtrace`]: ../../std/backtrace/struct.Backtrace.html
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// #![feature(backtrace, panic_info_ext)]
+    /// use std::backtrace::Backtrace;
+    /// use std::panic;
+    ///
+    /// panic::set_hook(Box::new(|panic_info| {
+    ///     let backtrace = panic_info.backtrace().and_then(|b| b.downcast_ref::<Backtrace>());
+    ///     match backtrace {
+    ///         Some(backtrace) => eprintln!("{}\n{}", panic_info, backtrace),
+    ///         None => eprintln!("{}", panic_info),
+    ///     }
+    /// }));
+    ///
+    /// panic!("Normal panic");
+    /// ```
+    #[unstable(feature = "panic_info_ext", issue = "none")]
+    pub fn backtrace(&self) -> Option<&(dyn Any + Send + Sync)> {
+        self.backtrace
+    }
+
+    /// Returns `true` if the thread was already panicking, that is, if this
+    /// panic happened while unwinding from an earlier one.
+    ///
+    /// A nested panic aborts the process as soon as the panic hook returns.
+    #[unstable(feature = "panic_info_ext", issue = "none")]
+    pub fn is_nested(&self) -> bool {
+        self.nested
+    }
 }
 
 #[stable(feature = "panic_hook_display", since = "1.26.0")]
@@ -0,0 +1,34 @@
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// A type representing a vector lane count.
+///
+/// It is only used in bounds of the form `LaneCount<LANES>: SupportedLaneCount`,
+/// which restrict [`Simd`] and [`Mask`] to the supported numbers of lanes.
+///
+/// [`Simd`]: super::Simd
+/// [`Mask`]: super::Mask
+#[derive(Debug)]
+#[unstable(feature = "portable_simd", issue = "none")]
+pub struct LaneCount<const LANES: usize>;
+
+/// Statically guarantees that a lane count is supported.
+///
+/// This trait is sealed and implemented for the powers of two from 1 to 64.
+#[unstable(feature = "portable_simd", issue = "none")]
+pub trait SupportedLaneCount: Sealed {}
+
+impl<const LANES: usize> Sealed for LaneCount<LANES> {}
+
+macro_rules! supported_lane_count {
+    ($($lanes:literal)*) => {
+        $(
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl SupportedLaneCount for LaneCount<$lanes> {}
+        )*
+    };
+}
+
+supported_lane_count! { 1 2 4 8 16 32 64 }
@@ -0,0 +1,341 @@
+use crate::fmt;
+use crate::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use super::intrinsics;
+use super::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+
+mod sealed {
+    pub trait Sealed {
+        const TRUE: Self;
+        const FALSE: Self;
+    }
+}
+use sealed::Sealed;
+
+/// Marker trait for the types which can be used as the elements of a [`Mask`].
+///
+/// This trait is sealed and implemented for the signed integer types. A mask
+/// lane is stored as an integer with all bits set for `true`, and all bits
+/// clear for `false`.
+#[unstable(feature = "portable_simd", issue = "none")]
+pub unsafe trait MaskElement: SimdElement<Mask = Self> + Sealed + PartialEq {}
+
+macro_rules! impl_mask_element {
+    ($($ty:ty),*) => {
+        $(
+            impl Sealed for $ty {
+                const TRUE: Self = -1;
+                const FALSE: Self = 0;
+            }
+
+            #[unstable(feature = "portable_simd", issue = "none")]
+            unsafe impl MaskElement for $ty {}
+        )*
+    };
+}
+
+impl_mask_element! { i8, i16, i32, i64, isize }
+
+/// A SIMD vector mask of `LANES` boolean lanes, as produced by lane-wise
+/// comparisons of [`Simd`] vectors.
+///
+/// The element type `T` determines the layout of the mask, which matches
+/// vectors whose elements have the same width as `T`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+/// use core::simd::{f32x4, i32x4};
+///
+/// let a = f32x4::from_array([1.0, -2.0, 3.0, -4.0]);
+/// let positive = a.lanes_gt(f32x4::splat(0.0));
+/// assert_eq!(positive.to_array(), [true, false, true, false]);
+///
+/// let clamped = positive.select(a, f32x4::splat(0.0));
+/// assert_eq!(clamped.to_array(), [1.0, 0.0, 3.0, 0.0]);
+/// assert_eq!(positive.to_int(), i32x4::from_array([-1, 0, -1, 0]));
+/// ```
+#[repr(transparent)]
+#[unstable(feature = "portable_simd", issue = "none")]
+pub struct Mask<T, const LANES: usize>(Simd<T, LANES>)
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount;
+
+impl<T, const LANES: usize> Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Constructs a mask with all lanes set to `value`.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn splat(value: bool) -> Self {
+        Self(Simd::splat(if value { T::TRUE } else { T::FALSE }))
+    }
+
+    /// Constructs a mask from an array of booleans.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn from_array(array: [bool; LANES]) -> Self {
+        let mut mask = Self::splat(false);
+        for (lane, value) in array.iter().enumerate() {
+            mask.set(lane, *value);
+        }
+        mask
+    }
+
+    /// Converts the mask to an array of booleans.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn to_array(self) -> [bool; LANES] {
+        let mut array = [false; LANES];
+        for (lane, value) in array.iter_mut().enumerate() {
+            *value = self.test(lane);
+        }
+        array
+    }
+
+    /// Converts a vector of integers to a mask, where 0 represents `false`
+    /// and -1 represents `true`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any lane is neither 0 nor -1.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn from_int(value: Simd<T, LANES>) -> Self {
+        // SAFETY: the comparisons produce all-zero or all-one lanes.
+        let valid = unsafe {
+            let is_false: Simd<T, LANES> = intrinsics::simd_eq(value, Simd::splat(T::FALSE));
+            let is_true: Simd<T, LANES> = intrinsics::simd_eq(value, Simd::splat(T::TRUE));
+            intrinsics::simd_reduce_all(intrinsics::simd_or(is_false, is_true))
+        };
+        assert!(valid, "all lanes of a mask must be 0 or -1");
+        // SAFETY: just checked.
+        unsafe { Self::from_int_unchecked(value) }
+    }
+
+    /// Converts a vector of integers to a mask, where 0 represents `false`
+    /// and -1 represents `true`.
+    ///
+    /// # Safety
+    ///
+    /// All lanes must be either 0 or -1.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub unsafe fn from_int_unchecked(value: Simd<T, LANES>) -> Self {
+        Self(value)
+    }
+
+    /// Converts the mask to a vector of integers, where 0 represents `false`
+    /// and -1 represents `true`.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn to_int(self) -> Simd<T, LANES> {
+        self.0
+    }
+
+    /// Tests the value of the specified lane.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane` is greater than or equal to the number of lanes.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn test(&self, lane: usize) -> bool {
+        self.0[lane] == T::TRUE
+    }
+
+    /// Sets the value of the specified lane.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane` is greater than or equal to the number of lanes.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn set(&mut self, lane: usize, value: bool) {
+        self.0[lane] = if value { T::TRUE } else { T::FALSE };
+    }
+
+    /// Returns `true` if any lane is set, or `false` otherwise.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn any(self) -> bool {
+        // SAFETY: the lanes of a mask are all-zero or all-one.
+        unsafe { intrinsics::simd_reduce_any(self.0) }
+    }
+
+    /// Returns `true` if all lanes are set, or `false` otherwise.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn all(self) -> bool {
+        // SAFETY: the lanes of a mask are all-zero or all-one.
+        unsafe { intrinsics::simd_reduce_all(self.0) }
+    }
+
+    /// Chooses lanes from two vectors: the lanes of `true_values` where the
+    /// mask is set, and the lanes of `false_values` elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// use core::simd::{mask32x4, u32x4};
+    ///
+    /// let a = u32x4::from_array([0, 1, 2, 3]);
+    /// let b = u32x4::from_array([4, 5, 6, 7]);
+    /// let mask = mask32x4::from_array([true, false, false, true]);
+    /// assert_eq!(mask.select(a, b).to_array(), [0, 5, 6, 3]);
+    /// ```
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn select<U>(
+        self,
+        true_values: Simd<U, LANES>,
+        false_values: Simd<U, LANES>,
+    ) -> Simd<U, LANES>
+    where
+        U: SimdElement<Mask = T>,
+    {
+        // SAFETY: the mask has the same number of lanes as the vectors.
+        unsafe { intrinsics::simd_select(self.0, true_values, false_values) }
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Copy for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Clone for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Default for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Creates a mask with all lanes unset.
+    fn default() -> Self {
+        Self::splat(false)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> PartialEq for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> fmt::Debug for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries((0..LANES).map(|lane| self.test(lane))).finish()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<[bool; LANES]> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn from(array: [bool; LANES]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<Mask<T, LANES>> for [bool; LANES]
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn from(mask: Mask<T, LANES>) -> Self {
+        mask.to_array()
+    }
+}
+
+macro_rules! impl_mask_op {
+    ($trait:ident::$fn:ident, $assign:ident::$assign_fn:ident, $intrinsic:ident) => {
+        #[unstable(feature = "portable_simd", issue = "none")]
+        impl<T, const LANES: usize> $trait for Mask<T, LANES>
+        where
+            T: MaskElement,
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                // SAFETY: bitwise operations on all-zero or all-one lanes
+                // produce all-zero or all-one lanes.
+                Self(unsafe { intrinsics::$intrinsic(self.0, rhs.0) })
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "none")]
+        impl<T, const LANES: usize> $assign for Mask<T, LANES>
+        where
+            T: MaskElement,
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = $trait::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_mask_op!(BitAnd::bitand, BitAndAssign::bitand_assign, simd_and);
+impl_mask_op!(BitOr::bitor, BitOrAssign::bitor_assign, simd_or);
+impl_mask_op!(BitXor::bitxor, BitXorAssign::bitxor_assign, simd_xor);
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Not for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        self ^ Self::splat(true)
+    }
+}
+
+macro_rules! mask_aliases {
+    ($($alias:ident = $ty:ty, $lanes:literal;)*) => {
+        $(
+            #[doc = concat!(
+                "A SIMD mask of ", stringify!($lanes), " lanes, for vectors whose lanes are ",
+                "the size of `", stringify!($ty), "`."
+            )]
+            #[unstable(feature = "portable_simd", issue = "none")]
+            #[allow(non_camel_case_types)]
+            pub type $alias = Mask<$ty, $lanes>;
+        )*
+    };
+}
+
+mask_aliases! {
+    mask8x8 = i8, 8; mask8x16 = i8, 16; mask8x32 = i8, 32; mask8x64 = i8, 64;
+    mask16x4 = i16, 4; mask16x8 = i16, 8; mask16x16 = i16, 16; mask16x32 = i16, 32;
+    mask32x2 = i32, 2; mask32x4 = i32, 4; mask32x8 = i32, 8; mask32x16 = i32, 16;
+    mask64x2 = i64, 2; mask64x4 = i64, 4; mask64x8 = i64, 8;
+    masksizex2 = isize, 2; masksizex4 = isize, 4; masksizex8 = isize, 8;
+}
@@ -0,0 +1,43 @@
+//! Portable SIMD module.
+//!
+//! This module offers a portable abstraction for SIMD operations that is not
+//! bound to any particular hardware architecture. Unlike the intrinsics in
+//! [`core::arch`], which expose the instructions of one target, the types in
+//! this module are lowered through the compiler's generic platform intrinsics,
+//! so the same code produces vector instructions on every target which has
+//! them, and a scalar fallback on those which don't.
+//!
+//! The central type is [`Simd<T, LANES>`], a vector of `LANES` elements of the
+//! primitive type `T`, and its companion [`Mask<T, LANES>`], a vector of
+//! booleans produced by lane-wise comparisons. Vectors support lane-wise
+//! arithmetic through the usual operator traits, comparisons, shuffles,
+//! horizontal reductions and gather/scatter memory accesses.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(portable_simd)]
+//! use core::simd::f32x4;
+//!
+//! let a = f32x4::from_array([1.0, 2.0, 3.0, 4.0]);
+//! let b = f32x4::splat(10.0);
+//! assert_eq!((a + b).to_array(), [11.0, 12.0, 13.0, 14.0]);
+//! assert_eq!((a * b).horizontal_sum(), 100.0);
+//! ```
+
+#![unstable(feature = "portable_simd", issue = "none")]
+
+mod intrinsics;
+
+mod cmp;
+mod lane_count;
+mod masks;
+mod ops;
+mod reduction;
+mod swizzle;
+mod vector;
+
+pub use self::lane_count::{LaneCount, SupportedLaneCount};
+pub use self::masks::*;
+pub use self::swizzle::Shuffle;
+pub use self::vector::*;
@@ -0,0 +1,213 @@
+use crate::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign};
+use crate::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+use crate::ops::{Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
+
+use super::intrinsics;
+use super::{LaneCount, Simd, SupportedLaneCount};
+
+/// Implements a binary operator and its assigning variant for vectors of the
+/// given element types. The check is run on both operands before the
+/// intrinsic, and is used to panic in the same cases as the scalar operator.
+macro_rules! impl_binary_op {
+    ($trait:ident::$fn:ident, $assign:ident::$assign_fn:ident, $intrinsic:ident, $check:ident
+     for $($ty:ty),*) => {
+        $(
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<const LANES: usize> $trait for Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                type Output = Self;
+
+                #[inline]
+                #[track_caller]
+                fn $fn(self, rhs: Self) -> Self {
+                    $check!(self, rhs, $ty);
+                    // SAFETY: the operands are vectors of the same primitive
+                    // type, and the check above excludes undefined results.
+                    unsafe { intrinsics::$intrinsic(self, rhs) }
+                }
+            }
+
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<const LANES: usize> $assign for Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                #[inline]
+                #[track_caller]
+                fn $assign_fn(&mut self, rhs: Self) {
+                    *self = $trait::$fn(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! no_check {
+    ($lhs:ident, $rhs:ident, $ty:ty) => {};
+}
+
+macro_rules! check_div {
+    ($lhs:ident, $rhs:ident, $ty:ty) => {
+        if $rhs.lanes_eq(Simd::splat(0)).any() {
+            panic!("attempt to divide by zero");
+        }
+    };
+}
+
+macro_rules! check_div_signed {
+    ($lhs:ident, $rhs:ident, $ty:ty) => {
+        check_div!($lhs, $rhs, $ty);
+        if ($lhs.lanes_eq(Simd::splat(<$ty>::MIN)) & $rhs.lanes_eq(Simd::splat(-1))).any() {
+            panic!("attempt to divide with overflow");
+        }
+    };
+}
+
+macro_rules! check_rem {
+    ($lhs:ident, $rhs:ident, $ty:ty) => {
+        if $rhs.lanes_eq(Simd::splat(0)).any() {
+            panic!("attempt to calculate the remainder with a divisor of zero");
+        }
+    };
+}
+
+macro_rules! check_rem_signed {
+    ($lhs:ident, $rhs:ident, $ty:ty) => {
+        check_rem!($lhs, $rhs, $ty);
+        if ($lhs.lanes_eq(Simd::splat(<$ty>::MIN)) & $rhs.lanes_eq(Simd::splat(-1))).any() {
+            panic!("attempt to calculate the remainder with overflow");
+        }
+    };
+}
+
+// A shift amount is out of range if it has any bit set above the low
+// `log2(BITS)` bits, which also covers negative amounts.
+macro_rules! check_shl {
+    ($lhs:ident, $rhs:ident, $ty:ty) => {
+        if ($rhs & Simd::splat(!(<$ty>::BITS as $ty - 1))).lanes_ne(Simd::splat(0)).any() {
+            panic!("attempt to shift left with overflow");
+        }
+    };
+}
+
+macro_rules! check_shr {
+    ($lhs:ident, $rhs:ident, $ty:ty) => {
+        if ($rhs & Simd::splat(!(<$ty>::BITS as $ty - 1))).lanes_ne(Simd::splat(0)).any() {
+            panic!("attempt to shift right with overflow");
+        }
+    };
+}
+
+impl_binary_op! {
+    Add::add, AddAssign::add_assign, simd_add, no_check
+    for u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64
+}
+impl_binary_op! {
+    Sub::sub, SubAssign::sub_assign, simd_sub, no_check
+    for u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64
+}
+impl_binary_op! {
+    Mul::mul, MulAssign::mul_assign, simd_mul, no_check
+    for u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64
+}
+impl_binary_op! { Div::div, DivAssign::div_assign, simd_div, no_check for f32, f64 }
+impl_binary_op! {
+    Div::div, DivAssign::div_assign, simd_div, check_div for u8, u16, u32, u64, usize
+}
+impl_binary_op! {
+    Div::div, DivAssign::div_assign, simd_div, check_div_signed for i8, i16, i32, i64, isize
+}
+impl_binary_op! { Rem::rem, RemAssign::rem_assign, simd_rem, no_check for f32, f64 }
+impl_binary_op! {
+    Rem::rem, RemAssign::rem_assign, simd_rem, check_rem for u8, u16, u32, u64, usize
+}
+impl_binary_op! {
+    Rem::rem, RemAssign::rem_assign, simd_rem, check_rem_signed for i8, i16, i32, i64, isize
+}
+impl_binary_op! {
+    Shl::shl, ShlAssign::shl_assign, simd_shl, check_shl
+    for u8, u16, u32, u64, usize, i8, i16, i32, i64, isize
+}
+impl_binary_op! {
+    Shr::shr, ShrAssign::shr_assign, simd_shr, check_shr
+    for u8, u16, u32, u64, usize, i8, i16, i32, i64, isize
+}
+impl_binary_op! {
+    BitAnd::bitand, BitAndAssign::bitand_assign, simd_and, no_check
+    for u8, u16, u32, u64, usize, i8, i16, i32, i64, isize
+}
+impl_binary_op! {
+    BitOr::bitor, BitOrAssign::bitor_assign, simd_or, no_check
+    for u8, u16, u32, u64, usize, i8, i16, i32, i64, isize
+}
+impl_binary_op! {
+    BitXor::bitxor, BitXorAssign::bitxor_assign, simd_xor, no_check
+    for u8, u16, u32, u64, usize, i8, i16, i32, i64, isize
+}
+
+macro_rules! impl_neg {
+    ($($ty:ty),*) => {
+        $(
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<const LANES: usize> Neg for Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn neg(self) -> Self {
+                    // SAFETY: negation is defined for all signed integer and
+                    // floating point lanes, and wraps for integers.
+                    unsafe { intrinsics::simd_neg(self) }
+                }
+            }
+        )*
+    };
+}
+
+impl_neg! { i8, i16, i32, i64, isize, f32, f64 }
+
+macro_rules! impl_not {
+    ($($ty:ty),*) => {
+        $(
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<const LANES: usize> Not for Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn not(self) -> Self {
+                    self ^ Simd::splat(!0)
+                }
+            }
+        )*
+    };
+}
+
+impl_not! { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
+
+macro_rules! impl_float {
+    ($($ty:ty),*) => {
+        $(
+            impl<const LANES: usize> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                /// Computes the absolute value of each lane.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn abs(self) -> Self {
+                    // SAFETY: `self` is a vector of floats.
+                    unsafe { intrinsics::simd_fabs(self) }
+                }
+            }
+        )*
+    };
+}
+
+impl_float! { f32, f64 }
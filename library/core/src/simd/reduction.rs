@@ -0,0 +1,133 @@
+use super::intrinsics;
+use super::{LaneCount, Simd, SupportedLaneCount};
+
+macro_rules! impl_integer_reductions {
+    ($($ty:ty),*) => {
+        $(
+            impl<const LANES: usize> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                /// Horizontal wrapping add. Returns the sum of the lanes of the
+                /// vector, with wrapping addition.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn horizontal_sum(self) -> $ty {
+                    // SAFETY: `self` is an integer vector.
+                    unsafe { intrinsics::simd_reduce_add_ordered(self, 0) }
+                }
+
+                /// Horizontal wrapping multiply. Returns the product of the
+                /// lanes of the vector, with wrapping multiplication.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn horizontal_product(self) -> $ty {
+                    // SAFETY: `self` is an integer vector.
+                    unsafe { intrinsics::simd_reduce_mul_ordered(self, 1) }
+                }
+
+                /// Horizontal bitwise "and". Returns the cumulative bitwise
+                /// "and" across the lanes of the vector.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn horizontal_and(self) -> $ty {
+                    // SAFETY: `self` is an integer vector.
+                    unsafe { intrinsics::simd_reduce_and(self) }
+                }
+
+                /// Horizontal bitwise "or". Returns the cumulative bitwise "or"
+                /// across the lanes of the vector.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn horizontal_or(self) -> $ty {
+                    // SAFETY: `self` is an integer vector.
+                    unsafe { intrinsics::simd_reduce_or(self) }
+                }
+
+                /// Horizontal bitwise "xor". Returns the cumulative bitwise
+                /// "xor" across the lanes of the vector.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn horizontal_xor(self) -> $ty {
+                    // SAFETY: `self` is an integer vector.
+                    unsafe { intrinsics::simd_reduce_xor(self) }
+                }
+
+                /// Horizontal maximum. Returns the maximum lane in the vector.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn horizontal_max(self) -> $ty {
+                    // SAFETY: `self` is an integer vector.
+                    unsafe { intrinsics::simd_reduce_max(self) }
+                }
+
+                /// Horizontal minimum. Returns the minimum lane in the vector.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn horizontal_min(self) -> $ty {
+                    // SAFETY: `self` is an integer vector.
+                    unsafe { intrinsics::simd_reduce_min(self) }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_reductions! { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
+
+macro_rules! impl_float_reductions {
+    ($($ty:ty),*) => {
+        $(
+            impl<const LANES: usize> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                /// Horizontal add. Returns the sum of the lanes of the vector,
+                /// added in lane order.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn horizontal_sum(self) -> $ty {
+                    // -0.0 is the identity of addition, so that a vector of
+                    // negative zeros sums to -0.0.
+                    // SAFETY: `self` is a float vector.
+                    unsafe { intrinsics::simd_reduce_add_ordered(self, -0.0) }
+                }
+
+                /// Horizontal multiply. Returns the product of the lanes of the
+                /// vector, multiplied in lane order.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn horizontal_product(self) -> $ty {
+                    // SAFETY: `self` is a float vector.
+                    unsafe { intrinsics::simd_reduce_mul_ordered(self, 1.0) }
+                }
+
+                /// Horizontal maximum. Returns the maximum lane in the vector.
+                ///
+                /// Returns values based on equality, so a vector containing
+                /// both `0.` and `-0.` may return either. This function will
+                /// not return `NaN` unless all lanes are `NaN`.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn horizontal_max(self) -> $ty {
+                    // SAFETY: `self` is a float vector.
+                    unsafe { intrinsics::simd_reduce_max(self) }
+                }
+
+                /// Horizontal minimum. Returns the minimum lane in the vector.
+                ///
+                /// Returns values based on equality, so a vector containing
+                /// both `0.` and `-0.` may return either. This function will
+                /// not return `NaN` unless all lanes are `NaN`.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn horizontal_min(self) -> $ty {
+                    // SAFETY: `self` is a float vector.
+                    unsafe { intrinsics::simd_reduce_min(self) }
+                }
+            }
+        )*
+    };
+}
+
+impl_float_reductions! { f32, f64 }
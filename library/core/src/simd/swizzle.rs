@@ -0,0 +1,150 @@
+use super::intrinsics;
+use super::{Simd, SimdElement};
+
+/// A constant lane permutation, used with [`Simd::shuffle`].
+///
+/// The indices select lanes from the concatenation of the two input vectors:
+/// indices below the number of lanes select from the first vector, and the
+/// following indices select from the second one. Out of range indices are a
+/// compile-time error.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+/// use core::simd::{f32x4, Shuffle};
+///
+/// struct LowHalves;
+///
+/// impl Shuffle<4> for LowHalves {
+///     const INDEX: [u32; 4] = [0, 1, 4, 5];
+/// }
+///
+/// let a = f32x4::from_array([1.0, 2.0, 3.0, 4.0]);
+/// let b = f32x4::from_array([5.0, 6.0, 7.0, 8.0]);
+/// assert_eq!(a.shuffle::<LowHalves>(b).to_array(), [1.0, 2.0, 5.0, 6.0]);
+/// ```
+#[unstable(feature = "portable_simd", issue = "none")]
+pub trait Shuffle<const LANES: usize> {
+    /// The index of the input lane for each output lane.
+    const INDEX: [u32; LANES];
+}
+
+struct Reverse;
+
+impl<const LANES: usize> Shuffle<LANES> for Reverse {
+    const INDEX: [u32; LANES] = {
+        let mut index = [0; LANES];
+        let mut i = 0;
+        while i < LANES {
+            index[i] = (LANES - 1 - i) as u32;
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Returns the indices of `LANES` consecutive lanes of the interleaving of
+/// two vectors, starting at `offset`.
+const fn interleave_index<const LANES: usize>(offset: usize) -> [u32; LANES] {
+    let mut index = [0; LANES];
+    let mut i = 0;
+    while i < LANES {
+        let lane = offset + i;
+        index[i] = (lane / 2 + lane % 2 * LANES) as u32;
+        i += 1;
+    }
+    index
+}
+
+/// Returns the indices of every other lane of the concatenation of two
+/// vectors, starting at `offset`.
+const fn deinterleave_index<const LANES: usize>(offset: usize) -> [u32; LANES] {
+    let mut index = [0; LANES];
+    let mut i = 0;
+    while i < LANES {
+        index[i] = (2 * i + offset) as u32;
+        i += 1;
+    }
+    index
+}
+
+struct InterleaveLow;
+struct InterleaveHigh;
+struct Even;
+struct Odd;
+
+impl<const LANES: usize> Shuffle<LANES> for InterleaveLow {
+    const INDEX: [u32; LANES] = interleave_index::<LANES>(0);
+}
+
+impl<const LANES: usize> Shuffle<LANES> for InterleaveHigh {
+    const INDEX: [u32; LANES] = interleave_index::<LANES>(LANES);
+}
+
+impl<const LANES: usize> Shuffle<LANES> for Even {
+    const INDEX: [u32; LANES] = deinterleave_index::<LANES>(0);
+}
+
+impl<const LANES: usize> Shuffle<LANES> for Odd {
+    const INDEX: [u32; LANES] = deinterleave_index::<LANES>(1);
+}
+
+// `simd_shuffle` needs the number of lanes in its name, so the shuffles are
+// implemented for each supported lane count separately.
+macro_rules! impl_shuffle {
+    ($($lanes:literal => $intrinsic:ident),*) => {
+        $(
+            impl<T: SimdElement> Simd<T, $lanes> {
+                /// Permutes the lanes of `self` and `second` according to the
+                /// indices of `S`.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn shuffle<S: Shuffle<$lanes>>(self, second: Self) -> Self {
+                    // SAFETY: the indices are a constant, which the code
+                    // generator checks to be in range.
+                    unsafe { intrinsics::$intrinsic(self, second, S::INDEX) }
+                }
+
+                /// Reverses the order of the lanes in the vector.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn reverse(self) -> Self {
+                    self.shuffle::<Reverse>(self)
+                }
+
+                /// Interleaves the lanes of two vectors, returning the first
+                /// and second half of the result.
+                ///
+                /// For vectors `[a0, a1, ..]` and `[b0, b1, ..]`, the
+                /// interleaved lanes are `[a0, b0, a1, b1, ..]`.
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn interleave(self, other: Self) -> (Self, Self) {
+                    (self.shuffle::<InterleaveLow>(other), self.shuffle::<InterleaveHigh>(other))
+                }
+
+                /// Deinterleaves the lanes of two vectors, the inverse of
+                /// [`interleave`]: returns the even and the odd lanes of the
+                /// concatenation of `self` and `other`.
+                ///
+                /// [`interleave`]: Self::interleave
+                #[inline]
+                #[unstable(feature = "portable_simd", issue = "none")]
+                pub fn deinterleave(self, other: Self) -> (Self, Self) {
+                    (self.shuffle::<Even>(other), self.shuffle::<Odd>(other))
+                }
+            }
+        )*
+    };
+}
+
+impl_shuffle! {
+    1 => simd_shuffle1,
+    2 => simd_shuffle2,
+    4 => simd_shuffle4,
+    8 => simd_shuffle8,
+    16 => simd_shuffle16,
+    32 => simd_shuffle32,
+    64 => simd_shuffle64
+}
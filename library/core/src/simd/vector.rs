@@ -0,0 +1,456 @@
+use crate::fmt;
+use crate::mem;
+use crate::slice::SliceIndex;
+
+use super::intrinsics;
+use super::{LaneCount, Mask, MaskElement, SupportedLaneCount};
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// Marker trait for the types which can be used as the elements of a [`Simd`]
+/// vector.
+///
+/// This trait is sealed and implemented for all primitive integer and
+/// floating point types.
+///
+/// # Safety
+///
+/// The code generator requires the elements of a SIMD vector to be primitive
+/// machine types.
+#[unstable(feature = "portable_simd", issue = "none")]
+pub unsafe trait SimdElement: Sealed + Copy {
+    /// The mask element type corresponding to this element type, which has
+    /// the same width.
+    type Mask: MaskElement;
+}
+
+macro_rules! impl_element {
+    ($($ty:ty => $mask:ty),*) => {
+        $(
+            impl Sealed for $ty {}
+
+            #[unstable(feature = "portable_simd", issue = "none")]
+            unsafe impl SimdElement for $ty {
+                type Mask = $mask;
+            }
+        )*
+    };
+}
+
+impl_element! {
+    u8 => i8, u16 => i16, u32 => i32, u64 => i64, usize => isize,
+    i8 => i8, i16 => i16, i32 => i32, i64 => i64, isize => isize,
+    f32 => i32, f64 => i64
+}
+
+/// A SIMD vector of `LANES` elements of type `T`.
+///
+/// Operations on `Simd` are applied lane-wise, i.e. to each pair of elements
+/// in the same position independently. Integer arithmetic wraps on overflow,
+/// while division, remainder and shifts panic on the same inputs as their
+/// scalar counterparts. Reading and writing individual lanes is possible by
+/// indexing, or through the array returned by [`as_array`].
+///
+/// [`as_array`]: Simd::as_array
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+/// use core::simd::Simd;
+///
+/// let a = Simd::from_array([1u32, 2, 3, 4]);
+/// let b = Simd::splat(2);
+/// let c = a * b + Simd::splat(1);
+/// assert_eq!(c.to_array(), [3, 5, 7, 9]);
+/// assert_eq!(c[2], 7);
+/// ```
+#[repr(simd)]
+#[unstable(feature = "portable_simd", issue = "none")]
+pub struct Simd<T, const LANES: usize>([T; LANES])
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount;
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// The number of lanes in this vector.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub const LANES: usize = LANES;
+
+    /// Returns the number of lanes in this vector.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub const fn lanes(&self) -> usize {
+        LANES
+    }
+
+    /// Constructs a vector with all lanes set to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// use core::simd::u32x4;
+    ///
+    /// assert_eq!(u32x4::splat(8).to_array(), [8, 8, 8, 8]);
+    /// ```
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub const fn splat(value: T) -> Self {
+        Self([value; LANES])
+    }
+
+    /// Constructs a vector from an array.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub const fn from_array(array: [T; LANES]) -> Self {
+        Self(array)
+    }
+
+    /// Converts the vector to an array.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub const fn to_array(self) -> [T; LANES] {
+        self.0
+    }
+
+    /// Returns a reference to the lanes of the vector as an array.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub const fn as_array(&self) -> &[T; LANES] {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the lanes of the vector as an array.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn as_mut_array(&mut self) -> &mut [T; LANES] {
+        &mut self.0
+    }
+
+    /// Constructs a vector from the first `LANES` elements of a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is shorter than the number of lanes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// use core::simd::u32x4;
+    ///
+    /// let source = [1, 2, 3, 4, 5, 6];
+    /// assert_eq!(u32x4::from_slice(&source[2..]).to_array(), [3, 4, 5, 6]);
+    /// ```
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn from_slice(slice: &[T]) -> Self {
+        assert!(slice.len() >= LANES, "slice length must be at least the number of lanes");
+        let mut array = [slice[0]; LANES];
+        array.copy_from_slice(&slice[..LANES]);
+        Self(array)
+    }
+
+    /// Reads from the lanes of `slice` given by `idxs`, using `or` for the
+    /// lanes whose index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// use core::simd::{i32x4, usizex4};
+    ///
+    /// let vec = [10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = usizex4::from_array([9, 3, 0, 5]);
+    /// let alt = i32x4::from_array([-5, -4, -3, -2]);
+    ///
+    /// let result = i32x4::gather_or(&vec, idxs, alt);
+    /// assert_eq!(result.to_array(), [-5, 13, 10, 15]);
+    /// ```
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn gather_or(slice: &[T], idxs: Simd<usize, LANES>, or: Self) -> Self {
+        Self::gather_select(slice, Mask::splat(true), idxs, or)
+    }
+
+    /// Reads from the lanes of `slice` given by `idxs`, using the default
+    /// value for the lanes whose index is out of bounds.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn gather_or_default(slice: &[T], idxs: Simd<usize, LANES>) -> Self
+    where
+        T: Default,
+    {
+        Self::gather_or(slice, idxs, Self::splat(T::default()))
+    }
+
+    /// Reads from the lanes of `slice` given by `idxs` where `enable` is set,
+    /// using `or` for the lanes which are disabled or whose index is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// use core::simd::{i32x4, masksizex4, usizex4};
+    ///
+    /// let vec = [10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = usizex4::from_array([9, 3, 0, 5]);
+    /// let alt = i32x4::from_array([-5, -4, -3, -2]);
+    /// let enable = masksizex4::from_array([true, true, true, false]);
+    ///
+    /// let result = i32x4::gather_select(&vec, enable, idxs, alt);
+    /// assert_eq!(result.to_array(), [-5, 13, 10, -2]);
+    /// ```
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn gather_select(
+        slice: &[T],
+        enable: Mask<isize, LANES>,
+        idxs: Simd<usize, LANES>,
+        or: Self,
+    ) -> Self {
+        let enable = enable & idxs.lanes_lt(Simd::splat(slice.len()));
+        let ptrs = SimdConstPtr::splat(slice.as_ptr()).wrapping_add(idxs);
+        // SAFETY: only the lanes which are enabled and in bounds are read.
+        unsafe { intrinsics::simd_gather(or, ptrs, enable.to_int()) }
+    }
+
+    /// Writes the lanes of the vector to the elements of `slice` given by
+    /// `idxs`, skipping the lanes whose index is out of bounds.
+    ///
+    /// If several lanes have the same index, the lane with the highest
+    /// position is written last and thus wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// use core::simd::{i32x4, usizex4};
+    ///
+    /// let mut vec = [10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = usizex4::from_array([9, 3, 0, 0]);
+    /// let vals = i32x4::from_array([-27, 82, -41, 124]);
+    ///
+    /// vals.scatter(&mut vec, idxs);
+    /// assert_eq!(vec, [124, 11, 12, 82, 14, 15, 16, 17, 18]);
+    /// ```
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn scatter(self, slice: &mut [T], idxs: Simd<usize, LANES>) {
+        self.scatter_select(slice, Mask::splat(true), idxs)
+    }
+
+    /// Writes the lanes of the vector where `enable` is set to the elements
+    /// of `slice` given by `idxs`, skipping the lanes which are disabled or
+    /// whose index is out of bounds.
+    ///
+    /// If several enabled lanes have the same index, the lane with the
+    /// highest position is written last and thus wins.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub fn scatter_select(
+        self,
+        slice: &mut [T],
+        enable: Mask<isize, LANES>,
+        idxs: Simd<usize, LANES>,
+    ) {
+        let enable = enable & idxs.lanes_lt(Simd::splat(slice.len()));
+        let ptrs = SimdMutPtr::splat(slice.as_mut_ptr()).wrapping_add(idxs);
+        // SAFETY: only the lanes which are enabled and in bounds are written,
+        // and the slice is borrowed mutably.
+        unsafe { intrinsics::simd_scatter(self, ptrs, enable.to_int()) }
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Copy for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Clone for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Default for Simd<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::splat(T::default())
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> PartialEq for Simd<T, LANES>
+where
+    T: SimdElement + PartialEq,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.lanes_eq(*other).all()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> fmt::Debug for Simd<T, LANES>
+where
+    T: SimdElement + fmt::Debug,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_array(), f)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<[T; LANES]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn from(array: [T; LANES]) -> Self {
+        Self(array)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<Simd<T, LANES>> for [T; LANES]
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn from(vector: Simd<T, LANES>) -> Self {
+        vector.to_array()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> AsRef<[T]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn as_ref(&self) -> &[T] {
+        self.as_array()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> AsMut<[T]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_array()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<I, T, const LANES: usize> crate::ops::Index<I> for Simd<T, LANES>
+where
+    I: SliceIndex<[T]>,
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_array()[index]
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<I, T, const LANES: usize> crate::ops::IndexMut<I> for Simd<T, LANES>
+where
+    I: SliceIndex<[T]>,
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_array()[index]
+    }
+}
+
+/// A vector of `*const T`, used as the addresses of a gather.
+#[repr(simd)]
+struct SimdConstPtr<T, const LANES: usize>([*const T; LANES]);
+
+/// A vector of `*mut T`, used as the addresses of a scatter.
+#[repr(simd)]
+struct SimdMutPtr<T, const LANES: usize>([*mut T; LANES]);
+
+macro_rules! impl_ptr_vector {
+    ($name:ident, $ptr:ty) => {
+        impl<T, const LANES: usize> Copy for $name<T, LANES> {}
+
+        impl<T, const LANES: usize> Clone for $name<T, LANES> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T, const LANES: usize> $name<T, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            fn splat(ptr: $ptr) -> Self {
+                Self([ptr; LANES])
+            }
+
+            /// Offsets each pointer by the corresponding number of elements,
+            /// like `ptr::wrapping_add`.
+            fn wrapping_add(self, count: Simd<usize, LANES>) -> Self {
+                // SAFETY: pointers and `usize` have the same size, so both
+                // vectors have the same layout.
+                unsafe {
+                    let addrs: Simd<usize, LANES> = mem::transmute_copy(&self);
+                    let addrs = addrs + count * Simd::splat(mem::size_of::<T>());
+                    mem::transmute_copy(&addrs)
+                }
+            }
+        }
+    };
+}
+
+impl_ptr_vector!(SimdConstPtr, *const T);
+impl_ptr_vector!(SimdMutPtr, *mut T);
+
+macro_rules! vector_aliases {
+    ($($alias:ident = $ty:ty, $lanes:literal;)*) => {
+        $(
+            #[doc = concat!(
+                "A SIMD vector of ", stringify!($lanes), " `", stringify!($ty), "` lanes."
+            )]
+            #[unstable(feature = "portable_simd", issue = "none")]
+            #[allow(non_camel_case_types)]
+            pub type $alias = Simd<$ty, $lanes>;
+        )*
+    };
+}
+
+vector_aliases! {
+    u8x8 = u8, 8; u8x16 = u8, 16; u8x32 = u8, 32; u8x64 = u8, 64;
+    u16x4 = u16, 4; u16x8 = u16, 8; u16x16 = u16, 16; u16x32 = u16, 32;
+    u32x2 = u32, 2; u32x4 = u32, 4; u32x8 = u32, 8; u32x16 = u32, 16;
+    u64x2 = u64, 2; u64x4 = u64, 4; u64x8 = u64, 8;
+    usizex2 = usize, 2; usizex4 = usize, 4; usizex8 = usize, 8;
+    i8x8 = i8, 8; i8x16 = i8, 16; i8x32 = i8, 32; i8x64 = i8, 64;
+    i16x4 = i16, 4; i16x8 = i16, 8; i16x16 = i16, 16; i16x32 = i16, 32;
+    i32x2 = i32, 2; i32x4 = i32, 4; i32x8 = i32, 8; i32x16 = i32, 16;
+    i64x2 = i64, 2; i64x4 = i64, 4; i64x8 = i64, 8;
+    isizex2 = isize, 2; isizex4 = isize, 4; isizex8 = isize, 8;
+    f32x2 = f32, 2; f32x4 = f32, 4; f32x8 = f32, 8; f32x16 = f32, 16;
+    f64x2 = f64, 2; f64x4 = f64, 4; f64x8 = f64, 8;
+}
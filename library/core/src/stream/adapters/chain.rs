@@ -0,0 +1,66 @@
+use crate::pin::Pin;
+use crate::stream::Stream;
+use crate::task::{Context, Poll};
+
+/// A stream that yields the items of `a` followed by the items of `b`.
+///
+/// This `struct` is created by the [`chain`] method on [`Stream`]. See its
+/// documentation for more.
+///
+/// [`chain`]: Stream::chain
+#[derive(Clone, Debug)]
+#[must_use = "streams do nothing unless polled"]
+#[unstable(feature = "async_stream", issue = "79024")]
+pub struct Chain<A, B> {
+    // `a` is dropped in place once it is exhausted, so that it is never
+    // polled again after returning `None`.
+    a: Option<A>,
+    b: B,
+}
+
+impl<A, B> Chain<A, B> {
+    pub(in crate::stream) fn new(a: A, b: B) -> Chain<A, B> {
+        Chain { a: Some(a), b }
+    }
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<A, B> Stream for Chain<A, B>
+where
+    A: Stream,
+    B: Stream<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<A::Item>> {
+        // SAFETY: both streams are structurally pinned. `a` is only ever
+        // dropped in place, never moved.
+        let this = unsafe { self.get_unchecked_mut() };
+        if let Some(a) = &mut this.a {
+            match unsafe { Pin::new_unchecked(a) }.poll_next(cx) {
+                Poll::Ready(None) => this.a = None,
+                other => return other,
+            }
+        }
+        unsafe { Pin::new_unchecked(&mut this.b) }.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (b_lower, b_upper) = self.b.size_hint();
+        match &self.a {
+            Some(a) => {
+                let (a_lower, a_upper) = a.size_hint();
+
+                let lower = a_lower.saturating_add(b_lower);
+
+                let upper = match (a_upper, b_upper) {
+                    (Some(x), Some(y)) => x.checked_add(y),
+                    _ => None,
+                };
+
+                (lower, upper)
+            }
+            None => (b_lower, b_upper),
+        }
+    }
+}
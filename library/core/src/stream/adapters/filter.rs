@@ -0,0 +1,62 @@
+use crate::fmt;
+use crate::pin::Pin;
+use crate::stream::Stream;
+use crate::task::{Context, Poll};
+
+/// A stream that filters the elements of `stream` with `predicate`.
+///
+/// This `struct` is created by the [`filter`] method on [`Stream`]. See its
+/// documentation for more.
+///
+/// [`filter`]: Stream::filter
+#[derive(Clone)]
+#[must_use = "streams do nothing unless polled"]
+#[unstable(feature = "async_stream", issue = "79024")]
+pub struct Filter<S, P> {
+    stream: S,
+    predicate: P,
+}
+
+impl<S, P> Filter<S, P> {
+    pub(in crate::stream) fn new(stream: S, predicate: P) -> Filter<S, P> {
+        Filter { stream, predicate }
+    }
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<S: fmt::Debug, P> fmt::Debug for Filter<S, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Filter").field("stream", &self.stream).finish()
+    }
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<S: Stream, P> Stream for Filter<S, P>
+where
+    P: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        // SAFETY: `stream` is structurally pinned and never moved out of
+        // `self`; `predicate` is not pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        let mut stream = unsafe { Pin::new_unchecked(&mut this.stream) };
+        loop {
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if (this.predicate)(&item) {
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.stream.size_hint();
+        (0, upper) // can't know a lower bound, due to the predicate
+    }
+}
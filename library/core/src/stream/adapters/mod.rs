@@ -0,0 +1,8 @@
+mod chain;
+mod filter;
+mod map;
+mod next;
+mod take;
+mod zip;
+
+pub use self::{chain::Chain, filter::Filter, map::Map, next::Next, take::Take, zip::Zip};
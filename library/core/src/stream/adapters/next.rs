@@ -0,0 +1,32 @@
+use crate::future::Future;
+use crate::pin::Pin;
+use crate::stream::Stream;
+use crate::task::{Context, Poll};
+
+/// A future that resolves to the next item of a stream.
+///
+/// This `struct` is created by the [`next`] method on [`Stream`]. See its
+/// documentation for more.
+///
+/// [`next`]: Stream::next
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[unstable(feature = "async_stream", issue = "79024")]
+pub struct Next<'a, S: ?Sized> {
+    stream: &'a mut S,
+}
+
+impl<'a, S: ?Sized> Next<'a, S> {
+    pub(in crate::stream) fn new(stream: &'a mut S) -> Next<'a, S> {
+        Next { stream }
+    }
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<S: ?Sized + Stream + Unpin> Future for Next<'_, S> {
+    type Output = Option<S::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.stream).poll_next(cx)
+    }
+}
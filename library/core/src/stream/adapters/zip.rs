@@ -0,0 +1,72 @@
+use crate::cmp;
+use crate::pin::Pin;
+use crate::stream::Stream;
+use crate::task::{Context, Poll};
+
+/// A stream that yields the items of two streams in pairs.
+///
+/// This `struct` is created by the [`zip`] method on [`Stream`]. See its
+/// documentation for more.
+///
+/// [`zip`]: Stream::zip
+#[derive(Clone, Debug)]
+#[must_use = "streams do nothing unless polled"]
+#[unstable(feature = "async_stream", issue = "79024")]
+pub struct Zip<A: Stream, B> {
+    a: A,
+    b: B,
+    // An item already taken from `a` while `b` was still pending.
+    a_item: Option<A::Item>,
+}
+
+impl<A: Stream, B> Zip<A, B> {
+    pub(in crate::stream) fn new(a: A, b: B) -> Zip<A, B> {
+        Zip { a, b, a_item: None }
+    }
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<A: Stream, B: Stream> Stream for Zip<A, B> {
+    type Item = (A::Item, B::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: `a` and `b` are structurally pinned and never moved out of
+        // `self`; `a_item` is not pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.a_item.is_none() {
+            match unsafe { Pin::new_unchecked(&mut this.a) }.poll_next(cx) {
+                Poll::Ready(Some(item)) => this.a_item = Some(item),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        match unsafe { Pin::new_unchecked(&mut this.b) }.poll_next(cx) {
+            Poll::Ready(Some(b)) => Poll::Ready(this.a_item.take().map(|a| (a, b))),
+            Poll::Ready(None) => {
+                this.a_item = None;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.a_item.is_some() as usize;
+        let (a_lower, a_upper) = self.a.size_hint();
+        let a_lower = a_lower.saturating_add(buffered);
+        let a_upper = a_upper.and_then(|x| x.checked_add(buffered));
+        let (b_lower, b_upper) = self.b.size_hint();
+
+        let lower = cmp::min(a_lower, b_lower);
+
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => Some(cmp::min(x, y)),
+            (Some(x), None) => Some(x),
+            (None, Some(y)) => Some(y),
+            (None, None) => None,
+        };
+
+        (lower, upper)
+    }
+}
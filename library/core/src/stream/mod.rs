@@ -40,8 +40,8 @@
 //! ```
 //!
 //! Unlike `Iterator`, `Stream` makes a distinction between the [`poll_next`]
-//! method which is used when implementing a `Stream`, and a (to-be-implemented)
-//! `next` method which is used when consuming a stream. Consumers of `Stream`
+//! method which is used when implementing a `Stream`, and a [`next`] method
+//! which is used when consuming a stream. Consumers of `Stream`
 //! only need to consider `next`, which when called, returns a future which
 //! yields `Option<Stream::Item>`.
 //!
@@ -58,6 +58,7 @@
 //! them for free.
 //!
 //! [`Poll`]: super::task::Poll
+//! [`next`]: Stream::next
 //! [`poll_next`]: Stream::poll_next
 //!
 //! # Implementing Stream
@@ -122,6 +123,10 @@
 //! warning: unused result that must be used: streams do nothing unless polled
 //! ```
 
+mod adapters;
+mod sources;
 mod stream;
 
+pub use adapters::{Chain, Filter, Map, Next, Take, Zip};
+pub use sources::{from_iter, once, poll_fn, repeat_with, FromIter, Once, PollFn, RepeatWith};
 pub use stream::Stream;
@@ -0,0 +1,16 @@
+mod from_iter;
+mod once;
+mod poll_fn;
+mod repeat_with;
+
+#[unstable(feature = "async_stream", issue = "79024")]
+pub use self::from_iter::{from_iter, FromIter};
+
+#[unstable(feature = "async_stream", issue = "79024")]
+pub use self::once::{once, Once};
+
+#[unstable(feature = "async_stream", issue = "79024")]
+pub use self::poll_fn::{poll_fn, PollFn};
+
+#[unstable(feature = "async_stream", issue = "79024")]
+pub use self::repeat_with::{repeat_with, RepeatWith};
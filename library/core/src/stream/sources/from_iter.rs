@@ -0,0 +1,55 @@
+use crate::pin::Pin;
+use crate::stream::Stream;
+use crate::task::{Context, Poll};
+
+/// Converts an iterator into a stream.
+///
+/// The returned stream is always ready: each call to `poll_next` yields the
+/// next item of the iterator.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_stream)]
+/// # async fn run() {
+/// use std::stream::{self, Stream};
+///
+/// let mut s = stream::from_iter(1..=3);
+///
+/// assert_eq!(s.next().await, Some(1));
+/// assert_eq!(s.next().await, Some(2));
+/// assert_eq!(s.next().await, Some(3));
+/// assert_eq!(s.next().await, None);
+/// # }
+/// ```
+#[unstable(feature = "async_stream", issue = "79024")]
+pub fn from_iter<I: IntoIterator>(iter: I) -> FromIter<I::IntoIter> {
+    FromIter { iter: iter.into_iter() }
+}
+
+/// A stream that was created from an iterator.
+///
+/// This `struct` is created by the [`from_iter()`] function. See its
+/// documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "streams do nothing unless polled"]
+#[unstable(feature = "async_stream", issue = "79024")]
+pub struct FromIter<I> {
+    iter: I,
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<I> Unpin for FromIter<I> {}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<I: Iterator> Stream for FromIter<I> {
+    type Item = I::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,69 @@
+use crate::future::Future;
+use crate::pin::Pin;
+use crate::stream::Stream;
+use crate::task::{Context, Poll};
+
+/// Creates a stream that yields the output of `future` as its single item.
+///
+/// To create a stream yielding a value that is already available, pass
+/// [`future::ready`] as the future.
+///
+/// [`future::ready`]: crate::future::ready
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_stream)]
+/// # async fn run() {
+/// use std::future;
+/// use std::stream::{self, Stream};
+///
+/// let mut s = stream::once(future::ready(17));
+///
+/// assert_eq!(s.next().await, Some(17));
+/// assert_eq!(s.next().await, None);
+/// # }
+/// ```
+#[unstable(feature = "async_stream", issue = "79024")]
+pub fn once<F: Future>(future: F) -> Once<F> {
+    Once { future: Some(future) }
+}
+
+/// A stream that yields the output of a future exactly once.
+///
+/// This `struct` is created by the [`once()`] function. See its
+/// documentation for more.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+#[unstable(feature = "async_stream", issue = "79024")]
+pub struct Once<F> {
+    future: Option<F>,
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<F: Future> Stream for Once<F> {
+    type Item = F::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<F::Output>> {
+        // SAFETY: the future is structurally pinned. It is only ever dropped
+        // in place, never moved.
+        let this = unsafe { self.get_unchecked_mut() };
+        let output = match &mut this.future {
+            Some(future) => match unsafe { Pin::new_unchecked(future) }.poll(cx) {
+                Poll::Ready(output) => output,
+                Poll::Pending => return Poll::Pending,
+            },
+            None => return Poll::Ready(None),
+        };
+        this.future = None;
+        Poll::Ready(Some(output))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.future.is_some() {
+            (1, Some(1))
+        } else {
+            (0, Some(0))
+        }
+    }
+}
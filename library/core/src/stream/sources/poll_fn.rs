@@ -0,0 +1,67 @@
+use crate::fmt;
+use crate::pin::Pin;
+use crate::stream::Stream;
+use crate::task::{Context, Poll};
+
+/// Creates a stream that wraps a function returning [`Poll`]`<`[`Option`]`<T>>`.
+///
+/// Polling the stream delegates to the wrapped function.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_stream)]
+/// # async fn run() {
+/// use std::stream::{self, Stream};
+/// use std::task::Poll;
+///
+/// let mut count = 0;
+/// let mut s = stream::poll_fn(|_cx| {
+///     count += 1;
+///     Poll::Ready(if count <= 2 { Some(count) } else { None })
+/// });
+///
+/// assert_eq!(s.next().await, Some(1));
+/// assert_eq!(s.next().await, Some(2));
+/// assert_eq!(s.next().await, None);
+/// # }
+/// ```
+#[unstable(feature = "async_stream", issue = "79024")]
+pub fn poll_fn<T, F>(f: F) -> PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<Option<T>>,
+{
+    PollFn { f }
+}
+
+/// A stream that wraps a function returning [`Poll`]`<`[`Option`]`<T>>`.
+///
+/// This `struct` is created by the [`poll_fn()`] function. See its
+/// documentation for more.
+#[must_use = "streams do nothing unless polled"]
+#[unstable(feature = "async_stream", issue = "79024")]
+pub struct PollFn<F> {
+    f: F,
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<F> Unpin for PollFn<F> {}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<F> fmt::Debug for PollFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PollFn").finish()
+    }
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<T, F> Stream for PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<Option<T>>,
+{
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        (&mut self.f)(cx)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::fmt;
+use crate::pin::Pin;
+use crate::stream::Stream;
+use crate::task::{Context, Poll};
+
+/// Creates a new stream that repeats elements of type `A` endlessly by
+/// applying the provided closure, the repeater, `F: FnMut() -> A`.
+///
+/// Infinite streams like `repeat_with()` are often used with adapters like
+/// [`Stream::take()`], in order to make them finite.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_stream)]
+/// # async fn run() {
+/// use std::stream::{self, Stream};
+///
+/// let mut curr = 1;
+/// let mut pow2 = stream::repeat_with(|| {
+///     let tmp = curr;
+///     curr *= 2;
+///     tmp
+/// })
+/// .take(3);
+///
+/// assert_eq!(pow2.next().await, Some(1));
+/// assert_eq!(pow2.next().await, Some(2));
+/// assert_eq!(pow2.next().await, Some(4));
+/// assert_eq!(pow2.next().await, None);
+/// # }
+/// ```
+#[unstable(feature = "async_stream", issue = "79024")]
+pub fn repeat_with<A, F: FnMut() -> A>(repeater: F) -> RepeatWith<F> {
+    RepeatWith { repeater }
+}
+
+/// A stream that repeats elements of type `A` endlessly by applying the
+/// provided closure `F: FnMut() -> A`.
+///
+/// This `struct` is created by the [`repeat_with()`] function. See its
+/// documentation for more.
+#[derive(Copy, Clone)]
+#[must_use = "streams do nothing unless polled"]
+#[unstable(feature = "async_stream", issue = "79024")]
+pub struct RepeatWith<F> {
+    repeater: F,
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<F> Unpin for RepeatWith<F> {}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<F> fmt::Debug for RepeatWith<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RepeatWith").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<A, F: FnMut() -> A> Stream for RepeatWith<F> {
+    type Item = A;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<A>> {
+        Poll::Ready(Some((self.repeater)()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
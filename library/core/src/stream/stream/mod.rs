@@ -1,5 +1,6 @@
 use crate::ops::DerefMut;
 use crate::pin::Pin;
+use crate::stream::{Chain, Filter, Map, Next, Take, Zip};
 use crate::task::{Context, Poll};
 
 /// An interface for dealing with asynchronous iterators.
@@ -77,6 +78,161 @@ pub trait Stream {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (0, None)
     }
+
+    /// Returns a future that resolves to the next item of the stream, or to
+    /// `None` once the stream is exhausted.
+    ///
+    /// This is the `Stream` counterpart of [`Iterator::next`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_stream)]
+    /// # async fn run() {
+    /// use std::stream::{self, Stream};
+    ///
+    /// let mut s = stream::from_iter([1, 2]);
+    ///
+    /// assert_eq!(s.next().await, Some(1));
+    /// assert_eq!(s.next().await, Some(2));
+    /// assert_eq!(s.next().await, None);
+    /// # }
+    /// ```
+    fn next(&mut self) -> Next<'_, Self>
+    where
+        Self: Sized + Unpin,
+    {
+        Next::new(self)
+    }
+
+    /// Takes a closure and creates a stream which calls that closure on each
+    /// item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_stream)]
+    /// # async fn run() {
+    /// use std::stream::{self, Stream};
+    ///
+    /// let mut s = stream::from_iter([1, 2]).map(|x| 2 * x);
+    ///
+    /// assert_eq!(s.next().await, Some(2));
+    /// assert_eq!(s.next().await, Some(4));
+    /// assert_eq!(s.next().await, None);
+    /// # }
+    /// ```
+    fn map<B, F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> B,
+    {
+        Map::new(self, f)
+    }
+
+    /// Creates a stream which uses a closure to determine if an item should
+    /// be yielded.
+    ///
+    /// Items for which the predicate returns `false` are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_stream)]
+    /// # async fn run() {
+    /// use std::stream::{self, Stream};
+    ///
+    /// let mut s = stream::from_iter(1..=4).filter(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(s.next().await, Some(2));
+    /// assert_eq!(s.next().await, Some(4));
+    /// assert_eq!(s.next().await, None);
+    /// # }
+    /// ```
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// Creates a stream that yields the first `n` items, or fewer if the
+    /// underlying stream ends sooner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_stream)]
+    /// # async fn run() {
+    /// use std::stream::{self, Stream};
+    ///
+    /// let mut s = stream::repeat_with(|| 7).take(2);
+    ///
+    /// assert_eq!(s.next().await, Some(7));
+    /// assert_eq!(s.next().await, Some(7));
+    /// assert_eq!(s.next().await, None);
+    /// # }
+    /// ```
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, n)
+    }
+
+    /// Takes two streams and creates a new stream over both in sequence.
+    ///
+    /// `chain()` returns a stream which first yields the items of `self`, and
+    /// once `self` is exhausted, yields the items of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_stream)]
+    /// # async fn run() {
+    /// use std::stream::{self, Stream};
+    ///
+    /// let mut s = stream::from_iter([1]).chain(stream::from_iter([2]));
+    ///
+    /// assert_eq!(s.next().await, Some(1));
+    /// assert_eq!(s.next().await, Some(2));
+    /// assert_eq!(s.next().await, None);
+    /// # }
+    /// ```
+    fn chain<U>(self, other: U) -> Chain<Self, U>
+    where
+        Self: Sized,
+        U: Stream<Item = Self::Item>,
+    {
+        Chain::new(self, other)
+    }
+
+    /// 'Zips up' two streams into a single stream of pairs.
+    ///
+    /// The returned stream ends as soon as either of the two streams ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_stream)]
+    /// # async fn run() {
+    /// use std::stream::{self, Stream};
+    ///
+    /// let mut s = stream::from_iter([1, 2]).zip(stream::from_iter(['a', 'b', 'c']));
+    ///
+    /// assert_eq!(s.next().await, Some((1, 'a')));
+    /// assert_eq!(s.next().await, Some((2, 'b')));
+    /// assert_eq!(s.next().await, None);
+    /// # }
+    /// ```
+    fn zip<U>(self, other: U) -> Zip<Self, U>
+    where
+        Self: Sized,
+        U: Stream,
+    {
+        Zip::new(self, other)
+    }
 }
 
 #[unstable(feature = "async_stream", issue = "79024")]
@@ -0,0 +1,90 @@
+use core::future::{self, Future};
+use core::pin::Pin;
+use core::ptr;
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+fn noop_raw_waker() -> RawWaker {
+    fn clone(_: *const ()) -> RawWaker {
+        noop_raw_waker()
+    }
+    fn noop(_: *const ()) {}
+
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    RawWaker::new(ptr::null(), &VTABLE)
+}
+
+/// Polls `future` once with a waker that does nothing.
+pub(crate) fn poll_once<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+    // SAFETY: the vtable functions never dereference the data pointer.
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    Pin::new(future).poll(&mut cx)
+}
+
+/// A future that is pending for the given number of polls before resolving
+/// to `value`.
+pub(crate) struct Delay<T> {
+    polls: usize,
+    value: Option<T>,
+}
+
+impl<T> Delay<T> {
+    pub(crate) fn new(polls: usize, value: T) -> Self {
+        Delay { polls, value: Some(value) }
+    }
+}
+
+impl<T> Unpin for Delay<T> {}
+
+impl<T> Future for Delay<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<T> {
+        if self.polls == 0 {
+            Poll::Ready(self.value.take().unwrap())
+        } else {
+            self.polls -= 1;
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn join() {
+    let mut fut = future::join(Delay::new(2, 'a'), Delay::new(0, 1));
+    assert_eq!(poll_once(&mut fut), Poll::Pending);
+    assert_eq!(poll_once(&mut fut), Poll::Pending);
+    assert_eq!(poll_once(&mut fut), Poll::Ready(('a', 1)));
+}
+
+#[test]
+fn join_nested() {
+    let inner = future::join(Delay::new(1, 1), Delay::new(3, 2));
+    let mut fut = future::join(inner, future::ready(3));
+    for _ in 0..3 {
+        assert_eq!(poll_once(&mut fut), Poll::Pending);
+    }
+    assert_eq!(poll_once(&mut fut), Poll::Ready(((1, 2), 3)));
+}
+
+#[test]
+#[should_panic]
+fn join_polled_after_completion() {
+    let mut fut = future::join(future::ready(1), future::ready(2));
+    assert_eq!(poll_once(&mut fut), Poll::Ready((1, 2)));
+    let _ = poll_once(&mut fut);
+}
+
+#[test]
+fn select() {
+    let mut fut = future::select(Delay::new(2, 'a'), Delay::new(1, 'b'));
+    assert_eq!(poll_once(&mut fut), Poll::Pending);
+    assert_eq!(poll_once(&mut fut), Poll::Ready('b'));
+
+    let mut fut = future::select(future::ready(1), future::ready(2));
+    assert_eq!(poll_once(&mut fut), Poll::Ready(1));
+
+    let mut fut = future::select(future::pending(), Delay::new(1, 2));
+    assert_eq!(poll_once(&mut fut), Poll::Pending);
+    assert_eq!(poll_once(&mut fut), Poll::Ready(2));
+}
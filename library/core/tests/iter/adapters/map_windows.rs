@@ -0,0 +1,89 @@
+use super::*;
+use core::cell::Cell;
+use core::iter::*;
+
+#[test]
+fn test_map_windows() {
+    let mut it = (0..5).map_windows(|&[a, b, c]| a + b + c);
+    assert_eq!(it.next(), Some(3));
+    assert_eq!(it.next(), Some(6));
+    assert_eq!(it.next(), Some(9));
+    assert_eq!(it.next(), None);
+
+    let words: Vec<_> =
+        "abcd".chars().map_windows(|w: &[char; 2]| w.iter().collect::<String>()).collect();
+    assert_eq!(words, ["ab", "bc", "cd"]);
+
+    let mut it = (0..5).map_windows(|w: &[_; 5]| *w);
+    assert_eq!(it.next(), Some([0, 1, 2, 3, 4]));
+    assert_eq!(it.next(), None);
+
+    let mut it = (0..4).map_windows(|w: &[_; 5]| *w);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_map_windows_size_hint() {
+    let it = (0..5).map_windows(|_: &[_; 2]| ());
+    assert_eq!(it.size_hint(), (4, Some(4)));
+
+    let it = (0..5).map_windows(|_: &[_; 6]| ());
+    assert_eq!(it.size_hint(), (0, Some(0)));
+
+    let it = (0..).map_windows(|_: &[_; 3]| ());
+    assert_eq!(it.size_hint(), (usize::MAX - 2, None));
+
+    let mut it = (0..5).map_windows(|_: &[_; 3]| ());
+    it.next();
+    assert_eq!(it.size_hint(), (2, Some(2)));
+    it.by_ref().for_each(drop);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_map_windows_drop() {
+    let count = Cell::new(0);
+    let mut it = (0..5).map(|_| CountDrop::new(&count)).map_windows(|_: &[_; 3]| ());
+    assert_eq!(it.next(), Some(()));
+    assert_eq!(count.get(), 0);
+    assert_eq!(it.next(), Some(()));
+    assert_eq!(count.get(), 1);
+    drop(it);
+    assert_eq!(count.get(), 4);
+
+    // An iterator too short for a single window drops what it consumed.
+    let count = Cell::new(0);
+    let mut it = (0..2).map(|_| CountDrop::new(&count)).map_windows(|_: &[_; 3]| ());
+    assert_eq!(it.next(), None);
+    assert_eq!(count.get(), 2);
+
+    let count = Cell::new(0);
+    let it = (0..5).map(|_| CountDrop::new(&count)).map_windows(|_: &[_; 2]| ());
+    assert_eq!(it.count(), 4);
+    assert_eq!(count.get(), 5);
+}
+
+#[test]
+fn test_map_windows_fused() {
+    let mut it = NonFused::new(0..3).map_windows(|&[a, b]| a * b);
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_map_windows_clone() {
+    let mut it = (0..5).map_windows(|w: &[_; 2]| *w);
+    assert_eq!(it.next(), Some([0, 1]));
+    let mut it2 = it.clone();
+    assert_eq!(it.next(), Some([1, 2]));
+    assert_eq!(it2.next(), Some([1, 2]));
+    assert_eq!(it2.collect::<Vec<_>>(), [[2, 3], [3, 4]]);
+}
+
+#[test]
+#[should_panic = "window size must be non-zero"]
+fn test_map_windows_zero() {
+    let _ = (0..4).map_windows(|_: &[_; 0]| ());
+}
@@ -1,3 +1,4 @@
+mod array_chunks;
 mod chain;
 mod cloned;
 mod copied;
@@ -11,6 +12,7 @@
 mod inspect;
 mod intersperse;
 mod map;
+mod map_windows;
 mod peekable;
 mod scan;
 mod skip;
@@ -20,6 +22,7 @@
 mod take_while;
 mod zip;
 
+use super::CountDrop;
 use core::cell::Cell;
 
 /// An iterator that panics whenever `next` or next_back` is called
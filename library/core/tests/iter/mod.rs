@@ -26,6 +26,24 @@
 
 pub fn is_trusted_len<I: TrustedLen>(_: I) {}
 
+/// Increments the shared counter whenever it is dropped. Used to test that
+/// adaptors and methods which move items around neither leak nor double-drop
+/// them.
+#[derive(Clone, Debug)]
+pub struct CountDrop<'a>(&'a Cell<usize>);
+
+impl<'a> CountDrop<'a> {
+    pub fn new(count: &'a Cell<usize>) -> Self {
+        Self(count)
+    }
+}
+
+impl Drop for CountDrop<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
 #[test]
 fn test_multi_iter() {
     let xs = [1, 2, 3, 4];
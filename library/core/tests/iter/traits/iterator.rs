@@ -468,3 +468,89 @@ fn test_collect() {
     let b: Vec<isize> = a.iter().cloned().collect();
     assert!(a == b);
 }
+
+#[test]
+fn test_next_chunk() {
+    let mut it = 0..12;
+    assert_eq!(it.next_chunk().unwrap(), [0, 1, 2, 3]);
+    assert_eq!(it.next_chunk().unwrap(), []);
+    assert_eq!(it.next_chunk().unwrap(), [4, 5, 6, 7, 8, 9]);
+    assert_eq!(it.next_chunk::<4>().unwrap_err().as_slice(), &[10, 11]);
+
+    let mut it = core::iter::empty::<u8>();
+    assert_eq!(it.next_chunk::<2>().unwrap_err().as_slice(), &[]);
+}
+
+#[test]
+fn test_next_chunk_drop() {
+    use crate::iter::CountDrop;
+    use core::cell::Cell;
+
+    let count = Cell::new(0);
+    let mut it = (0..5).map(|_| CountDrop::new(&count));
+    let chunk: [_; 3] = it.next_chunk().unwrap();
+    assert_eq!(count.get(), 0);
+    drop(chunk);
+    assert_eq!(count.get(), 3);
+
+    let rem = it.next_chunk::<3>().unwrap_err();
+    assert_eq!(rem.len(), 2);
+    assert_eq!(count.get(), 3);
+    drop(rem);
+    assert_eq!(count.get(), 5);
+}
+
+#[test]
+#[cfg(panic = "unwind")]
+fn test_next_chunk_panic() {
+    use crate::iter::CountDrop;
+    use core::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let count = Cell::new(0);
+    let mut it = (0..5).map(|i| {
+        if i == 3 {
+            panic!("bomb");
+        }
+        CountDrop::new(&count)
+    });
+    catch_unwind(AssertUnwindSafe(|| {
+        let _: [_; 4] = it.next_chunk().unwrap();
+    }))
+    .unwrap_err();
+    // The three items taken before the panic were dropped exactly once.
+    assert_eq!(count.get(), 3);
+}
+
+#[test]
+fn test_try_collect() {
+    use core::ops::ControlFlow::{Break, Continue};
+
+    let u = vec![Some(1), Some(2), Some(3)];
+    let v = u.into_iter().try_collect::<Vec<i32>>();
+    assert_eq!(v, Some(vec![1, 2, 3]));
+
+    let u = vec![Some(1), Some(2), None, Some(3)];
+    let mut it = u.into_iter();
+    let v = it.try_collect::<Vec<i32>>();
+    assert_eq!(v, None);
+    // The iterator is left just after the first failure.
+    assert_eq!(it.next(), Some(Some(3)));
+
+    let u: Vec<Result<i32, ()>> = vec![Ok(1), Ok(2), Ok(3)];
+    let v = u.into_iter().try_collect::<Vec<i32>>();
+    assert_eq!(v, Ok(vec![1, 2, 3]));
+
+    let u = vec![Ok(1), Ok(2), Err(()), Ok(3)];
+    let v = u.into_iter().try_collect::<Vec<i32>>();
+    assert_eq!(v, Err(()));
+
+    let numbers = vec![1, 2, 3, 4, 5];
+    let u = numbers.iter().map(|&x| if x < 3 { Continue(x) } else { Break(x) });
+    let v = u.collect::<Vec<_>>().into_iter().try_collect::<Vec<_>>();
+    assert_eq!(v, Break(3));
+
+    let u = vec![Continue(1), Continue(2)];
+    let v = u.into_iter().try_collect::<Vec<i32>>();
+    assert_eq!(v, Continue::<(), _>(vec![1, 2]));
+}
@@ -30,6 +30,7 @@
 #![feature(try_find)]
 #![feature(is_sorted)]
 #![feature(pattern)]
+#![feature(portable_simd)]
 #![feature(raw)]
 #![feature(sort_internals)]
 #![feature(slice_partition_at_index)]
@@ -51,6 +52,10 @@
 #![feature(iter_is_partitioned)]
 #![feature(iter_order_by)]
 #![feature(iter_map_while)]
+#![feature(iter_next_chunk)]
+#![feature(iter_array_chunks)]
+#![feature(iter_map_windows)]
+#![feature(iterator_try_collect)]
 #![feature(const_mut_refs)]
 #![feature(const_pin)]
 #![feature(const_slice_from_raw_parts)]
@@ -64,6 +69,16 @@
 #![feature(unsized_tuple_coercion)]
 #![feature(const_option)]
 #![feature(integer_atomics)]
+#![feature(int_log)]
+#![feature(int_roundings)]
+#![feature(isqrt)]
+#![feature(num_midpoint)]
+#![feature(bigint_helper_methods)]
+#![feature(saturating_int_impl)]
+#![feature(async_stream)]
+#![feature(future_join)]
+#![feature(future_select)]
+#![feature(ip_in_core)]
 #![feature(slice_group_by)]
 #![feature(trusted_random_access)]
 #![feature(unsize)]
@@ -83,6 +98,7 @@
 mod cmp;
 mod const_ptr;
 mod fmt;
+mod future;
 mod hash;
 mod intrinsics;
 mod iter;
@@ -90,6 +106,7 @@
 mod macros;
 mod manually_drop;
 mod mem;
+mod net;
 mod nonzero;
 mod num;
 mod ops;
@@ -98,9 +115,11 @@
 mod pin;
 mod ptr;
 mod result;
+mod simd;
 mod slice;
 mod str;
 mod str_lossy;
+mod stream;
 mod task;
 mod time;
 mod tuple;
@@ -328,3 +328,16 @@ fn test_nonzero_uint_rem() {
     let x: u32 = 42u32 % nz;
     assert_eq!(x, 2u32);
 }
+
+#[test]
+fn test_nonzero_ilog_isqrt() {
+    assert_eq!(NonZeroU8::new(1).unwrap().ilog2(), 0);
+    assert_eq!(NonZeroU8::new(u8::MAX).unwrap().ilog2(), 7);
+    assert_eq!(NonZeroU32::new(1000).unwrap().ilog10(), 3);
+    assert_eq!(NonZeroU128::new(u128::MAX).unwrap().ilog2(), 127);
+    assert_eq!(NonZeroU64::new(99).unwrap().isqrt(), NonZeroU64::new(9).unwrap());
+    assert_eq!(NonZeroU16::new(1).unwrap().isqrt(), NonZeroU16::new(1).unwrap());
+
+    const ILOG2: u32 = NonZeroU16::new(1 << 9).unwrap().ilog2();
+    assert_eq!(ILOG2, 9);
+}
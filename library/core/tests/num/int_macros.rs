@@ -289,6 +289,84 @@ fn test_pow() {
                 assert_eq!(r.saturating_pow(3), -8 as $T);
                 assert_eq!(r.saturating_pow(0), 1 as $T);
             }
+
+            #[test]
+            fn test_div_ceil() {
+                assert_eq!((8 as $T).div_ceil(3), 3);
+                assert_eq!((8 as $T).div_ceil(-3), -2);
+                assert_eq!((-8 as $T).div_ceil(3), -2);
+                assert_eq!((-8 as $T).div_ceil(-3), 3);
+                assert_eq!((9 as $T).div_ceil(-3), -3);
+            }
+
+            #[test]
+            fn test_next_multiple_of() {
+                assert_eq!((16 as $T).next_multiple_of(8), 16);
+                assert_eq!((23 as $T).next_multiple_of(8), 24);
+                assert_eq!((16 as $T).next_multiple_of(-8), 16);
+                assert_eq!((23 as $T).next_multiple_of(-8), 16);
+                assert_eq!((-16 as $T).next_multiple_of(8), -16);
+                assert_eq!((-23 as $T).next_multiple_of(8), -16);
+                assert_eq!((-23 as $T).next_multiple_of(-8), -24);
+                assert_eq!(MIN.next_multiple_of(-1), MIN);
+                assert_eq!((-23 as $T).checked_next_multiple_of(-8), Some(-24));
+                assert_eq!((1 as $T).checked_next_multiple_of(0), None);
+                assert_eq!(MAX.checked_next_multiple_of(2), None);
+                assert_eq!(MIN.checked_next_multiple_of(-3), None);
+                assert_eq!(MIN.checked_next_multiple_of(-1), Some(MIN));
+            }
+
+            #[test]
+            fn test_midpoint() {
+                assert_eq!((0 as $T).midpoint(4), 2);
+                assert_eq!((1 as $T).midpoint(4), 2);
+                assert_eq!((-1 as $T).midpoint(2), 0);
+                assert_eq!((-1 as $T).midpoint(-4), -2);
+                assert_eq!((-7 as $T).midpoint(0), -3);
+                assert_eq!(MIN.midpoint(MAX), 0);
+                assert_eq!(MAX.midpoint(MAX), MAX);
+                assert_eq!(MIN.midpoint(MIN), MIN);
+            }
+
+            #[test]
+            fn test_ilog() {
+                assert_eq!((1 as $T).ilog2(), 0);
+                assert_eq!((8 as $T).ilog2(), 3);
+                assert_eq!(MAX.ilog2(), $T::BITS - 2);
+                assert_eq!((99 as $T).ilog10(), 1);
+                assert_eq!((100 as $T).ilog10(), 2);
+                assert_eq!((81 as $T).ilog(3), 4);
+                assert_eq!((0 as $T).checked_ilog2(), None);
+                assert_eq!((-1 as $T).checked_ilog2(), None);
+                assert_eq!((-100 as $T).checked_ilog10(), None);
+                assert_eq!(MIN.checked_ilog(2), None);
+                assert_eq!((5 as $T).checked_ilog(-2), None);
+                for i in 1..=(i8::MAX as $T) {
+                    assert_eq!(i.checked_ilog(2), i.checked_ilog2());
+                    assert_eq!(i.checked_ilog(10), i.checked_ilog10());
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_ilog_negative() {
+                let _ = (-1 as $T).ilog10();
+            }
+
+            #[test]
+            fn test_isqrt() {
+                assert_eq!((0 as $T).isqrt(), 0);
+                assert_eq!((99 as $T).isqrt(), 9);
+                assert_eq!((100 as $T).isqrt(), 10);
+                for i in 0..=(i8::MAX as $T) {
+                    let s = i.isqrt();
+                    assert!(s * s <= i);
+                    assert!((s + 1).checked_mul(s + 1).map_or(true, |sq| sq > i));
+                }
+                assert_eq!((-1 as $T).checked_isqrt(), None);
+                assert_eq!(MIN.checked_isqrt(), None);
+                assert!(MAX.checked_isqrt().is_some());
+            }
         }
     };
 }
@@ -30,6 +30,7 @@
 mod dec2flt;
 mod flt2dec;
 mod ops;
+mod saturating;
 mod wrapping;
 
 mod ieee754;
@@ -140,6 +141,21 @@ fn test_empty() {
     test_parse::<u8>("", Err(IntErrorKind::Empty));
 }
 
+#[test]
+fn test_widening_mul() {
+    assert_eq!(5u8.widening_mul(2), (10, 0));
+    assert_eq!(u8::MAX.widening_mul(u8::MAX), (1, u8::MAX - 1));
+    assert_eq!(u16::MAX.widening_mul(2), (u16::MAX - 1, 1));
+    assert_eq!(u32::MAX.widening_mul(u32::MAX), (1, u32::MAX - 1));
+    assert_eq!(u64::MAX.widening_mul(u64::MAX), (1, u64::MAX - 1));
+    assert_eq!(usize::MAX.widening_mul(usize::MAX), (1, usize::MAX - 1));
+
+    assert_eq!(5u8.carrying_mul(2, 3), (13, 0));
+    assert_eq!(u8::MAX.carrying_mul(u8::MAX, u8::MAX), (0, u8::MAX));
+    assert_eq!(u32::MAX.carrying_mul(u32::MAX, u32::MAX), (0, u32::MAX));
+    assert_eq!(u64::MAX.carrying_mul(u64::MAX, u64::MAX), (0, u64::MAX));
+}
+
 #[test]
 fn test_infallible_try_from_int_error() {
     let func = |x: i8| -> Result<i32, TryFromIntError> { Ok(x.try_into()?) };
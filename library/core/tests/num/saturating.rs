@@ -0,0 +1,120 @@
+use core::num::Saturating;
+
+macro_rules! saturating_operation {
+    ($result:expr, $lhs:ident $op:tt $rhs:expr) => {
+        assert_eq!($result, $lhs $op $rhs);
+        assert_eq!($result, &$lhs $op $rhs);
+        assert_eq!($result, $lhs $op &$rhs);
+        assert_eq!($result, &$lhs $op &$rhs);
+    };
+    ($result:expr, $op:tt $expr:expr) => {
+        assert_eq!($result, $op $expr);
+        assert_eq!($result, $op &$expr);
+    };
+}
+
+macro_rules! saturating_assignment {
+    ($result:expr, $lhs:ident $op:tt $rhs:expr) => {
+        let mut lhs1 = $lhs;
+        lhs1 $op $rhs;
+        assert_eq!($result, lhs1);
+
+        let mut lhs2 = $lhs;
+        lhs2 $op &$rhs;
+        assert_eq!($result, lhs2);
+    };
+}
+
+macro_rules! saturating_test {
+    ($fn_name:ident, $type:ty, $min:expr, $max:expr) => {
+        #[test]
+        fn $fn_name() {
+            let zero: Saturating<$type> = Saturating(0);
+            let one: Saturating<$type> = Saturating(1);
+            let two: Saturating<$type> = Saturating(2);
+            let min: Saturating<$type> = Saturating($min);
+            let max: Saturating<$type> = Saturating($max);
+
+            saturating_operation!(max, max + one);
+            saturating_assignment!(max, max += one);
+            saturating_operation!(min, min - one);
+            saturating_assignment!(min, min -= one);
+            saturating_operation!(max, max * two);
+            saturating_assignment!(max, max *= two);
+            saturating_operation!(max, max / one);
+            saturating_assignment!(max, max /= one);
+            saturating_operation!(zero, max % one);
+            saturating_assignment!(zero, max %= one);
+            saturating_operation!(zero, zero & max);
+            saturating_assignment!(zero, zero &= max);
+            saturating_operation!(max, zero | max);
+            saturating_assignment!(max, zero |= max);
+            saturating_operation!(zero, max ^ max);
+            saturating_assignment!(zero, max ^= max);
+            saturating_operation!(max, !min);
+            saturating_operation!(max, max << 1usize);
+            saturating_assignment!(max, max <<= 1usize);
+            saturating_operation!(zero, zero << 200usize);
+            saturating_operation!(max, one << 200usize);
+            saturating_operation!(zero, one >> 1usize);
+            saturating_assignment!(zero, one >>= 1usize);
+            saturating_operation!(zero, max >> 200usize);
+            assert_eq!(two << 2usize, Saturating(8));
+            assert_eq!(one.rotate_right(1).rotate_left(1), one);
+            assert_eq!(one.swap_bytes().swap_bytes(), one);
+            assert_eq!(one.reverse_bits().leading_zeros(), 0);
+            assert_eq!(<Saturating<$type>>::from_be(one.to_be()), one);
+            assert_eq!(<Saturating<$type>>::from_le(one.to_le()), one);
+
+            assert_eq!(max, [max, one].iter().sum());
+            assert_eq!(max, [max, two].iter().copied().product());
+        }
+    };
+}
+
+saturating_test!(test_saturating_i8, i8, i8::MIN, i8::MAX);
+saturating_test!(test_saturating_i16, i16, i16::MIN, i16::MAX);
+saturating_test!(test_saturating_i32, i32, i32::MIN, i32::MAX);
+saturating_test!(test_saturating_i64, i64, i64::MIN, i64::MAX);
+#[cfg(not(target_os = "emscripten"))]
+saturating_test!(test_saturating_i128, i128, i128::MIN, i128::MAX);
+saturating_test!(test_saturating_isize, isize, isize::MIN, isize::MAX);
+saturating_test!(test_saturating_u8, u8, u8::MIN, u8::MAX);
+saturating_test!(test_saturating_u16, u16, u16::MIN, u16::MAX);
+saturating_test!(test_saturating_u32, u32, u32::MIN, u32::MAX);
+saturating_test!(test_saturating_u64, u64, u64::MIN, u64::MAX);
+#[cfg(not(target_os = "emscripten"))]
+saturating_test!(test_saturating_u128, u128, u128::MIN, u128::MAX);
+saturating_test!(test_saturating_usize, usize, usize::MIN, usize::MAX);
+
+#[test]
+fn test_saturating_signed() {
+    let min = Saturating(i32::MIN);
+    let max = Saturating(i32::MAX);
+    let minus_one = Saturating(-1i32);
+
+    saturating_operation!(max, -min);
+    saturating_operation!(Saturating(-i32::MAX), -max);
+    saturating_operation!(max, min / minus_one);
+    saturating_operation!(Saturating(0), min % minus_one);
+    saturating_operation!(max, min * minus_one);
+    saturating_operation!(min, max * Saturating(-2));
+    assert_eq!(min.abs(), max);
+    assert_eq!(min.signum(), minus_one);
+    assert_eq!(Saturating(-3i8).pow(5), Saturating(i8::MIN));
+    saturating_operation!(min, min << 1usize);
+    saturating_operation!(min, minus_one << 31usize);
+    assert_eq!(Saturating(1i32) << 31usize, max);
+    saturating_operation!(min, minus_one << 200usize);
+    saturating_operation!(minus_one, min >> 200usize);
+    assert_eq!(Saturating(-8i32) >> 2usize, Saturating(-2));
+}
+
+#[test]
+fn test_saturating_fmt() {
+    let n = Saturating(255u8);
+    assert_eq!(
+        format!("{:?} {} {:b} {:o} {:x} {:X}", n, n, n, n, n, n),
+        "255 255 11111111 377 ff FF"
+    );
+}
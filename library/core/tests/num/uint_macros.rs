@@ -205,6 +205,74 @@ fn test_pow() {
                 assert_eq!(r.overflowing_pow(2), (1 as $T, true));
                 assert_eq!(r.saturating_pow(2), MAX);
             }
+
+            #[test]
+            fn test_div_ceil() {
+                assert_eq!((8 as $T).div_ceil(3), 3);
+                assert_eq!((9 as $T).div_ceil(3), 3);
+                assert_eq!((0 as $T).div_ceil(3), 0);
+                assert_eq!(MAX.div_ceil(2), MAX / 2 + 1);
+            }
+
+            #[test]
+            fn test_next_multiple_of() {
+                assert_eq!((16 as $T).next_multiple_of(8), 16);
+                assert_eq!((23 as $T).next_multiple_of(8), 24);
+                assert_eq!(MAX.next_multiple_of(1), MAX);
+                assert_eq!((23 as $T).checked_next_multiple_of(8), Some(24));
+                assert_eq!((1 as $T).checked_next_multiple_of(0), None);
+                assert_eq!(MAX.checked_next_multiple_of(2), None);
+            }
+
+            #[test]
+            fn test_midpoint() {
+                assert_eq!((0 as $T).midpoint(4), 2);
+                assert_eq!((4 as $T).midpoint(0), 2);
+                assert_eq!((1 as $T).midpoint(4), 2);
+                assert_eq!(MAX.midpoint(MAX), MAX);
+                assert_eq!(MAX.midpoint(0), MAX / 2);
+            }
+
+            #[test]
+            fn test_ilog() {
+                assert_eq!((1 as $T).ilog2(), 0);
+                assert_eq!((8 as $T).ilog2(), 3);
+                assert_eq!(MAX.ilog2(), $T::BITS - 1);
+                assert_eq!((99 as $T).ilog10(), 1);
+                assert_eq!((100 as $T).ilog10(), 2);
+                assert_eq!((81 as $T).ilog(3), 4);
+                assert_eq!((80 as $T).ilog(3), 3);
+                assert_eq!((0 as $T).checked_ilog2(), None);
+                assert_eq!((0 as $T).checked_ilog10(), None);
+                assert_eq!((5 as $T).checked_ilog(1), None);
+                assert_eq!((5 as $T).checked_ilog(0), None);
+                assert_eq!(MAX.checked_ilog(MAX), Some(1));
+                for i in 1..=(u8::MAX as $T) {
+                    assert_eq!(i.checked_ilog(2), i.checked_ilog2());
+                    assert_eq!(i.checked_ilog(10), i.checked_ilog10());
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_ilog_zero() {
+                let _ = (0 as $T).ilog2();
+            }
+
+            #[test]
+            fn test_isqrt() {
+                assert_eq!((0 as $T).isqrt(), 0);
+                assert_eq!((1 as $T).isqrt(), 1);
+                assert_eq!((99 as $T).isqrt(), 9);
+                assert_eq!((100 as $T).isqrt(), 10);
+                for i in 0..=(u8::MAX as $T) {
+                    let s = i.isqrt();
+                    assert!(s * s <= i);
+                    assert!((s + 1).checked_mul(s + 1).map_or(true, |sq| sq > i));
+                }
+                let s = MAX.isqrt();
+                assert_eq!(s, MAX >> ($T::BITS / 2));
+            }
         }
     };
 }
@@ -0,0 +1,149 @@
+use core::simd::*;
+
+struct Concat;
+
+impl Shuffle<4> for Concat {
+    const INDEX: [u32; 4] = [2, 3, 4, 5];
+}
+
+#[test]
+fn splat_and_arrays() {
+    let a = u32x4::splat(3);
+    assert_eq!(a.to_array(), [3; 4]);
+    assert_eq!(a.lanes(), 4);
+    assert_eq!(u32x4::LANES, 4);
+
+    let mut b = i16x8::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    b[3] = -3;
+    assert_eq!(b[3], -3);
+    assert_eq!(&b[6..], &[6, 7]);
+    assert_eq!(<[i16; 8]>::from(b), [0, 1, 2, -3, 4, 5, 6, 7]);
+    assert_eq!(
+        i16x8::from_slice(&[9, 8, 7, 6, 5, 4, 3, 2, 1]),
+        i16x8::from([9, 8, 7, 6, 5, 4, 3, 2])
+    );
+    assert_eq!(format!("{:?}", u8x8::default()), "[0, 0, 0, 0, 0, 0, 0, 0]");
+}
+
+#[test]
+#[should_panic]
+fn from_short_slice() {
+    let _ = u32x4::from_slice(&[1, 2, 3]);
+}
+
+#[test]
+fn arithmetic() {
+    let a = i32x4::from_array([1, -2, 3, i32::MAX]);
+    let b = i32x4::from_array([4, 5, -6, 1]);
+    assert_eq!((a + b).to_array(), [5, 3, -3, i32::MIN]);
+    assert_eq!((a - b).to_array(), [-3, -7, 9, i32::MAX - 1]);
+    assert_eq!((a * b).to_array(), [4, -10, -18, i32::MAX]);
+    assert_eq!((b / a).to_array(), [4, -2, -2, 0]);
+    assert_eq!((b % a).to_array(), [0, 1, 0, 1]);
+    assert_eq!((-a).to_array(), [-1, 2, -3, -i32::MAX]);
+    assert_eq!((a & i32x4::splat(1)).to_array(), [1, 0, 1, 1]);
+    assert_eq!((!i32x4::splat(0)).to_array(), [-1; 4]);
+    assert_eq!((u8x8::splat(1) << u8x8::splat(7)).to_array(), [128; 8]);
+    assert_eq!((i8x8::splat(-128) >> i8x8::splat(7)).to_array(), [-1; 8]);
+
+    let mut c = f64x2::from_array([1.5, -2.0]);
+    c *= f64x2::splat(2.0);
+    c /= f64x2::from_array([1.0, 4.0]);
+    assert_eq!(c.to_array(), [3.0, -1.0]);
+    assert_eq!(c.abs().to_array(), [3.0, 1.0]);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn divide_by_zero() {
+    let _ = u32x4::splat(1) / u32x4::from_array([1, 2, 0, 4]);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn divide_overflow() {
+    let _ = i8x8::splat(i8::MIN) / i8x8::splat(-1);
+}
+
+#[test]
+#[should_panic(expected = "attempt to shift left with overflow")]
+fn shift_overflow() {
+    let _ = u16x4::splat(1) << u16x4::from_array([0, 1, 16, 2]);
+}
+
+#[test]
+fn comparisons_and_masks() {
+    let a = f32x4::from_array([1.0, f32::NAN, 3.0, -4.0]);
+    let b = f32x4::splat(2.0);
+    assert_eq!(a.lanes_lt(b).to_array(), [true, false, false, true]);
+    assert_eq!(a.lanes_ge(b).to_array(), [false, false, true, false]);
+    assert_eq!(a.lanes_ne(a).to_array(), [false, true, false, false]);
+
+    let mut mask = mask32x4::splat(false);
+    assert!(!mask.any());
+    mask.set(2, true);
+    assert!(mask.test(2));
+    assert!(mask.any() && !mask.all());
+    assert!((mask | !mask).all());
+    assert_eq!((!mask).to_int().to_array(), [-1, -1, 0, -1]);
+    assert_eq!(mask, mask32x4::from_int(i32x4::from_array([0, 0, -1, 0])));
+    assert_eq!(mask.select(i32x4::splat(1), i32x4::splat(2)).to_array(), [2, 2, 1, 2]);
+    assert_eq!(format!("{:?}", mask), "[false, false, true, false]");
+}
+
+#[test]
+#[should_panic]
+fn mask_from_invalid_int() {
+    let _ = mask8x8::from_int(i8x8::splat(1));
+}
+
+#[test]
+fn shuffles() {
+    let a = u32x4::from_array([0, 1, 2, 3]);
+    let b = u32x4::from_array([4, 5, 6, 7]);
+    assert_eq!(a.shuffle::<Concat>(b).to_array(), [2, 3, 4, 5]);
+    assert_eq!(a.reverse().to_array(), [3, 2, 1, 0]);
+
+    let (lo, hi) = a.interleave(b);
+    assert_eq!(lo.to_array(), [0, 4, 1, 5]);
+    assert_eq!(hi.to_array(), [2, 6, 3, 7]);
+    assert_eq!(lo.deinterleave(hi), (a, b));
+}
+
+#[test]
+fn reductions() {
+    let a = i32x4::from_array([1, -2, 3, 8]);
+    assert_eq!(a.horizontal_sum(), 10);
+    assert_eq!(a.horizontal_product(), -48);
+    assert_eq!(a.horizontal_max(), 8);
+    assert_eq!(a.horizontal_min(), -2);
+    assert_eq!(u8x8::splat(0x81).horizontal_or(), 0x81);
+    assert_eq!(u8x8::from_array([1, 2, 4, 8, 16, 32, 64, 128]).horizontal_xor(), 0xff);
+    assert_eq!(u8x8::splat(200).horizontal_sum(), 200u8.wrapping_mul(8));
+
+    let f = f64x4::from_array([1.0, 2.5, -0.5, 4.0]);
+    assert_eq!(f.horizontal_sum(), 7.0);
+    assert_eq!(f.horizontal_product(), -5.0);
+    assert_eq!(f.horizontal_max(), 4.0);
+    assert_eq!(f64x4::from_array([f64::NAN, 1.0, f64::NAN, -1.0]).horizontal_min(), -1.0);
+    assert!(f64x2::splat(-0.0).horizontal_sum().is_sign_negative());
+}
+
+#[test]
+fn gather_scatter() {
+    let data = [10, 11, 12, 13, 14, 15, 16, 17, 18];
+    let idxs = usizex4::from_array([9, 3, 0, 5]);
+    let alt = i32x4::from_array([-5, -4, -3, -2]);
+    assert_eq!(i32x4::gather_or(&data, idxs, alt).to_array(), [-5, 13, 10, 15]);
+    assert_eq!(i32x4::gather_or_default(&data, idxs).to_array(), [0, 13, 10, 15]);
+    let enable = masksizex4::from_array([true, true, false, true]);
+    assert_eq!(i32x4::gather_select(&data, enable, idxs, alt).to_array(), [-5, 13, -3, 15]);
+
+    let mut data = data;
+    let idxs = usizex4::from_array([8, 100, 1, 1]);
+    i32x4::from_array([1, 2, 3, 4]).scatter(&mut data, idxs);
+    assert_eq!(data, [10, 4, 12, 13, 14, 15, 16, 17, 1]);
+    let enable = masksizex4::from_array([false, true, true, false]);
+    i32x4::splat(0).scatter_select(&mut data, enable, idxs);
+    assert_eq!(data, [10, 0, 12, 13, 14, 15, 16, 17, 1]);
+}
@@ -0,0 +1,126 @@
+use core::future::{self, Future};
+use core::pin::Pin;
+use core::stream::{self, Stream};
+use core::task::{Context, Poll};
+
+use crate::future::{poll_once, Delay};
+
+/// Polls `stream` for its next item once with a waker that does nothing.
+fn poll_next<S: Stream + Unpin>(stream: &mut S) -> Poll<Option<S::Item>> {
+    poll_once(&mut stream.next())
+}
+
+/// Collects the items of a stream that never returns `Poll::Pending`.
+fn collect<S: Stream + Unpin>(mut stream: S) -> Vec<S::Item> {
+    let mut items = Vec::new();
+    while let Poll::Ready(Some(item)) = poll_next(&mut stream) {
+        items.push(item);
+    }
+    items
+}
+
+/// A stream that yields `Poll::Pending` before each of its items.
+struct Stutter<I> {
+    iter: I,
+    pending: bool,
+}
+
+fn stutter<I: IntoIterator>(iter: I) -> Stutter<I::IntoIter> {
+    Stutter { iter: iter.into_iter(), pending: true }
+}
+
+impl<I> Unpin for Stutter<I> {}
+
+impl<I: Iterator> Stream for Stutter<I> {
+    type Item = I::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        self.pending = !self.pending;
+        if self.pending {
+            Poll::Ready(self.iter.next())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn from_iter() {
+    let s = stream::from_iter(1..=3);
+    assert_eq!(s.size_hint(), (3, Some(3)));
+    assert_eq!(collect(s), [1, 2, 3]);
+}
+
+#[test]
+fn once() {
+    let mut s = stream::once(Delay::new(1, 5));
+    assert_eq!(s.size_hint(), (1, Some(1)));
+    assert_eq!(poll_next(&mut s), Poll::Pending);
+    assert_eq!(poll_next(&mut s), Poll::Ready(Some(5)));
+    assert_eq!(poll_next(&mut s), Poll::Ready(None));
+    assert_eq!(s.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn repeat_with() {
+    let mut n = 0;
+    let s = stream::repeat_with(|| {
+        n += 1;
+        n
+    });
+    assert_eq!(collect(s.take(3)), [1, 2, 3]);
+}
+
+#[test]
+fn poll_fn() {
+    let mut items = vec![3, 2, 1];
+    let s = stream::poll_fn(|_cx| Poll::Ready(items.pop()));
+    assert_eq!(collect(s), [1, 2, 3]);
+}
+
+#[test]
+fn map_filter() {
+    let s = stream::from_iter(1..=6).filter(|x| x % 2 == 0).map(|x| x * 10);
+    assert_eq!(s.size_hint(), (0, Some(6)));
+    assert_eq!(collect(s), [20, 40, 60]);
+}
+
+#[test]
+fn take() {
+    let s = stream::from_iter(1..=6).take(2);
+    assert_eq!(s.size_hint(), (2, Some(2)));
+    assert_eq!(collect(s), [1, 2]);
+    assert_eq!(collect(stream::from_iter(1..=2).take(5)), [1, 2]);
+    assert_eq!(collect(stream::from_iter(1..=2).take(0)), Vec::<i32>::new());
+}
+
+#[test]
+fn chain() {
+    let s = stream::from_iter(1..=2).chain(stream::once(future::ready(3)));
+    assert_eq!(s.size_hint(), (3, Some(3)));
+    assert_eq!(collect(s), [1, 2, 3]);
+}
+
+#[test]
+fn zip() {
+    let s = stream::from_iter(1..=3).zip(stream::from_iter("ab".chars()));
+    assert_eq!(s.size_hint(), (1, Some(2)));
+    assert_eq!(collect(s), [(1, 'a'), (2, 'b')]);
+}
+
+#[test]
+fn zip_pending() {
+    let mut s = stream::from_iter(1..=2).zip(stutter("ab".chars()));
+    assert_eq!(poll_next(&mut s), Poll::Pending);
+    assert_eq!(poll_next(&mut s), Poll::Ready(Some((1, 'a'))));
+    assert_eq!(poll_next(&mut s), Poll::Pending);
+    assert_eq!(poll_next(&mut s), Poll::Ready(Some((2, 'b'))));
+    assert_eq!(poll_next(&mut s), Poll::Ready(None));
+}
+
+#[test]
+fn next_is_unpin_future() {
+    fn assert_future<F: Future + Unpin>(_: &F) {}
+    let mut s = stream::from_iter(0..1);
+    assert_future(&s.next());
+}
@@ -99,9 +99,10 @@
 use crate::env;
 use crate::ffi::c_void;
 use crate::fmt;
+use crate::path::{Path, PathBuf};
 use crate::sync::atomic::{AtomicUsize, Ordering::SeqCst};
 use crate::sync::Once;
-use crate::sys_common::backtrace::{lock, output_filename};
+use crate::sys_common::backtrace::{lock, module_path, output_filename};
 use crate::vec::Vec;
 
 /// A captured OS thread stack backtrace.
@@ -161,7 +162,10 @@ enum RawFrame {
     Fake,
 }
 
-struct BacktraceSymbol {
+/// A symbol of a backtrace frame, such as a function that was inlined into
+/// the frame, along with its location in the source code.
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
@@ -173,6 +177,27 @@ enum BytesOrWide {
     Wide(Vec<u16>),
 }
 
+/// The addresses of a single frame of a backtrace, which are known without
+/// resolving any symbols.
+///
+/// Unlike [`BacktraceFrame`], this is plain data: it can be stored or sent
+/// elsewhere, for example to a crash reporter that symbolizes the addresses
+/// later or in another process, using the debug information of the modules
+/// involved. As modules may be loaded at different addresses in each run,
+/// such a symbolizer typically works with [`module_path`] and
+/// [`module_offset`].
+///
+/// [`module_path`]: UnresolvedFrame::module_path
+/// [`module_offset`]: UnresolvedFrame::module_offset
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnresolvedFrame {
+    ip: usize,
+    symbol_address: usize,
+    module_base_address: Option<usize>,
+    module_path: Option<PathBuf>,
+}
+
 impl fmt::Debug for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let capture = match &self.inner {
@@ -199,6 +224,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "backtrace_frames", issue = "79676")]
 impl fmt::Debug for BacktraceFrame {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut dbg = fmt.debug_list();
@@ -207,6 +233,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "backtrace_frames", issue = "79676")]
 impl fmt::Debug for BacktraceSymbol {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         // FIXME: improve formatting: https://github.com/rust-lang/rust/issues/65280
@@ -233,6 +260,27 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+impl BytesOrWide {
+    fn to_path_buf(&self) -> PathBuf {
+        match self {
+            #[cfg(unix)]
+            BytesOrWide::Bytes(b) => {
+                use crate::os::unix::ffi::OsStrExt;
+                PathBuf::from(crate::ffi::OsStr::from_bytes(b))
+            }
+            #[cfg(not(unix))]
+            BytesOrWide::Bytes(b) => PathBuf::from(String::from_utf8_lossy(b).into_owned()),
+            #[cfg(windows)]
+            BytesOrWide::Wide(w) => {
+                use crate::os::windows::ffi::OsStringExt;
+                PathBuf::from(crate::ffi::OsString::from_wide(w))
+            }
+            #[cfg(not(windows))]
+            BytesOrWide::Wide(w) => PathBuf::from(String::from_utf16_lossy(w)),
+        }
+    }
+}
+
 impl fmt::Debug for BytesOrWide {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         output_filename(
@@ -366,10 +414,192 @@ pub fn status(&self) -> BacktraceStatus {
 
 impl<'a> Backtrace {
     /// Returns an iterator over the backtrace frames.
+    ///
+    /// This resolves the symbols of all frames, if that has not happened yet.
     #[unstable(feature = "backtrace_frames", issue = "79676")]
     pub fn frames(&'a self) -> &'a [BacktraceFrame] {
         if let Inner::Captured(c) = &self.inner { &c.force().frames } else { &[] }
     }
+
+    /// Returns the addresses of the backtrace frames, without resolving any
+    /// symbols.
+    ///
+    /// This is cheap, and the result can be stored or sent elsewhere to be
+    /// symbolized later. The frames are the same as those returned by
+    /// [`frames`], in the same order.
+    ///
+    /// [`frames`]: Backtrace::frames
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(backtrace, backtrace_frames)]
+    /// use std::backtrace::Backtrace;
+    ///
+    /// let backtrace = Backtrace::force_capture();
+    /// for frame in backtrace.unresolved_frames() {
+    ///     match (frame.module_path(), frame.module_offset()) {
+    ///         (Some(path), Some(offset)) => println!("{}+{:#x}", path.display(), offset),
+    ///         _ => println!("{:#x}", frame.ip()),
+    ///     }
+    /// }
+    /// ```
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn unresolved_frames(&'a self) -> &'a [UnresolvedFrame] {
+        if let Inner::Captured(c) = &self.inner { &c.unresolved } else { &[] }
+    }
+
+    /// Resolves the symbols of all frames now, rather than when they are
+    /// first needed.
+    ///
+    /// Symbols are resolved lazily, the first time the backtrace is printed
+    /// or its [`frames`] are inspected, since resolution can be much more
+    /// expensive than capturing. This allows doing it at a convenient time
+    /// instead, for example before the modules the backtrace refers to are
+    /// unloaded. Resolution happens only once; later calls do nothing.
+    ///
+    /// [`frames`]: Backtrace::frames
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn resolve(&self) {
+        if let Inner::Captured(c) = &self.inner {
+            c.force();
+        }
+    }
+}
+
+impl BacktraceFrame {
+    /// Returns the current instruction pointer of this frame.
+    ///
+    /// This is normally the next instruction to execute in the frame, but
+    /// not all implementations list this with 100% accuracy.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn ip(&self) -> *mut c_void {
+        self.frame.ip()
+    }
+
+    /// Returns the starting address of the function this frame belongs to,
+    /// if known.
+    ///
+    /// On some platforms this is only an approximation, or the same as
+    /// [`ip`](BacktraceFrame::ip).
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbol_address(&self) -> *mut c_void {
+        self.frame.symbol_address()
+    }
+
+    /// Returns the base address of the module (executable or shared
+    /// library) this frame belongs to, if known.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn module_base_address(&self) -> Option<*mut c_void> {
+        self.frame.module_base_address()
+    }
+
+    /// Returns the symbols this frame resolved to.
+    ///
+    /// There can be more than one symbol when functions were inlined into
+    /// this frame, in which case the innermost function comes first. The list
+    /// is empty if no symbol could be found.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        &self.symbols
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the demangled name of this symbol, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(backtrace, backtrace_frames)]
+    /// use std::backtrace::Backtrace;
+    ///
+    /// let backtrace = Backtrace::force_capture();
+    /// for frame in backtrace.frames() {
+    ///     for symbol in frame.symbols() {
+    ///         let name = symbol.name().unwrap_or_else(|| "<unknown>".to_string());
+    ///         match (symbol.filename(), symbol.lineno()) {
+    ///             (Some(file), Some(line)) => println!("{} at {}:{}", name, file.display(), line),
+    ///             _ => println!("{}", name),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn name(&self) -> Option<String> {
+        self.name.as_ref().map(|b| format!("{:#}", backtrace_rs::SymbolName::new(b)))
+    }
+
+    /// Returns the name of this symbol as found in the debug information or
+    /// symbol table, which is typically mangled.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn raw_name(&self) -> Option<&[u8]> {
+        self.name.as_deref()
+    }
+
+    /// Returns the path of the source file this symbol is defined in, if known.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn filename(&self) -> Option<PathBuf> {
+        self.filename.as_ref().map(BytesOrWide::to_path_buf)
+    }
+
+    /// Returns the line number in the source file, if known.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+
+    /// Returns the column number in the source file, if known.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn colno(&self) -> Option<u32> {
+        self.colno
+    }
+}
+
+impl UnresolvedFrame {
+    /// Returns the instruction pointer of this frame.
+    ///
+    /// See [`BacktraceFrame::ip`].
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    /// Returns the starting address of the function this frame belongs to.
+    ///
+    /// See [`BacktraceFrame::symbol_address`].
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbol_address(&self) -> usize {
+        self.symbol_address
+    }
+
+    /// Returns the base address of the module this frame belongs to, if
+    /// known.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn module_base_address(&self) -> Option<usize> {
+        self.module_base_address
+    }
+
+    /// Returns the path of the module (the executable or a shared library)
+    /// this frame belongs to, if known.
+    ///
+    /// This is the path the module was loaded from, as reported by the
+    /// platform, and is only available on some platforms.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn module_path(&self) -> Option<&Path> {
+        self.module_path.as_deref()
+    }
+
+    /// Returns the offset of the instruction pointer from the base address
+    /// of its module, if that is known.
+    ///
+    /// Unlike the instruction pointer itself, this does not depend on where
+    /// the module was loaded, so it can be resolved with the module's debug
+    /// information alone.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn module_offset(&self) -> Option<usize> {
+        self.module_base_address.and_then(|base| self.ip.checked_sub(base))
+    }
 }
 
 impl fmt::Display for Backtrace {
@@ -424,11 +654,24 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
 struct LazilyResolvedCapture {
     sync: Once,
     capture: UnsafeCell<Capture>,
+    // Kept apart from `capture`, so that it can be accessed while another
+    // thread is resolving.
+    unresolved: Vec<UnresolvedFrame>,
 }
 
 impl LazilyResolvedCapture {
     fn new(capture: Capture) -> Self {
-        LazilyResolvedCapture { sync: Once::new(), capture: UnsafeCell::new(capture) }
+        let unresolved = capture
+            .frames
+            .iter()
+            .map(|frame| UnresolvedFrame {
+                ip: frame.frame.ip() as usize,
+                symbol_address: frame.frame.symbol_address() as usize,
+                module_base_address: frame.frame.module_base_address().map(|base| base as usize),
+                module_path: module_path(frame.frame.ip() as usize),
+            })
+            .collect();
+        LazilyResolvedCapture { sync: Once::new(), capture: UnsafeCell::new(capture), unresolved }
     }
 
     fn force(&self) -> &Capture {
@@ -493,4 +736,20 @@ fn ip(&self) -> *mut c_void {
             RawFrame::Fake => 1 as *mut c_void,
         }
     }
+
+    fn symbol_address(&self) -> *mut c_void {
+        match self {
+            RawFrame::Actual(frame) => frame.symbol_address(),
+            #[cfg(test)]
+            RawFrame::Fake => 1 as *mut c_void,
+        }
+    }
+
+    fn module_base_address(&self) -> Option<*mut c_void> {
+        match self {
+            RawFrame::Actual(frame) => frame.module_base_address(),
+            #[cfg(test)]
+            RawFrame::Fake => None,
+        }
+    }
 }
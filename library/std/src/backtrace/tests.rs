@@ -93,3 +93,58 @@ fn test_frames() {
 
     assert!(iter.all(|(f, e)| format!("{:#?}", f) == *e));
 }
+
+#[test]
+fn test_frame_accessors() {
+    let backtrace = Backtrace {
+        inner: Inner::Captured(LazilyResolvedCapture::new(Capture {
+            actual_start: 1,
+            resolved: true,
+            frames: generate_fake_frames(),
+        })),
+    };
+
+    let unresolved = backtrace.unresolved_frames();
+    assert_eq!(unresolved.len(), 3);
+    assert_eq!(unresolved[0].ip(), 1);
+    assert_eq!(unresolved[0].module_offset(), None);
+    assert_eq!(unresolved[0].module_path(), None);
+
+    let frames = backtrace.frames();
+    assert_eq!(frames.len(), unresolved.len());
+    assert_eq!(frames[1].ip() as usize, unresolved[1].ip());
+
+    let symbols = frames[2].symbols();
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(symbols[0].name().as_deref(), Some("std::rt::lang_start_internal"));
+    assert_eq!(symbols[0].raw_name(), Some(&b"std::rt::lang_start_internal"[..]));
+    assert_eq!(symbols[0].filename(), Some(PathBuf::from("rust/rt.rs")));
+    assert_eq!(symbols[0].lineno(), Some(300));
+    assert_eq!(symbols[0].colno(), Some(5));
+    assert_eq!(symbols[1].colno(), None);
+
+    let symbols = frames[1].symbols();
+    assert_eq!(symbols[0].filename(), None);
+    assert_eq!(symbols[0].lineno(), None);
+}
+
+#[test]
+fn test_unresolved_frame_module() {
+    let frame = UnresolvedFrame {
+        ip: 0x1234,
+        symbol_address: 0x1200,
+        module_base_address: Some(0x1000),
+        module_path: Some(PathBuf::from("/usr/lib/libfoo.so")),
+    };
+    assert_eq!(frame.module_offset(), Some(0x234));
+    assert_eq!(frame.module_path(), Some(Path::new("/usr/lib/libfoo.so")));
+
+    let frame = UnresolvedFrame {
+        ip: 0x1234,
+        symbol_address: 0x1200,
+        module_base_address: None,
+        module_path: None,
+    };
+    assert_eq!(frame.module_offset(), None);
+    assert_eq!(frame.module_path(), None);
+}
@@ -1,4 +1,4 @@
-use super::Error;
+use super::{request_ref, request_value, Error, Request};
 use crate::fmt;
 
 #[derive(Debug, PartialEq)]
@@ -35,3 +35,52 @@ fn downcasting() {
         Err(e) => assert_eq!(*e.downcast::<A>().unwrap(), A),
     }
 }
+
+#[derive(Debug)]
+struct Status {
+    code: u16,
+    reason: String,
+    source: A,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "status {}", self.code)
+    }
+}
+
+impl Error for Status {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+
+    fn provide<'a>(&'a self, request: &mut Request<'a>) {
+        request
+            .provide_value::<u16>(self.code)
+            .provide_value::<u16>(0)
+            .provide_ref::<str>(&self.reason)
+            .provide_value_with::<String>(|| format!("{} {}", self.code, self.reason));
+    }
+}
+
+#[test]
+fn request_by_type() {
+    let status = Status { code: 418, reason: "I'm a teapot".to_string(), source: A };
+    let err = &status as &(dyn Error + 'static);
+
+    // The first provided value of the requested type wins.
+    assert_eq!(request_value::<u16>(err), Some(418));
+    assert_eq!(request_ref::<str>(err), Some("I'm a teapot"));
+    assert_eq!(request_value::<String>(err).as_deref(), Some("418 I'm a teapot"));
+    assert_eq!(request_value::<u32>(err), None);
+    assert_eq!(request_ref::<String>(err), None);
+    assert_eq!(request_ref::<u16>(err), None);
+
+    // Forwarding impls pass requests through.
+    let boxed = Box::new(Status { code: 404, reason: String::new(), source: A });
+    assert_eq!(request_value::<u16>(&boxed), Some(404));
+    assert_eq!(request_value::<u16>(&&status), Some(418));
+
+    let codes: Vec<_> = err.chain().map(|e| request_value::<u16>(e)).collect();
+    assert_eq!(codes, [Some(418), None]);
+}
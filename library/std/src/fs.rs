@@ -10,6 +10,7 @@
 
 #[cfg(all(test, not(any(target_os = "emscripten", target_env = "sgx"))))]
 mod tests;
+mod walk;
 
 use crate::ffi::OsString;
 use crate::fmt;
@@ -19,6 +20,9 @@
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 use crate::time::SystemTime;
 
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub use self::walk::{walk_dir, WalkDir, WalkDirEntry, WalkDirOptions};
+
 /// A reference to an open file on the filesystem.
 ///
 /// An instance of a `File` can be read and/or written depending on what options
@@ -195,6 +199,34 @@ pub struct DirBuilder {
     recursive: bool,
 }
 
+/// A directory with a unique random name which is removed, together with its
+/// contents, when dropped.
+///
+/// On Unix the directory is created with mode `0o700`, so that only the
+/// current user can access it.
+///
+/// Errors while removing the directory on drop are ignored. Use
+/// [`TempDir::close`] to handle them.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_temp)]
+/// use std::fs::{self, TempDir};
+///
+/// fn main() -> std::io::Result<()> {
+///     let dir = TempDir::new()?;
+///     fs::write(dir.path().join("config.toml"), "debug = true")?;
+///     // ...
+///     dir.close()?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_temp", issue = "none")]
+pub struct TempDir {
+    path: Option<PathBuf>,
+}
+
 /// Indicates how large a buffer to pre-allocate before reading the entire file.
 fn initial_buffer_size(file: &File) -> usize {
     // Allocate one extra byte so the buffer doesn't need to grow before the
@@ -388,6 +420,55 @@ pub fn with_options() -> OpenOptions {
         OpenOptions::new()
     }
 
+    /// Creates a new anonymous temporary file in [`env::temp_dir()`], opened
+    /// for reading and writing.
+    ///
+    /// See [`File::create_temp_in`] for more details.
+    ///
+    /// [`env::temp_dir()`]: crate::env::temp_dir
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_temp)]
+    /// use std::fs::File;
+    /// use std::io::{Read, Seek, SeekFrom, Write};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut f = File::create_temp()?;
+    ///     f.write_all(b"scratch data")?;
+    ///     f.seek(SeekFrom::Start(0))?;
+    ///     let mut data = String::new();
+    ///     f.read_to_string(&mut data)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_temp", issue = "none")]
+    pub fn create_temp() -> io::Result<File> {
+        File::create_temp_in(crate::env::temp_dir())
+    }
+
+    /// Creates a new anonymous temporary file in `dir`, opened for reading and
+    /// writing.
+    ///
+    /// The file is removed from the file system as soon as possible, at the
+    /// latest when the returned `File` is closed, so it cannot be reopened by
+    /// path and no cleanup is needed.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Linux the file is created with `O_TMPFILE` and never has a name. On
+    /// other Unix platforms, or file systems without `O_TMPFILE` support, it is
+    /// created under a random name with `O_EXCL` and mode `0o600`, and unlinked
+    /// right away. On Windows it is created under a random name with
+    /// `FILE_FLAG_DELETE_ON_CLOSE`. Note that this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    #[unstable(feature = "fs_temp", issue = "none")]
+    pub fn create_temp_in<P: AsRef<Path>>(dir: P) -> io::Result<File> {
+        fs_imp::create_temp(dir.as_ref()).map(|inner| File { inner })
+    }
+
     /// Attempts to sync all OS-internal metadata to disk.
     ///
     /// This function will attempt to ensure that all in-memory data reaches the
@@ -2235,6 +2316,66 @@ fn as_inner_mut(&mut self) -> &mut fs_imp::DirBuilder {
     }
 }
 
+impl TempDir {
+    /// Creates a new temporary directory in [`env::temp_dir()`].
+    ///
+    /// [`env::temp_dir()`]: crate::env::temp_dir
+    #[unstable(feature = "fs_temp", issue = "none")]
+    pub fn new() -> io::Result<TempDir> {
+        TempDir::new_in(crate::env::temp_dir())
+    }
+
+    /// Creates a new temporary directory inside `dir`, which must already
+    /// exist.
+    #[unstable(feature = "fs_temp", issue = "none")]
+    pub fn new_in<P: AsRef<Path>>(dir: P) -> io::Result<TempDir> {
+        fs_imp::create_temp_dir(dir.as_ref()).map(|path| TempDir { path: Some(path) })
+    }
+
+    /// Returns the path to the directory.
+    #[unstable(feature = "fs_temp", issue = "none")]
+    pub fn path(&self) -> &Path {
+        self.path.as_deref().unwrap()
+    }
+
+    /// Consumes the `TempDir` without removing the directory, returning its
+    /// path.
+    #[unstable(feature = "fs_temp", issue = "none")]
+    pub fn into_path(mut self) -> PathBuf {
+        self.path.take().unwrap()
+    }
+
+    /// Removes the directory and its contents, returning any error
+    /// encountered.
+    #[unstable(feature = "fs_temp", issue = "none")]
+    pub fn close(mut self) -> io::Result<()> {
+        remove_dir_all(self.path.take().unwrap())
+    }
+}
+
+#[unstable(feature = "fs_temp", issue = "none")]
+impl AsRef<Path> for TempDir {
+    fn as_ref(&self) -> &Path {
+        self.path()
+    }
+}
+
+#[unstable(feature = "fs_temp", issue = "none")]
+impl fmt::Debug for TempDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TempDir").field(&self.path()).finish()
+    }
+}
+
+#[unstable(feature = "fs_temp", issue = "none")]
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        if let Some(path) = self.path.take() {
+            let _ = remove_dir_all(path);
+        }
+    }
+}
+
 /// Returns `Ok(true)` if the path points at an existing entity.
 ///
 /// This function will traverse symbolic links to query information about the
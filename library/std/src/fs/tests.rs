@@ -206,6 +206,15 @@ fn file_test_io_seek_and_write() {
     assert!(read_str == final_msg);
 }
 
+#[test]
+fn file_test_io_is_terminal() {
+    use crate::io::IsTerminal;
+
+    let tmpdir = tmpdir();
+    let file = check!(File::create(&tmpdir.join("file_rt_io_file_test_is_terminal.txt")));
+    assert!(!file.is_terminal());
+}
+
 #[test]
 fn file_test_io_seek_shakedown() {
     //                   01234567890123
@@ -1392,3 +1401,193 @@ fn symlink_hard_link() {
     // "hard_link" should still appear as a symlink.
     assert!(check!(fs::symlink_metadata(tmpdir.join("hard_link"))).file_type().is_symlink());
 }
+
+#[test]
+#[cfg(target_os = "linux")]
+fn memfd_seals() {
+    use crate::os::linux::fs::{FileExt, MemfdOptions, Seals};
+
+    let mut file = check!(MemfdOptions::new().allow_sealing(true).create("memfd_seals"));
+    check!(file.write_all(b"hello"));
+    assert_eq!(check!(file.seals()), Seals::empty());
+
+    check!(file.add_seals(Seals::SHRINK | Seals::GROW));
+    let seals = check!(file.seals());
+    assert!(seals.contains(Seals::SHRINK | Seals::GROW));
+    assert!(!seals.contains(Seals::SEAL));
+    assert!(file.set_len(2).is_err());
+    assert!(file.write_all(b" world").is_err());
+
+    check!(file.seek(SeekFrom::Start(0)));
+    let mut contents = String::new();
+    check!(file.read_to_string(&mut contents));
+    assert_eq!(contents, "hello");
+
+    let unsealable = check!(MemfdOptions::new().create("memfd_unsealable"));
+    assert!(check!(unsealable.seals()).contains(Seals::SEAL));
+    assert!(unsealable.add_seals(Seals::WRITE).is_err());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn sendfile_splice_with_offsets() {
+    use crate::os::linux::fs::{sendfile, splice, SpliceFlags};
+
+    let tmpdir = tmpdir();
+    let source_path = tmpdir.join("sendfile_source.txt");
+    check!(fs::write(&source_path, b"0123456789"));
+    let source = check!(File::open(&source_path));
+    let mut sink = check!(File::create(tmpdir.join("sendfile_sink.txt")));
+
+    let mut offset = 4;
+    assert_eq!(check!(sendfile(&source, Some(&mut offset), &sink, 3)), 3);
+    assert_eq!(offset, 7);
+    // The offset is used instead of the file position of the input.
+    assert_eq!(check!(source.stream_position()), 0);
+    assert_eq!(check!(sendfile(&source, None, &sink, 2)), 2);
+    assert_eq!(check!(sink.stream_position()), 5);
+
+    let (mut reader, writer) = check!(crate::io::pipe());
+    let mut in_offset = 8;
+    let n = check!(splice(&source, Some(&mut in_offset), &writer, None, 5, SpliceFlags::MOVE));
+    assert_eq!(n, 2);
+    assert_eq!(in_offset, 10);
+    let mut out_offset = 1;
+    let n = check!(splice(&reader, None, &sink, Some(&mut out_offset), 2, SpliceFlags::empty()));
+    assert_eq!(n, 2);
+    assert_eq!(out_offset, 3);
+    drop(writer);
+    let mut rest = Vec::new();
+    check!(reader.read_to_end(&mut rest));
+    assert!(rest.is_empty());
+
+    check!(sink.flush());
+    // "456" and "01" were sent, then "89" spliced over the second byte.
+    assert_eq!(check!(fs::read(tmpdir.join("sendfile_sink.txt"))), b"48901");
+}
+
+#[test]
+fn walk_dir_orders_and_depth() {
+    use crate::fs::WalkDirOptions;
+
+    let tmpdir = tmpdir();
+    let root = tmpdir.join("walk");
+    check!(fs::create_dir_all(root.join("a/b")));
+    check!(fs::create_dir_all(root.join("c")));
+    check!(File::create(root.join("a/b/f1")));
+    check!(File::create(root.join("a/f2")));
+    check!(File::create(root.join("c/f3")));
+    check!(File::create(root.join("f4")));
+
+    let walk = |opts: &WalkDirOptions| -> Vec<(String, usize)> {
+        check!(opts.walk(&root))
+            .map(|entry| {
+                let entry = check!(entry);
+                let rel = entry.path().strip_prefix(&root).unwrap();
+                (rel.to_str().unwrap().replace('\\', "/"), entry.depth())
+            })
+            .collect()
+    };
+
+    let mut opts = WalkDirOptions::new();
+    opts.sort_by_file_name();
+    assert_eq!(
+        walk(&opts),
+        [("a", 1), ("a/b", 2), ("a/b/f1", 3), ("a/f2", 2), ("c", 1), ("c/f3", 2), ("f4", 1)]
+            .iter()
+            .map(|&(p, d)| (p.to_string(), d))
+            .collect::<Vec<_>>()
+    );
+
+    opts.breadth_first(true);
+    assert_eq!(
+        walk(&opts),
+        [("a", 1), ("c", 1), ("f4", 1), ("a/b", 2), ("a/f2", 2), ("c/f3", 2), ("a/b/f1", 3)]
+            .iter()
+            .map(|&(p, d)| (p.to_string(), d))
+            .collect::<Vec<_>>()
+    );
+
+    opts.max_depth(1);
+    assert_eq!(walk(&opts).len(), 3);
+    opts.max_depth(0);
+    assert!(walk(&opts).is_empty());
+
+    let unsorted = check!(fs::walk_dir(&root)).count();
+    assert_eq!(unsorted, 7);
+}
+
+#[test]
+fn walk_dir_symlink_loop() {
+    use crate::fs::WalkDirOptions;
+
+    let tmpdir = tmpdir();
+    if !got_symlink_permission(&tmpdir) {
+        return;
+    };
+    let root = tmpdir.join("walk_loop");
+    check!(fs::create_dir_all(root.join("sub")));
+    check!(symlink_dir("..", root.join("sub/up")));
+
+    // Without following links the link is yielded as a plain entry.
+    let entries: Vec<_> = check!(fs::walk_dir(&root)).map(|e| check!(e)).collect();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().any(|e| e.path_is_symlink() && e.file_type().is_symlink()));
+
+    // Following links detects the loop instead of recursing forever.
+    let results: Vec<_> = check!(WalkDirOptions::new().follow_links(true).walk(&root)).collect();
+    assert_eq!(results.iter().filter(|r| r.is_err()).count(), 1);
+    let link = results.iter().flatten().find(|e| e.path_is_symlink()).unwrap();
+    assert!(link.file_type().is_dir());
+}
+
+#[test]
+fn create_temp_file() {
+    let tmpdir = tmpdir();
+    let mut f = check!(File::create_temp_in(tmpdir.path()));
+    check!(f.write_all(b"temporary"));
+    check!(f.seek(SeekFrom::Start(0)));
+    let mut contents = String::new();
+    check!(f.read_to_string(&mut contents));
+    assert_eq!(contents, "temporary");
+
+    // The file is never visible by name once it is closed.
+    drop(f);
+    assert_eq!(check!(fs::read_dir(tmpdir.path())).count(), 0);
+}
+
+#[test]
+fn temp_dir_cleanup() {
+    let tmpdir = tmpdir();
+    let dir = check!(fs::TempDir::new_in(tmpdir.path()));
+    let path = dir.path().to_path_buf();
+    assert!(path.starts_with(tmpdir.path()));
+    assert!(path.is_dir());
+    check!(fs::create_dir(path.join("sub")));
+    check!(fs::write(path.join("sub/file"), b"data"));
+
+    let other = check!(fs::TempDir::new_in(tmpdir.path()));
+    assert_ne!(other.path(), path);
+    let kept = other.into_path();
+    assert!(kept.is_dir());
+
+    drop(dir);
+    assert!(!path.exists());
+    assert!(kept.is_dir());
+
+    let closed = check!(fs::TempDir::new_in(tmpdir.path()));
+    let closed_path = closed.path().to_path_buf();
+    check!(closed.close());
+    assert!(!closed_path.exists());
+}
+
+#[test]
+#[cfg(unix)]
+fn temp_dir_private_mode() {
+    use crate::os::unix::fs::PermissionsExt;
+
+    let tmpdir = tmpdir();
+    let dir = check!(fs::TempDir::new_in(tmpdir.path()));
+    let mode = check!(fs::metadata(dir.path())).permissions().mode();
+    assert_eq!(mode & 0o777, 0o700);
+}
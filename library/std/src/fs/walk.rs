@@ -0,0 +1,461 @@
+use super::{DirEntry, FileType, Metadata, ReadDir};
+use crate::cmp::Ordering;
+use crate::collections::VecDeque;
+use crate::ffi::OsString;
+use crate::fmt;
+use crate::io;
+use crate::path::{Path, PathBuf};
+use crate::sync::Arc;
+use crate::vec;
+
+type SortFn = dyn Fn(&DirEntry, &DirEntry) -> Ordering + Send + Sync;
+
+/// Options which configure how a directory tree is traversed by [`walk_dir`].
+///
+/// # Examples
+///
+/// List every Rust source file up to three levels below the current directory,
+/// visiting the entries of each directory in alphabetical order:
+///
+/// ```no_run
+/// #![feature(fs_walk_dir)]
+/// use std::fs::WalkDirOptions;
+///
+/// fn main() -> std::io::Result<()> {
+///     for entry in WalkDirOptions::new().max_depth(3).sort_by_file_name().walk(".")? {
+///         let entry = entry?;
+///         if entry.path().extension().map_or(false, |ext| ext == "rs") {
+///             println!("{}", entry.path().display());
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub struct WalkDirOptions {
+    breadth_first: bool,
+    follow_links: bool,
+    max_depth: usize,
+    sort: Option<Arc<SortFn>>,
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl WalkDirOptions {
+    /// Creates a blank new set of options, which walk the whole tree depth-first
+    /// without following symbolic links or sorting entries.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn new() -> WalkDirOptions {
+        WalkDirOptions {
+            breadth_first: false,
+            follow_links: false,
+            max_depth: usize::MAX,
+            sort: None,
+        }
+    }
+
+    /// Sets whether the tree is walked breadth-first instead of depth-first.
+    ///
+    /// Depth-first order yields each directory immediately followed by its
+    /// contents, while breadth-first order yields all entries at one depth
+    /// before any entry at the next. A breadth-first walk keeps a handle open
+    /// for every directory whose subdirectories are still queued.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn breadth_first(&mut self, breadth_first: bool) -> &mut WalkDirOptions {
+        self.breadth_first = breadth_first;
+        self
+    }
+
+    /// Sets whether symbolic links to directories are followed.
+    ///
+    /// When links are followed, a link pointing to one of its own ancestors is
+    /// not descended into; an error is yielded after the link instead.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn follow_links(&mut self, follow_links: bool) -> &mut WalkDirOptions {
+        self.follow_links = follow_links;
+        self
+    }
+
+    /// Sets the maximum depth of entries yielded by the walk.
+    ///
+    /// The entries directly inside the root have a depth of 1, so a maximum
+    /// depth of 1 yields the same entries as [`read_dir`] and a maximum depth
+    /// of 0 yields nothing.
+    ///
+    /// [`read_dir`]: super::read_dir
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn max_depth(&mut self, max_depth: usize) -> &mut WalkDirOptions {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets a comparison function used to order the entries of each directory.
+    ///
+    /// Sorting requires the entries of a directory to be read in full before
+    /// any of them is yielded.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn sort_by<F>(&mut self, compare: F) -> &mut WalkDirOptions
+    where
+        F: Fn(&DirEntry, &DirEntry) -> Ordering + Send + Sync + 'static,
+    {
+        self.sort = Some(Arc::new(compare));
+        self
+    }
+
+    /// Orders the entries of each directory by their file name.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn sort_by_file_name(&mut self) -> &mut WalkDirOptions {
+        self.sort_by(|a, b| a.file_name().cmp(&b.file_name()))
+    }
+
+    /// Starts walking the tree below `path` with the options specified by
+    /// `self`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` cannot be opened as a
+    /// directory. Errors encountered further down the tree are yielded by the
+    /// returned iterator.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn walk<P: AsRef<Path>>(&self, path: P) -> io::Result<WalkDir> {
+        let dir = super::read_dir(path.as_ref())?;
+        let ancestors = if self.follow_links {
+            Some(Arc::new(Ancestor { id: dir_id(&dir, path.as_ref())?, parent: None }))
+        } else {
+            None
+        };
+        let mut walk = WalkDir { opts: self.clone(), frames: Vec::new(), pending: VecDeque::new() };
+        if self.max_depth > 0 {
+            let frame = walk.frame(dir, 1, ancestors);
+            walk.frames.push(frame);
+        }
+        Ok(walk)
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl Default for WalkDirOptions {
+    fn default() -> WalkDirOptions {
+        WalkDirOptions::new()
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl fmt::Debug for WalkDirOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WalkDirOptions")
+            .field("breadth_first", &self.breadth_first)
+            .field("follow_links", &self.follow_links)
+            .field("max_depth", &self.max_depth)
+            .field("sorted", &self.sort.is_some())
+            .finish()
+    }
+}
+
+/// Returns an iterator over all entries below a directory, recursively.
+///
+/// This is a shorthand for [`WalkDirOptions::new().walk(path)`], which walks
+/// the tree depth-first without following symbolic links. The root itself is
+/// not yielded.
+///
+/// [`WalkDirOptions::new().walk(path)`]: WalkDirOptions::walk
+///
+/// # Platform-specific behavior
+///
+/// On Unix, subdirectories are opened relative to the already opened parent
+/// directory with `openat`, and symbolic links are never followed unless
+/// requested. Renaming or replacing directories along the way can therefore
+/// not redirect the walk outside of the tree. Other platforms open
+/// subdirectories by path.
+///
+/// # Errors
+///
+/// This function will return an error if `path` does not exist, is not a
+/// directory, or the user lacks permission to read it.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_walk_dir)]
+/// use std::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     for entry in fs::walk_dir("src")? {
+///         let entry = entry?;
+///         println!("{}{}", "  ".repeat(entry.depth() - 1), entry.file_name().to_string_lossy());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub fn walk_dir<P: AsRef<Path>>(path: P) -> io::Result<WalkDir> {
+    WalkDirOptions::new().walk(path)
+}
+
+/// Iterator over the entries of a directory tree.
+///
+/// This iterator is returned from [`walk_dir`] and [`WalkDirOptions::walk`].
+/// It will yield an [`Err`] for every directory which could not be read, after
+/// the entry for that directory itself, and continue with the rest of the
+/// tree.
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub struct WalkDir {
+    opts: WalkDirOptions,
+    // The directories being read. A depth-first walk reads the last one and
+    // keeps one per level, a breadth-first walk only ever has one open.
+    frames: Vec<Frame>,
+    // Directories which have been yielded but not opened yet.
+    pending: VecDeque<Pending>,
+}
+
+struct Frame {
+    entries: Entries,
+    depth: usize,
+    ancestors: Option<Arc<Ancestor>>,
+}
+
+enum Entries {
+    Unsorted(ReadDir),
+    Sorted(vec::IntoIter<io::Result<DirEntry>>),
+}
+
+struct Pending {
+    entry: DirEntry,
+    follow: bool,
+    depth: usize,
+    ancestors: Option<Arc<Ancestor>>,
+}
+
+/// The identity of a directory on the path from the root, used to detect
+/// symbolic links pointing back up the tree.
+struct Ancestor {
+    id: DirId,
+    parent: Option<Arc<Ancestor>>,
+}
+
+#[cfg(unix)]
+type DirId = (u64, u64);
+#[cfg(not(unix))]
+type DirId = PathBuf;
+
+#[cfg(unix)]
+fn dir_id(dir: &ReadDir, _path: &Path) -> io::Result<DirId> {
+    use crate::os::unix::fs::MetadataExt;
+
+    let meta = Metadata(dir.0.file_attr()?);
+    Ok((meta.dev(), meta.ino()))
+}
+
+#[cfg(not(unix))]
+fn dir_id(_dir: &ReadDir, path: &Path) -> io::Result<DirId> {
+    super::canonicalize(path)
+}
+
+#[cfg(unix)]
+fn open_dir(entry: &DirEntry, follow: bool) -> io::Result<ReadDir> {
+    entry.0.open_dir(follow).map(ReadDir)
+}
+
+#[cfg(not(unix))]
+fn open_dir(entry: &DirEntry, _follow: bool) -> io::Result<ReadDir> {
+    super::read_dir(entry.path())
+}
+
+// Like `open_dir`, this looks the entry up relative to its already opened
+// parent on Unix, so that the type checked is that of the file opened next.
+#[cfg(unix)]
+fn target_metadata(entry: &DirEntry) -> io::Result<Metadata> {
+    entry.0.metadata_follow().map(Metadata)
+}
+
+#[cfg(not(unix))]
+fn target_metadata(entry: &DirEntry) -> io::Result<Metadata> {
+    super::metadata(entry.path())
+}
+
+impl WalkDir {
+    fn frame(&self, dir: ReadDir, depth: usize, ancestors: Option<Arc<Ancestor>>) -> Frame {
+        let entries = match self.opts.sort {
+            Some(ref compare) => {
+                let mut entries: Vec<_> = dir.collect();
+                entries.sort_by(|a, b| match (a, b) {
+                    (Ok(a), Ok(b)) => compare(a, b),
+                    (Ok(_), Err(_)) => Ordering::Less,
+                    (Err(_), Ok(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => Ordering::Equal,
+                });
+                Entries::Sorted(entries.into_iter())
+            }
+            None => Entries::Unsorted(dir),
+        };
+        Frame { entries, depth, ancestors }
+    }
+
+    fn open(&mut self, pending: Pending) -> io::Result<()> {
+        let dir = open_dir(&pending.entry, pending.follow)?;
+        let ancestors = match pending.ancestors {
+            Some(parent) => {
+                let path = pending.entry.path();
+                let id = dir_id(&dir, &path)?;
+                let mut ancestor = Some(&parent);
+                while let Some(a) = ancestor {
+                    if a.id == id {
+                        return Err(io::Error::new(
+                            io::ErrorKind::Other,
+                            format!(
+                                "file system loop found: {} points to an ancestor",
+                                path.display()
+                            ),
+                        ));
+                    }
+                    ancestor = a.parent.as_ref();
+                }
+                Some(Arc::new(Ancestor { id, parent: Some(parent) }))
+            }
+            None => None,
+        };
+        let frame = self.frame(dir, pending.depth, ancestors);
+        self.frames.push(frame);
+        Ok(())
+    }
+
+    fn visit(
+        &mut self,
+        entry: DirEntry,
+        depth: usize,
+        ancestors: Option<Arc<Ancestor>>,
+    ) -> io::Result<WalkDirEntry> {
+        let mut file_type = entry.file_type()?;
+        let path = entry.path();
+        let is_symlink = file_type.is_symlink();
+        if is_symlink && self.opts.follow_links {
+            match target_metadata(&entry) {
+                Ok(meta) => file_type = meta.file_type(),
+                // A dangling link is yielded as the link itself.
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+        let walk_entry = WalkDirEntry { path, file_type, depth, is_symlink };
+        if file_type.is_dir() && depth < self.opts.max_depth {
+            let follow = is_symlink;
+            self.pending.push_back(Pending { entry, follow, depth: depth + 1, ancestors });
+        }
+        Ok(walk_entry)
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl Iterator for WalkDir {
+    type Item = io::Result<WalkDirEntry>;
+
+    fn next(&mut self) -> Option<io::Result<WalkDirEntry>> {
+        loop {
+            // Descend into the directory yielded last, or once the current
+            // level is done, into the directory queued first.
+            let pending = if self.opts.breadth_first {
+                if self.frames.is_empty() { self.pending.pop_front() } else { None }
+            } else {
+                self.pending.pop_back()
+            };
+            if let Some(pending) = pending {
+                if let Err(e) = self.open(pending) {
+                    return Some(Err(e));
+                }
+            }
+
+            let frame = self.frames.last_mut()?;
+            let next = match frame.entries {
+                Entries::Unsorted(ref mut dir) => dir.next(),
+                Entries::Sorted(ref mut entries) => entries.next(),
+            };
+            match next {
+                Some(Ok(entry)) => {
+                    let (depth, ancestors) = (frame.depth, frame.ancestors.clone());
+                    return Some(self.visit(entry, depth, ancestors));
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.frames.pop();
+                }
+            }
+        }
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl fmt::Debug for WalkDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WalkDir").field("opts", &self.opts).finish_non_exhaustive()
+    }
+}
+
+/// An entry yielded by the [`WalkDir`] iterator.
+#[derive(Clone, Debug)]
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub struct WalkDirEntry {
+    path: PathBuf,
+    file_type: FileType,
+    depth: usize,
+    is_symlink: bool,
+}
+
+impl WalkDirEntry {
+    /// Returns the full path to the file that this entry represents, which is
+    /// the path passed to [`walk_dir`] joined with the names of all directories
+    /// leading to the entry.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Consumes the entry, returning its full path.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn into_path(self) -> PathBuf {
+        self.path
+    }
+
+    /// Returns the bare file name of this entry without any other leading path
+    /// component.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn file_name(&self) -> OsString {
+        self.path.file_name().unwrap_or(self.path.as_os_str()).to_os_string()
+    }
+
+    /// Returns the depth of this entry below the root, where entries directly
+    /// inside the root have a depth of 1.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the file type of this entry.
+    ///
+    /// If symbolic links are followed, this is the type of the link's target
+    /// unless the link is dangling.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn file_type(&self) -> FileType {
+        self.file_type
+    }
+
+    /// Returns `true` if this entry is a symbolic link, whether or not it was
+    /// followed.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn path_is_symlink(&self) -> bool {
+        self.is_symlink
+    }
+
+    /// Queries the metadata of this entry, following symbolic links if the
+    /// walk does.
+    ///
+    /// The metadata is looked up by path, so it reflects the file system at the
+    /// time of the call.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn metadata(&self) -> io::Result<Metadata> {
+        if self.is_symlink && !self.file_type.is_symlink() {
+            super::metadata(&self.path)
+        } else {
+            super::symlink_metadata(&self.path)
+        }
+    }
+}
@@ -3,6 +3,7 @@
 use crate::io::{
     self, BufRead, Initializer, IoSliceMut, Read, Seek, SeekFrom, SizeHint, DEFAULT_BUF_SIZE,
 };
+use crate::sys_common::memchr;
 
 /// The `BufReader<R>` struct adds buffering to any reader.
 ///
@@ -97,6 +98,155 @@ pub fn with_capacity(capacity: usize, inner: R) -> BufReader<R> {
             BufReader { inner, buf, pos: 0, cap: 0 }
         }
     }
+
+    /// Returns the buffered data after reading until at least `n` bytes are
+    /// available, without consuming any of it.
+    ///
+    /// Unlike [`fill_buf`], which reads from the underlying reader only once
+    /// the buffer is empty, this keeps reading until `n` bytes are buffered.
+    /// The returned slice is shorter than `n` only if EOF was reached. Data
+    /// already in the buffer may be moved to make room.
+    ///
+    /// [`fill_buf`]: BufRead::fill_buf
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than the [capacity] of the buffer.
+    ///
+    /// [capacity]: BufReader::capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufreader_peek)]
+    /// use std::io::{BufReader, Read};
+    ///
+    /// let data: &[u8] = &[0x00, 0x05, b'h', b'e', b'l', b'l', b'o'];
+    /// let mut reader = BufReader::with_capacity(16, data);
+    ///
+    /// let header = reader.peek(2)?;
+    /// let len = u16::from_be_bytes([header[0], header[1]]) as usize;
+    /// assert_eq!(len, 5);
+    /// assert_eq!(reader.peek(2 + len)?.len(), 2 + len);
+    ///
+    /// let mut message = [0; 7];
+    /// reader.read_exact(&mut message)?;
+    /// assert_eq!(&message[2..], b"hello");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[unstable(feature = "bufreader_peek", issue = "none")]
+    pub fn peek(&mut self, n: usize) -> io::Result<&[u8]> {
+        assert!(n <= self.capacity(), "cannot peek more bytes than the capacity of the buffer");
+        self.fill_to(n)?;
+        Ok(self.buffer())
+    }
+
+    /// Reads the next line, returning it as a slice borrowed from the internal
+    /// buffer, or `None` at EOF.
+    ///
+    /// Like [`lines`], the returned line does not include the trailing newline
+    /// byte (the `0xA` byte) or `CRLF` (`0xD`, `0xA` bytes). Unlike `lines`, no
+    /// allocation takes place and the line is not required to be valid UTF-8,
+    /// but the borrow of the reader has to end before the next line is read.
+    ///
+    /// [`lines`]: BufRead::lines
+    ///
+    /// # Errors
+    ///
+    /// Besides errors from the underlying reader, an error of kind
+    /// [`InvalidData`] is returned if a line and its trailing newline do not
+    /// fit into the buffer. The data read so far stays buffered. A reader with
+    /// zero capacity fits no line at all; it reads a single byte to tell a
+    /// line from EOF, and that byte is lost with the error.
+    ///
+    /// [`InvalidData`]: io::ErrorKind::InvalidData
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufreader_next_line)]
+    /// use std::io::BufReader;
+    ///
+    /// let data: &[u8] = b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\n";
+    /// let mut reader = BufReader::new(data);
+    ///
+    /// let mut headers = 0;
+    /// while let Some(line) = reader.next_line() {
+    ///     let line = line?;
+    ///     if line.is_empty() {
+    ///         break;
+    ///     }
+    ///     headers += 1;
+    /// }
+    /// assert_eq!(headers, 2);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[unstable(feature = "bufreader_next_line", issue = "none")]
+    pub fn next_line(&mut self) -> Option<io::Result<&[u8]>> {
+        let mut searched = 0;
+        loop {
+            let start = self.pos;
+            if let Some(i) = memchr::memchr(b'\n', &self.buf[start + searched..self.cap]) {
+                let newline = start + searched + i;
+                self.pos = newline + 1;
+                let line = &self.buf[start..newline];
+                return Some(Ok(line.strip_suffix(b"\r").unwrap_or(line)));
+            }
+            searched = self.cap - start;
+            if searched == self.buf.len() {
+                if self.buf.is_empty() {
+                    let mut byte = [0];
+                    loop {
+                        match self.inner.read(&mut byte) {
+                            Ok(0) => return None,
+                            Ok(_) => break,
+                            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                            Err(e) => return Some(Err(e)),
+                        }
+                    }
+                }
+                return Some(Err(io::Error::new_const(
+                    io::ErrorKind::InvalidData,
+                    &"line does not fit into the buffer",
+                )));
+            }
+            if let Err(e) = self.fill_to(searched + 1) {
+                return Some(Err(e));
+            }
+            if self.cap - self.pos == searched {
+                // EOF, the rest of the data is the last line.
+                if searched == 0 {
+                    return None;
+                }
+                let start = self.pos;
+                self.pos = self.cap;
+                return Some(Ok(&self.buf[start..self.cap]));
+            }
+        }
+    }
+
+    /// Reads from the underlying reader until at least `n` bytes are buffered
+    /// or EOF is reached, moving the buffered data to the front if needed.
+    fn fill_to(&mut self, n: usize) -> io::Result<()> {
+        debug_assert!(n <= self.buf.len());
+        if self.cap - self.pos >= n {
+            return Ok(());
+        }
+        if self.buf.len() - self.pos < n {
+            self.buf.copy_within(self.pos..self.cap, 0);
+            self.cap -= self.pos;
+            self.pos = 0;
+        }
+        while self.cap - self.pos < n {
+            match self.inner.read(&mut self.buf[self.cap..]) {
+                Ok(0) => break,
+                Ok(read) => self.cap += read,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<R> BufReader<R> {
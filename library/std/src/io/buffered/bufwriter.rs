@@ -115,6 +115,16 @@ pub fn with_capacity(capacity: usize, inner: W) -> BufWriter<W> {
         BufWriter { inner: Some(inner), buf: Vec::with_capacity(capacity), panicked: false }
     }
 
+    /// Writes out the buffer and replaces it with an empty one that can hold
+    /// `capacity` bytes.
+    ///
+    /// On error the buffer is left as it is.
+    pub(in crate::io) fn set_capacity(&mut self, capacity: usize) -> io::Result<()> {
+        self.flush_buf()?;
+        self.buf = Vec::with_capacity(capacity);
+        Ok(())
+    }
+
     /// Send data in our local buffer into the inner writer, looping as
     /// necessary until either it's all been sent or an error occurs.
     ///
@@ -374,6 +374,60 @@ fn test_lines() {
     assert!(it.next().is_none());
 }
 
+#[test]
+fn test_peek() {
+    let inner = ShortReader { lengths: vec![1, 1, 2, 0] };
+    let mut reader = BufReader::with_capacity(4, inner);
+    assert_eq!(reader.peek(3).unwrap().len(), 4);
+    reader.consume(3);
+    assert_eq!(reader.peek(2).unwrap().len(), 1);
+    assert_eq!(reader.buffer().len(), 1);
+
+    let inner: &[u8] = &[0, 1, 2, 3, 4, 5];
+    let mut reader = BufReader::with_capacity(4, inner);
+    assert_eq!(reader.peek(1).unwrap(), [0, 1, 2, 3]);
+    reader.consume(3);
+    assert_eq!(reader.peek(3).unwrap(), [3, 4, 5]);
+    assert_eq!(reader.peek(4).unwrap(), [3, 4, 5]);
+    reader.consume(3);
+    assert_eq!(reader.peek(1).unwrap(), []);
+}
+
+#[test]
+#[should_panic]
+fn test_peek_larger_than_capacity() {
+    let inner: &[u8] = &[0, 1, 2];
+    let mut reader = BufReader::with_capacity(2, inner);
+    let _ = reader.peek(3);
+}
+
+#[test]
+fn test_next_line() {
+    let in_buf: &[u8] = b"a\r\nbc\n\ndef";
+    let mut reader = BufReader::with_capacity(4, in_buf);
+    assert_eq!(reader.next_line().unwrap().unwrap(), b"a");
+    assert_eq!(reader.next_line().unwrap().unwrap(), b"bc");
+    assert_eq!(reader.next_line().unwrap().unwrap(), b"");
+    assert_eq!(reader.next_line().unwrap().unwrap(), b"def");
+    assert!(reader.next_line().is_none());
+
+    let in_buf: &[u8] = b"abcd\nef";
+    let mut reader = BufReader::with_capacity(3, in_buf);
+    let err = reader.next_line().unwrap().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(reader.buffer(), b"abc");
+
+    let in_buf: &[u8] = b"";
+    let mut reader = BufReader::with_capacity(0, in_buf);
+    assert!(reader.next_line().is_none());
+
+    let in_buf: &[u8] = b"\n";
+    let mut reader = BufReader::with_capacity(0, in_buf);
+    let err = reader.next_line().unwrap().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(reader.next_line().is_none());
+}
+
 #[test]
 fn test_short_reads() {
     let inner = ShortReader { lengths: vec![0, 1, 2, 0, 1, 0] };
@@ -191,6 +191,14 @@ pub enum ErrorKind {
     /// to allocate enough memory.
     #[stable(feature = "out_of_memory_error", since = "1.54.0")]
     OutOfMemory,
+
+    /// A temporary failure occurred, and the operation may succeed if it is
+    /// retried later.
+    ///
+    /// For example, a host name lookup can fail like this when no name
+    /// server could be reached.
+    #[unstable(feature = "dns_resolver", issue = "none")]
+    TemporaryFailure,
 }
 
 impl ErrorKind {
@@ -216,6 +224,7 @@ pub(crate) fn as_str(&self) -> &'static str {
             ErrorKind::UnexpectedEof => "unexpected end of file",
             ErrorKind::Unsupported => "unsupported",
             ErrorKind::OutOfMemory => "out of memory",
+            ErrorKind::TemporaryFailure => "temporary failure",
         }
     }
 }
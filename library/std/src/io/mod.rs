@@ -272,9 +272,15 @@
 pub use self::cursor::Cursor;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::error::{Error, ErrorKind, Result};
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub use self::pipe::{pipe, PipeReader, PipeWriter};
 #[unstable(feature = "internal_output_capture", issue = "none")]
 #[doc(no_inline, hidden)]
 pub use self::stdio::set_output_capture;
+#[unstable(feature = "stdio_buffering", issue = "none")]
+pub use self::stdio::BufferMode;
+#[unstable(feature = "is_terminal", issue = "none")]
+pub use self::stdio::IsTerminal;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::stdio::{stderr, stdin, stdout, Stderr, Stdin, Stdout};
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -289,6 +295,7 @@
 mod cursor;
 mod error;
 mod impls;
+mod pipe;
 pub mod prelude;
 mod stdio;
 mod util;
@@ -299,6 +306,10 @@ pub(crate) fn cleanup() {
     stdio::cleanup()
 }
 
+pub(crate) fn flush_on_panic() {
+    stdio::flush_on_panic()
+}
+
 struct Guard<'a> {
     buf: &'a mut Vec<u8>,
     len: usize,
@@ -1892,6 +1903,64 @@ fn read_until<R: BufRead + ?Sized>(r: &mut R, delim: u8, buf: &mut Vec<u8>) -> R
     }
 }
 
+fn read_until_any<R: BufRead + ?Sized>(
+    r: &mut R,
+    delims: &[u8],
+    buf: &mut Vec<u8>,
+) -> Result<usize> {
+    let mut read = 0;
+    loop {
+        let (done, used) = {
+            let available = match r.fill_buf() {
+                Ok(n) => n,
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            let found = match *delims {
+                [delim] => memchr::memchr(delim, available),
+                _ => available.iter().position(|b| delims.contains(b)),
+            };
+            match found {
+                Some(i) => {
+                    buf.extend_from_slice(&available[..=i]);
+                    (true, i + 1)
+                }
+                None => {
+                    buf.extend_from_slice(available);
+                    (false, available.len())
+                }
+            }
+        };
+        r.consume(used);
+        read += used;
+        if done || used == 0 {
+            return Ok(read);
+        }
+    }
+}
+
+fn skip_until<R: BufRead + ?Sized>(r: &mut R, delim: u8) -> Result<usize> {
+    let mut read = 0;
+    loop {
+        let (done, used) = {
+            let available = match r.fill_buf() {
+                Ok(n) => n,
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            match memchr::memchr(delim, available) {
+                Some(i) => (true, i + 1),
+                None => (false, available.len()),
+            }
+        };
+        r.consume(used);
+        read += used;
+        if done || used == 0 {
+            return Ok(read);
+        }
+    }
+}
+
 /// A `BufRead` is a type of `Read`er which has an internal buffer, allowing it
 /// to perform extra ways of reading.
 ///
@@ -2095,6 +2164,83 @@ fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> Result<usize> {
         read_until(self, byte, buf)
     }
 
+    /// Read all bytes into `buf` until any of the bytes in `delims` or EOF is
+    /// reached.
+    ///
+    /// This behaves like [`read_until`], except that reading stops at the first
+    /// byte which is contained in `delims`. That byte is appended to `buf` as
+    /// well, and can be inspected to tell which delimiter was found. An empty
+    /// `delims` reads until EOF.
+    ///
+    /// If successful, this function will return the total number of bytes read.
+    ///
+    /// [`read_until`]: BufRead::read_until
+    ///
+    /// # Errors
+    ///
+    /// This function has the same error semantics as [`read_until`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufread_read_until_any)]
+    /// use std::io::{self, BufRead};
+    ///
+    /// let mut cursor = io::Cursor::new(b"key=value;next");
+    /// let mut buf = vec![];
+    ///
+    /// let num_bytes = cursor.read_until_any(b"=;", &mut buf)
+    ///     .expect("reading from cursor won't fail");
+    /// assert_eq!(num_bytes, 4);
+    /// assert_eq!(buf, b"key=");
+    /// buf.clear();
+    ///
+    /// let num_bytes = cursor.read_until_any(b"=;", &mut buf)
+    ///     .expect("reading from cursor won't fail");
+    /// assert_eq!(num_bytes, 6);
+    /// assert_eq!(buf, b"value;");
+    /// ```
+    #[unstable(feature = "bufread_read_until_any", issue = "none")]
+    fn read_until_any(&mut self, delims: &[u8], buf: &mut Vec<u8>) -> Result<usize> {
+        read_until_any(self, delims, buf)
+    }
+
+    /// Skip all bytes until the delimiter `byte` or EOF is reached.
+    ///
+    /// This function will read (and discard) bytes from the underlying stream
+    /// until the delimiter or EOF is found. It is the counterpart of
+    /// [`read_until`] for data which is not needed, and does not allocate.
+    ///
+    /// If successful, this function will return the total number of bytes
+    /// skipped, including the delimiter byte.
+    ///
+    /// [`read_until`]: BufRead::read_until
+    ///
+    /// # Errors
+    ///
+    /// This function has the same error semantics as [`read_until`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufread_skip_until)]
+    /// use std::io::{self, BufRead, Read};
+    ///
+    /// let mut cursor = io::Cursor::new(b"# comment\ndata");
+    ///
+    /// let num_bytes = cursor.skip_until(b'\n')
+    ///     .expect("reading from cursor won't fail");
+    /// assert_eq!(num_bytes, 10);
+    ///
+    /// let mut rest = String::new();
+    /// cursor.read_to_string(&mut rest).expect("reading from cursor won't fail");
+    /// assert_eq!(rest, "data");
+    /// ```
+    #[unstable(feature = "bufread_skip_until", issue = "none")]
+    fn skip_until(&mut self, byte: u8) -> Result<usize> {
+        skip_until(self, byte)
+    }
+
     /// Read all bytes until a newline (the `0xA` byte) is reached, and append
     /// them to the provided buffer.
     ///
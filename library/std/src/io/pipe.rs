@@ -0,0 +1,268 @@
+#[cfg(test)]
+mod tests;
+
+use crate::fmt;
+use crate::io::{self, Initializer, IoSlice, IoSliceMut, Read, Write};
+use crate::sys::pipe::{self as imp, AnonPipe};
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+
+/// Creates an anonymous pipe.
+///
+/// A pipe is a one-way channel: bytes written to the [`PipeWriter`] can be
+/// read back, in order, from the [`PipeReader`]. Reads block until data is
+/// available, and return `Ok(0)` (end of file) once every writer, including
+/// clones made with [`PipeWriter::try_clone`] and copies handed to child
+/// processes, has been dropped. Writes to a pipe whose readers have all been
+/// dropped fail with [`io::ErrorKind::BrokenPipe`].
+///
+/// Either end can be passed to [`Command::stdin`], [`Command::stdout`] and
+/// [`Command::stderr`] through [`Stdio::from`], which makes it possible to
+/// connect several children to the same pipe, or to send a child's stdout
+/// and stderr into one stream.
+///
+/// Neither end is inherited by child processes unless it is explicitly
+/// passed to [`Command`].
+///
+/// # Platform-specific behavior
+///
+/// This function currently corresponds to the `pipe2` function on Linux and
+/// most other Unix platforms, with `O_CLOEXEC` set, and to `CreatePipe` on
+/// Windows. **Note that this [may change in the future][changes]**.
+///
+/// On platforms without process support it returns an error of kind
+/// [`io::ErrorKind::Unsupported`].
+///
+/// [changes]: io#platform-specific-behavior
+/// [`Command`]: crate::process::Command
+/// [`Command::stdin`]: crate::process::Command::stdin
+/// [`Command::stdout`]: crate::process::Command::stdout
+/// [`Command::stderr`]: crate::process::Command::stderr
+/// [`Stdio::from`]: crate::process::Stdio
+///
+/// # Examples
+///
+/// Collecting stdout and stderr of a child into a single stream:
+///
+/// ```no_run
+/// #![feature(anonymous_pipe)]
+/// use std::io::{self, Read};
+/// use std::process::Command;
+///
+/// fn main() -> io::Result<()> {
+///     let (mut reader, writer) = io::pipe()?;
+///
+///     let mut command = Command::new("cargo");
+///     command.arg("build").stdout(writer.try_clone()?).stderr(writer);
+///     let mut child = command.spawn()?;
+///
+///     // `command` still holds the write ends; drop it so that the read below
+///     // sees end of file once the child exits.
+///     drop(command);
+///
+///     let mut output = String::new();
+///     reader.read_to_string(&mut output)?;
+///     child.wait()?;
+///     println!("{}", output);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub fn pipe() -> io::Result<(PipeReader, PipeWriter)> {
+    let (reader, writer) = imp::pipe()?;
+    Ok((PipeReader(reader), PipeWriter(writer)))
+}
+
+/// The read half of an anonymous pipe.
+///
+/// This struct is created by the [`pipe`] function. See its documentation
+/// for more.
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeReader(AnonPipe);
+
+/// The write half of an anonymous pipe.
+///
+/// This struct is created by the [`pipe`] function. See its documentation
+/// for more.
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeWriter(AnonPipe);
+
+impl PipeReader {
+    /// Creates a new `PipeReader` instance that shares the same underlying
+    /// pipe as the existing `PipeReader`.
+    ///
+    /// Bytes read through one of the instances are no longer available to
+    /// the others.
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<PipeReader> {
+        self.0.try_clone().map(PipeReader)
+    }
+}
+
+impl PipeWriter {
+    /// Creates a new `PipeWriter` instance that shares the same underlying
+    /// pipe as the existing `PipeWriter`.
+    ///
+    /// The read end only sees end of file once all clones have been dropped.
+    ///
+    /// # Examples
+    ///
+    /// Sharing one pipe between the stdout of two children:
+    ///
+    /// ```no_run
+    /// #![feature(anonymous_pipe)]
+    /// use std::io::{self, Read};
+    /// use std::process::Command;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let (mut reader, writer) = io::pipe()?;
+    ///
+    ///     let mut first = Command::new("echo").arg("one").stdout(writer.try_clone()?).spawn()?;
+    ///     let mut second = Command::new("echo").arg("two").stdout(writer).spawn()?;
+    ///     first.wait()?;
+    ///     second.wait()?;
+    ///
+    ///     let mut output = String::new();
+    ///     reader.read_to_string(&mut output)?;
+    ///     assert_eq!(output.lines().count(), 2);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<PipeWriter> {
+        self.0.try_clone().map(PipeWriter)
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        (&*self).read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        (&*self).read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+
+    #[inline]
+    unsafe fn initializer(&self) -> Initializer {
+        // SAFETY: Read is guaranteed to work on uninitialized memory
+        unsafe { Initializer::nop() }
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for &PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+
+    #[inline]
+    unsafe fn initializer(&self) -> Initializer {
+        // SAFETY: Read is guaranteed to work on uninitialized memory
+        unsafe { Initializer::nop() }
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        (&*self).write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        (&*self).write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for &PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeReader").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeWriter").finish_non_exhaustive()
+    }
+}
+
+impl AsInner<AnonPipe> for PipeReader {
+    fn as_inner(&self) -> &AnonPipe {
+        &self.0
+    }
+}
+
+impl IntoInner<AnonPipe> for PipeReader {
+    fn into_inner(self) -> AnonPipe {
+        self.0
+    }
+}
+
+impl FromInner<AnonPipe> for PipeReader {
+    fn from_inner(pipe: AnonPipe) -> PipeReader {
+        PipeReader(pipe)
+    }
+}
+
+impl AsInner<AnonPipe> for PipeWriter {
+    fn as_inner(&self) -> &AnonPipe {
+        &self.0
+    }
+}
+
+impl IntoInner<AnonPipe> for PipeWriter {
+    fn into_inner(self) -> AnonPipe {
+        self.0
+    }
+}
+
+impl FromInner<AnonPipe> for PipeWriter {
+    fn from_inner(pipe: AnonPipe) -> PipeWriter {
+        PipeWriter(pipe)
+    }
+}
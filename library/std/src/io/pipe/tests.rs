@@ -0,0 +1,52 @@
+use crate::io::{pipe, Read, Write};
+use crate::thread;
+
+#[test]
+#[cfg_attr(any(target_os = "vxworks", target_os = "emscripten"), ignore)]
+fn pipe_read_write() {
+    let (mut reader, mut writer) = pipe().unwrap();
+    writer.write_all(b"hello").unwrap();
+    drop(writer);
+
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"hello");
+}
+
+#[test]
+#[cfg_attr(any(target_os = "vxworks", target_os = "emscripten"), ignore)]
+fn pipe_eof_after_all_writers_dropped() {
+    let (mut reader, writer) = pipe().unwrap();
+    let clone = writer.try_clone().unwrap();
+
+    let t = thread::spawn(move || {
+        (&writer).write_all(b"one").unwrap();
+    });
+    (&clone).write_all(b"two").unwrap();
+    t.join().unwrap();
+    drop(clone);
+
+    let mut buf = String::new();
+    reader.read_to_string(&mut buf).unwrap();
+    assert_eq!(buf.len(), 6);
+    assert!(buf.contains("one") && buf.contains("two"));
+}
+
+#[test]
+#[cfg(unix)]
+#[cfg_attr(any(target_os = "vxworks", target_os = "android"), ignore)]
+fn pipe_merges_child_stdout_and_stderr() {
+    use crate::process::Command;
+
+    let (mut reader, writer) = pipe().unwrap();
+    let mut command = Command::new("/bin/sh");
+    command.arg("-c").arg("echo out; echo err >&2");
+    command.stdout(writer.try_clone().unwrap()).stderr(writer);
+    let mut child = command.spawn().unwrap();
+    drop(command);
+
+    let mut output = String::new();
+    reader.read_to_string(&mut output).unwrap();
+    assert!(child.wait().unwrap().success());
+    assert_eq!(output, "out\nerr\n");
+}
@@ -7,7 +7,9 @@
 
 use crate::cell::{Cell, RefCell};
 use crate::fmt;
-use crate::io::{self, BufReader, Initializer, IoSlice, IoSliceMut, LineWriter};
+use crate::fs::File;
+use crate::io::buffered::LineWriterShim;
+use crate::io::{self, BufReader, BufWriter, Initializer, IoSlice, IoSliceMut};
 use crate::lazy::SyncOnceCell;
 use crate::pin::Pin;
 use crate::sync::atomic::{AtomicBool, Ordering};
@@ -193,6 +195,97 @@ fn handle_ebadf<T>(r: io::Result<T>, default: T) -> io::Result<T> {
     }
 }
 
+/// The buffering strategy of [`Stdout`] or [`Stderr`].
+///
+/// Set with [`Stdout::set_buffer_mode`] and [`Stderr::set_buffer_mode`].
+#[unstable(feature = "stdio_buffering", issue = "none")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BufferMode {
+    /// Every write goes straight to the underlying stream. This is the
+    /// default for stderr.
+    Unbuffered,
+    /// Output is buffered until a newline is written, the buffer fills up, or
+    /// the stream is flushed. This is the default for stdout when it is a
+    /// terminal.
+    Line,
+    /// Output is buffered until a buffer of the given capacity in bytes
+    /// fills up, or the stream is flushed.
+    ///
+    /// This is the most efficient mode when writing many lines to a file or
+    /// pipe, but output may show up late, or out of order with respect to
+    /// the other stream, unless it is flushed explicitly. This is the default
+    /// for stdout when it is not a terminal.
+    Block(usize),
+}
+
+/// The buffer behind `Stdout` and `Stderr`, which can switch between the
+/// strategies of `BufferMode` at runtime.
+///
+/// Line buffering is the same `LineWriterShim` logic `LineWriter` uses, and
+/// unbuffered output is a `BufWriter` without any capacity, which passes
+/// every write straight through.
+struct StdioBuffer<W: Write> {
+    inner: BufWriter<W>,
+    mode: BufferMode,
+}
+
+impl<W: Write> StdioBuffer<W> {
+    fn new(mode: BufferMode, inner: W) -> StdioBuffer<W> {
+        StdioBuffer { inner: BufWriter::with_capacity(Self::capacity(mode), inner), mode }
+    }
+
+    fn capacity(mode: BufferMode) -> usize {
+        match mode {
+            BufferMode::Unbuffered => 0,
+            // Same as `LineWriter::new`.
+            BufferMode::Line => 1024,
+            BufferMode::Block(capacity) => capacity,
+        }
+    }
+
+    /// Flushes everything buffered so far and switches to `mode`.
+    fn set_mode(&mut self, mode: BufferMode) -> io::Result<()> {
+        self.inner.set_capacity(Self::capacity(mode))?;
+        self.mode = mode;
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for StdioBuffer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.mode {
+            BufferMode::Line => LineWriterShim::new(&mut self.inner).write(buf),
+            _ => self.inner.write(buf),
+        }
+    }
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        match self.mode {
+            BufferMode::Line => LineWriterShim::new(&mut self.inner).write_vectored(bufs),
+            _ => self.inner.write_vectored(bufs),
+        }
+    }
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        match self.mode {
+            BufferMode::Line => LineWriterShim::new(&mut self.inner).write_all(buf),
+            _ => self.inner.write_all(buf),
+        }
+    }
+    fn write_all_vectored(&mut self, bufs: &mut [IoSlice<'_>]) -> io::Result<()> {
+        match self.mode {
+            BufferMode::Line => LineWriterShim::new(&mut self.inner).write_all_vectored(bufs),
+            _ => self.inner.write_all_vectored(bufs),
+        }
+    }
+}
+
 /// A handle to the standard input stream of a process.
 ///
 /// Each handle is a shared reference to a global buffer of input data to this
@@ -487,10 +580,11 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 /// [`io::stdout`]: stdout
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct Stdout {
-    // FIXME: this should be LineWriter or BufWriter depending on the state of
-    //        stdout (tty or not). Note that if this is not line buffered it
-    //        should also flush-on-panic or some form of flush-on-abort.
-    inner: Pin<&'static ReentrantMutex<RefCell<LineWriter<StdoutRaw>>>>,
+    // The default mode is `Line` or `Block` depending on whether stdout is a
+    // terminal (see `stdout_default_mode`). As block buffered output would
+    // otherwise be lost if a panic aborts the process, it is flushed on panic
+    // (see `flush_on_panic`).
+    inner: Pin<&'static ReentrantMutex<RefCell<StdioBuffer<StdoutRaw>>>>,
 }
 
 /// A locked reference to the [`Stdout`] handle.
@@ -504,10 +598,10 @@ pub struct Stdout {
 /// an error.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct StdoutLock<'a> {
-    inner: ReentrantMutexGuard<'a, RefCell<LineWriter<StdoutRaw>>>,
+    inner: ReentrantMutexGuard<'a, RefCell<StdioBuffer<StdoutRaw>>>,
 }
 
-static STDOUT: SyncOnceCell<ReentrantMutex<RefCell<LineWriter<StdoutRaw>>>> = SyncOnceCell::new();
+static STDOUT: SyncOnceCell<ReentrantMutex<RefCell<StdioBuffer<StdoutRaw>>>> = SyncOnceCell::new();
 
 /// Constructs a new handle to the standard output of the current process.
 ///
@@ -515,6 +609,12 @@ pub struct StdoutLock<'a> {
 /// is synchronized via a mutex. If you need more explicit control over
 /// locking, see the [`Stdout::lock`] method.
 ///
+/// By default, stdout is line-buffered if it is a terminal, and block-buffered
+/// otherwise. This can be changed with [`Stdout::set_buffer_mode`].
+///
+/// Block-buffered output is flushed when the process exits normally or a
+/// thread panics, but not when the process is aborted.
+///
 /// ### Note: Windows Portability Consideration
 /// When operating in a console, the Windows implementation of this stream does not support
 /// non-UTF-8 byte sequences. Attempting to write bytes that are not valid UTF-8 will return
@@ -552,22 +652,74 @@ pub struct StdoutLock<'a> {
 pub fn stdout() -> Stdout {
     Stdout {
         inner: Pin::static_ref(&STDOUT).get_or_init_pin(
-            || unsafe { ReentrantMutex::new(RefCell::new(LineWriter::new(stdout_raw()))) },
+            || unsafe {
+                ReentrantMutex::new(RefCell::new(StdioBuffer::new(
+                    stdout_default_mode(),
+                    stdout_raw(),
+                )))
+            },
             |mutex| unsafe { mutex.init() },
         ),
     }
 }
 
+/// Returns the buffer mode stdout starts out with: line buffering if it is a
+/// terminal, block buffering otherwise.
+///
+/// This can't use `IsTerminal`, as it is called while the `Stdout` buffer is
+/// being initialized. Where it can't be told whether stdout is a terminal,
+/// line buffering is kept.
+fn stdout_default_mode() -> BufferMode {
+    #[cfg(unix)]
+    let is_terminal = crate::sys::io::is_terminal(&libc::STDOUT_FILENO);
+    #[cfg(all(windows, not(target_vendor = "uwp")))]
+    let is_terminal = {
+        use crate::os::windows::io::{AsRawHandle, RawHandle};
+        use crate::sys::c;
+
+        struct StdoutHandle;
+        impl AsRawHandle for StdoutHandle {
+            fn as_raw_handle(&self) -> RawHandle {
+                unsafe { c::GetStdHandle(c::STD_OUTPUT_HANDLE) as RawHandle }
+            }
+        }
+        crate::sys::io::is_terminal(&StdoutHandle)
+    };
+    #[cfg(not(any(unix, all(windows, not(target_vendor = "uwp")))))]
+    let is_terminal = true;
+
+    if is_terminal { BufferMode::Line } else { BufferMode::Block(io::DEFAULT_BUF_SIZE) }
+}
+
+/// Writes out whatever stdout has buffered, at the start of a panic.
+///
+/// This makes the output come before the panic message, and keeps it from
+/// being lost if the panic aborts the process. Like `cleanup`, this gives up
+/// rather than block if another thread holds the lock, and it skips the
+/// flush if the panic happened while this thread was writing to stdout.
+pub fn flush_on_panic() {
+    if let Some(instance) = STDOUT.get() {
+        if let Some(lock) = Pin::static_ref(instance).try_lock() {
+            if let Ok(mut buffer) = lock.try_borrow_mut() {
+                let _ = buffer.flush();
+            }
+        }
+    }
+}
+
 pub fn cleanup() {
+    // Flush the data and disable buffering during shutdown.
+    // We use try_lock() instead of lock(), because someone
+    // might have leaked a StdoutLock or StderrLock, which would
+    // otherwise cause a deadlock here.
     if let Some(instance) = STDOUT.get() {
-        // Flush the data and disable buffering during shutdown
-        // by replacing the line writer by one with zero
-        // buffering capacity.
-        // We use try_lock() instead of lock(), because someone
-        // might have leaked a StdoutLock, which would
-        // otherwise cause a deadlock here.
         if let Some(lock) = Pin::static_ref(instance).try_lock() {
-            *lock.borrow_mut() = LineWriter::with_capacity(0, stdout_raw());
+            let _ = lock.borrow_mut().set_mode(BufferMode::Unbuffered);
+        }
+    }
+    if let Some(instance) = STDERR.get() {
+        if let Some(lock) = Pin::static_ref(instance).try_lock() {
+            let _ = lock.borrow_mut().set_mode(BufferMode::Unbuffered);
         }
     }
 }
@@ -597,6 +749,45 @@ impl Stdout {
     pub fn lock(&self) -> StdoutLock<'_> {
         StdoutLock { inner: self.inner.lock() }
     }
+
+    /// Changes how output written to stdout is buffered.
+    ///
+    /// Anything buffered so far is flushed first. If that fails, the error is
+    /// returned and the buffer mode is left unchanged.
+    ///
+    /// The mode applies to all handles, since they share one global buffer.
+    /// It is reset to [`BufferMode::Unbuffered`] at process exit, after the
+    /// final flush.
+    ///
+    /// # Examples
+    ///
+    /// Writing many lines to a pipe without paying a system call per line:
+    ///
+    /// ```no_run
+    /// #![feature(stdio_buffering)]
+    /// use std::io::{self, BufferMode, Write};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let stdout = io::stdout();
+    ///     stdout.set_buffer_mode(BufferMode::Block(64 * 1024))?;
+    ///
+    ///     let mut handle = stdout.lock();
+    ///     for i in 0..1_000_000 {
+    ///         writeln!(handle, "{}", i)?;
+    ///     }
+    ///     handle.flush()
+    /// }
+    /// ```
+    #[unstable(feature = "stdio_buffering", issue = "none")]
+    pub fn set_buffer_mode(&self, mode: BufferMode) -> io::Result<()> {
+        self.inner.lock().borrow_mut().set_mode(mode)
+    }
+
+    /// Returns how output written to stdout is currently buffered.
+    #[unstable(feature = "stdio_buffering", issue = "none")]
+    pub fn buffer_mode(&self) -> BufferMode {
+        self.inner.lock().borrow().mode
+    }
 }
 
 #[stable(feature = "std_debug", since = "1.16.0")]
@@ -700,7 +891,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 /// an error.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct Stderr {
-    inner: Pin<&'static ReentrantMutex<RefCell<StderrRaw>>>,
+    inner: Pin<&'static ReentrantMutex<RefCell<StdioBuffer<StderrRaw>>>>,
 }
 
 /// A locked reference to the [`Stderr`] handle.
@@ -714,12 +905,15 @@ pub struct Stderr {
 /// an error.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct StderrLock<'a> {
-    inner: ReentrantMutexGuard<'a, RefCell<StderrRaw>>,
+    inner: ReentrantMutexGuard<'a, RefCell<StdioBuffer<StderrRaw>>>,
 }
 
+static STDERR: SyncOnceCell<ReentrantMutex<RefCell<StdioBuffer<StderrRaw>>>> = SyncOnceCell::new();
+
 /// Constructs a new handle to the standard error of the current process.
 ///
-/// This handle is not buffered.
+/// By default, this handle is not buffered. This can be changed with
+/// [`Stderr::set_buffer_mode`].
 ///
 /// ### Note: Windows Portability Consideration
 /// When operating in a console, the Windows implementation of this stream does not support
@@ -756,14 +950,14 @@ pub struct StderrLock<'a> {
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn stderr() -> Stderr {
-    // Note that unlike `stdout()` we don't use `at_exit` here to register a
-    // destructor. Stderr is not buffered , so there's no need to run a
-    // destructor for flushing the buffer
-    static INSTANCE: SyncOnceCell<ReentrantMutex<RefCell<StderrRaw>>> = SyncOnceCell::new();
-
     Stderr {
-        inner: Pin::static_ref(&INSTANCE).get_or_init_pin(
-            || unsafe { ReentrantMutex::new(RefCell::new(stderr_raw())) },
+        inner: Pin::static_ref(&STDERR).get_or_init_pin(
+            || unsafe {
+                ReentrantMutex::new(RefCell::new(StdioBuffer::new(
+                    BufferMode::Unbuffered,
+                    stderr_raw(),
+                )))
+            },
             |mutex| unsafe { mutex.init() },
         ),
     }
@@ -794,6 +988,27 @@ impl Stderr {
     pub fn lock(&self) -> StderrLock<'_> {
         StderrLock { inner: self.inner.lock() }
     }
+
+    /// Changes how output written to stderr is buffered.
+    ///
+    /// Anything buffered so far is flushed first. If that fails, the error is
+    /// returned and the buffer mode is left unchanged.
+    ///
+    /// Note that panic messages are written to the standard error stream
+    /// directly, bypassing this buffer, so they may appear before output that
+    /// was written to `Stderr` earlier but not flushed yet.
+    ///
+    /// See [`Stdout::set_buffer_mode`] for more.
+    #[unstable(feature = "stdio_buffering", issue = "none")]
+    pub fn set_buffer_mode(&self, mode: BufferMode) -> io::Result<()> {
+        self.inner.lock().borrow_mut().set_mode(mode)
+    }
+
+    /// Returns how output written to stderr is currently buffered.
+    #[unstable(feature = "stdio_buffering", issue = "none")]
+    pub fn buffer_mode(&self) -> BufferMode {
+        self.inner.lock().borrow().mode
+    }
 }
 
 #[stable(feature = "std_debug", since = "1.16.0")]
@@ -885,6 +1100,56 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// Trait to determine if a descriptor/handle refers to a terminal/tty.
+///
+/// # Platform-specific behavior
+///
+/// On Unix this uses `isatty`. On Windows, in addition to detecting a
+/// console, it detects MSYS/cygwin terminals such as mintty, which connect
+/// programs through a named pipe. On platforms without terminals, such as
+/// WASI, Hermit and SGX, it always returns `false`.
+/// **Note that this [may change in the future][changes]**.
+///
+/// [changes]: io#platform-specific-behavior
+#[unstable(feature = "is_terminal", issue = "none")]
+pub trait IsTerminal: crate::sealed::Sealed {
+    /// Returns `true` if the descriptor/handle refers to a terminal/tty.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(is_terminal)]
+    /// use std::io::{self, IsTerminal, Write};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let stdout = io::stdout();
+    ///     if stdout.is_terminal() {
+    ///         writeln!(stdout.lock(), "\x1b[1mhello\x1b[0m")
+    ///     } else {
+    ///         writeln!(stdout.lock(), "hello")
+    ///     }
+    /// }
+    /// ```
+    fn is_terminal(&self) -> bool;
+}
+
+macro_rules! impl_is_terminal {
+    ($($t:ty),* $(,)?) => {$(
+        #[unstable(feature = "sealed", issue = "none")]
+        impl crate::sealed::Sealed for $t {}
+
+        #[unstable(feature = "is_terminal", issue = "none")]
+        impl IsTerminal for $t {
+            #[inline]
+            fn is_terminal(&self) -> bool {
+                crate::sys::io::is_terminal(self)
+            }
+        }
+    )*}
+}
+
+impl_is_terminal!(File, Stdin, StdinLock<'_>, Stdout, StdoutLock<'_>, Stderr, StderrLock<'_>);
+
 /// Sets the thread-local output capture buffer and returns the old one.
 #[unstable(
     feature = "internal_output_capture",
@@ -45,3 +45,24 @@ fn panic_doesnt_poison() {
     let _a = stderr();
     let _a = _a.lock();
 }
+
+#[test]
+fn stdio_buffer_modes() {
+    let mut buffer = StdioBuffer::new(BufferMode::Unbuffered, Vec::new());
+    buffer.write_all(b"one").unwrap();
+    assert_eq!(buffer.inner.get_ref(), b"one");
+
+    buffer.set_mode(BufferMode::Line).unwrap();
+    buffer.write_all(b"two\nthree").unwrap();
+    assert_eq!(buffer.inner.get_ref(), b"onetwo\n");
+
+    // Switching modes flushes whatever was buffered.
+    buffer.set_mode(BufferMode::Block(16)).unwrap();
+    assert_eq!(buffer.inner.get_ref(), b"onetwo\nthree");
+    buffer.write_all(b"four\n").unwrap();
+    assert_eq!(buffer.inner.get_ref(), b"onetwo\nthree");
+    buffer.write_all(b"fivesixseven\n").unwrap();
+    assert_eq!(buffer.inner.get_ref(), b"onetwo\nthreefour\n");
+    buffer.flush().unwrap();
+    assert_eq!(buffer.inner.get_ref(), b"onetwo\nthreefour\nfivesixseven\n");
+}
@@ -24,6 +24,40 @@ fn read_until() {
     assert_eq!(v, []);
 }
 
+#[test]
+fn read_until_any() {
+    let mut buf = Cursor::new(&b"a=b;c"[..]);
+    let mut v = Vec::new();
+    assert_eq!(buf.read_until_any(b";=", &mut v).unwrap(), 2);
+    assert_eq!(v, b"a=");
+    v.truncate(0);
+    assert_eq!(buf.read_until_any(b";=", &mut v).unwrap(), 2);
+    assert_eq!(v, b"b;");
+    v.truncate(0);
+    assert_eq!(buf.read_until_any(b";=", &mut v).unwrap(), 1);
+    assert_eq!(v, b"c");
+    v.truncate(0);
+    assert_eq!(buf.read_until_any(b";=", &mut v).unwrap(), 0);
+    assert_eq!(v, []);
+
+    let mut buf = Cursor::new(&b"123"[..]);
+    assert_eq!(buf.read_until_any(b"", &mut v).unwrap(), 3);
+    assert_eq!(v, b"123");
+}
+
+#[test]
+fn skip_until() {
+    let inner: &[u8] = b"skip#this#\n#rest";
+    let mut reader = BufReader::with_capacity(2, inner);
+    assert_eq!(reader.skip_until(b'#').unwrap(), 5);
+    assert_eq!(reader.skip_until(b'#').unwrap(), 5);
+    assert_eq!(reader.skip_until(b'#').unwrap(), 2);
+    let mut rest = String::new();
+    reader.read_to_string(&mut rest).unwrap();
+    assert_eq!(rest, "rest");
+    assert_eq!(reader.skip_until(b'#').unwrap(), 0);
+}
+
 #[test]
 fn split() {
     let buf = Cursor::new(&b"12"[..]);
@@ -219,6 +219,7 @@
 // std is implemented with unstable features, many of which are internal
 // compiler details that will never be stable
 // NB: the following list is sorted to minimize merge conflicts.
+#![cfg_attr(bootstrap, feature(addr_parse_error_internals))]
 #![feature(alloc_error_handler)]
 #![feature(alloc_layout_extra)]
 #![feature(allocator_api)]
@@ -226,7 +227,7 @@
 #![feature(allow_internal_unsafe)]
 #![feature(allow_internal_unstable)]
 #![feature(arbitrary_self_types)]
-#![feature(array_error_internals)]
+#![cfg_attr(bootstrap, feature(array_error_internals))]
 #![feature(asm)]
 #![feature(assert_matches)]
 #![feature(associated_type_bounds)]
@@ -240,7 +241,7 @@
 #![feature(cfg_eval)]
 #![feature(cfg_target_has_atomic)]
 #![feature(cfg_target_thread_local)]
-#![feature(char_error_internals)]
+#![cfg_attr(bootstrap, feature(char_error_internals))]
 #![feature(char_internals)]
 #![feature(concat_idents)]
 #![feature(const_cstr_unchecked)]
@@ -265,6 +266,9 @@
 #![feature(duration_checked_float)]
 #![feature(duration_constants)]
 #![feature(edition_panic)]
+#![cfg_attr(not(bootstrap), feature(error_generic_member_access))]
+#![cfg_attr(not(bootstrap), feature(error_in_core))]
+#![cfg_attr(not(bootstrap), feature(error_iter))]
 #![feature(exact_size_is_empty)]
 #![feature(exhaustive_patterns)]
 #![feature(extend_one)]
@@ -277,10 +281,12 @@
 #![feature(get_mut_unchecked)]
 #![feature(global_asm)]
 #![feature(hashmap_internals)]
-#![feature(int_error_internals)]
+#![cfg_attr(bootstrap, feature(int_error_internals))]
 #![feature(integer_atomics)]
 #![feature(into_future)]
 #![feature(intra_doc_pointers)]
+#![feature(ip)]
+#![feature(ip_in_core)]
 #![feature(iter_zip)]
 #![feature(lang_items)]
 #![feature(linkage)]
@@ -460,6 +466,8 @@
 pub use core::raw;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::result;
+#[unstable(feature = "portable_simd", issue = "none")]
+pub use core::simd;
 #[unstable(feature = "async_stream", issue = "79024")]
 pub use core::stream;
 #[stable(feature = "i128", since = "1.26.0")]
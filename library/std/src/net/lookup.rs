@@ -0,0 +1,188 @@
+#[cfg(all(test, not(any(target_os = "emscripten", target_env = "sgx"))))]
+mod tests;
+
+use crate::fmt;
+use crate::io;
+use crate::net::{IpAddr, SocketAddr};
+use crate::sys_common::net as net_imp;
+
+/// The address families a name lookup may return, as configured by
+/// [`LookupOptions::family`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[unstable(feature = "dns_resolver", issue = "none")]
+pub enum AddressFamily {
+    /// Both IPv4 and IPv6 addresses are returned.
+    Any,
+    /// Only IPv4 addresses are returned.
+    Ipv4,
+    /// Only IPv6 addresses are returned.
+    Ipv6,
+}
+
+/// Options and flags which can be used to configure how a host name is
+/// resolved to socket addresses.
+///
+/// This is a thin layer over the system's `getaddrinfo` and gives control over
+/// the hints that [`ToSocketAddrs`] leaves at their defaults.
+///
+/// Lookup failures can be told apart by their [`io::ErrorKind`]: a name which
+/// does not exist, or has no addresses of the requested family, is reported as
+/// [`NotFound`], while a failure which may succeed if retried later (such as an
+/// unreachable name server) is reported as [`TemporaryFailure`].
+///
+/// [`ToSocketAddrs`]: crate::net::ToSocketAddrs
+/// [`NotFound`]: io::ErrorKind::NotFound
+/// [`TemporaryFailure`]: io::ErrorKind::TemporaryFailure
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(dns_resolver)]
+/// use std::net::{AddressFamily, LookupOptions};
+///
+/// fn main() -> std::io::Result<()> {
+///     let addrs = LookupOptions::new()
+///         .family(AddressFamily::Ipv6)
+///         .addr_config(true)
+///         .canonical_name(true)
+///         .lookup("rust-lang.org", 443)?;
+///     println!("canonical name: {:?}", addrs.canonical_name());
+///     for addr in addrs {
+///         println!("{}", addr);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[unstable(feature = "dns_resolver", issue = "none")]
+pub struct LookupOptions {
+    pub(crate) family: AddressFamily,
+    pub(crate) canonical_name: bool,
+    pub(crate) addr_config: bool,
+    pub(crate) numeric_host: bool,
+}
+
+#[unstable(feature = "dns_resolver", issue = "none")]
+impl LookupOptions {
+    /// Creates a blank new set of options, which resolves to addresses of any
+    /// family with no additional flags set.
+    #[unstable(feature = "dns_resolver", issue = "none")]
+    pub fn new() -> LookupOptions {
+        LookupOptions {
+            family: AddressFamily::Any,
+            canonical_name: false,
+            addr_config: false,
+            numeric_host: false,
+        }
+    }
+
+    /// Restricts the lookup to addresses of the given family.
+    #[unstable(feature = "dns_resolver", issue = "none")]
+    pub fn family(&mut self, family: AddressFamily) -> &mut LookupOptions {
+        self.family = family;
+        self
+    }
+
+    /// Sets whether the canonical name of the host should be requested
+    /// (`AI_CANONNAME`). It is available through [`LookupHost::canonical_name`].
+    #[unstable(feature = "dns_resolver", issue = "none")]
+    pub fn canonical_name(&mut self, canonical_name: bool) -> &mut LookupOptions {
+        self.canonical_name = canonical_name;
+        self
+    }
+
+    /// Sets whether addresses of a family are only returned if the local
+    /// system has at least one address of that family configured
+    /// (`AI_ADDRCONFIG`).
+    #[unstable(feature = "dns_resolver", issue = "none")]
+    pub fn addr_config(&mut self, addr_config: bool) -> &mut LookupOptions {
+        self.addr_config = addr_config;
+        self
+    }
+
+    /// Sets whether the host must be a numeric address string, in which case
+    /// no name resolution is attempted (`AI_NUMERICHOST`).
+    #[unstable(feature = "dns_resolver", issue = "none")]
+    pub fn numeric_host(&mut self, numeric_host: bool) -> &mut LookupOptions {
+        self.numeric_host = numeric_host;
+        self
+    }
+
+    /// Resolves `host` with the options specified by `self`, returning its
+    /// addresses with their port set to `port`.
+    ///
+    /// On SGX, where the host name is resolved outside the enclave, this
+    /// returns an error of kind [`io::ErrorKind::Unsupported`] unless all
+    /// options are left at their defaults.
+    #[unstable(feature = "dns_resolver", issue = "none")]
+    pub fn lookup(&self, host: &str, port: u16) -> io::Result<LookupHost> {
+        net_imp::LookupHost::with_options(host, port, self).map(|inner| LookupHost { inner })
+    }
+}
+
+#[unstable(feature = "dns_resolver", issue = "none")]
+impl Default for LookupOptions {
+    fn default() -> LookupOptions {
+        LookupOptions::new()
+    }
+}
+
+/// An iterator over the addresses a host name resolved to.
+///
+/// This struct is created by [`LookupOptions::lookup`].
+#[unstable(feature = "dns_resolver", issue = "none")]
+pub struct LookupHost {
+    inner: net_imp::LookupHost,
+}
+
+impl LookupHost {
+    /// Returns the canonical name of the host, if it was requested with
+    /// [`LookupOptions::canonical_name`] and reported by the resolver.
+    #[unstable(feature = "dns_resolver", issue = "none")]
+    pub fn canonical_name(&self) -> Option<&str> {
+        self.inner.canonical_name()
+    }
+}
+
+#[unstable(feature = "dns_resolver", issue = "none")]
+impl Iterator for LookupHost {
+    type Item = SocketAddr;
+
+    fn next(&mut self) -> Option<SocketAddr> {
+        let port = self.inner.port();
+        self.inner.next().map(|mut addr| {
+            addr.set_port(port);
+            addr
+        })
+    }
+}
+
+#[unstable(feature = "dns_resolver", issue = "none")]
+impl fmt::Debug for LookupHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LookupHost").finish_non_exhaustive()
+    }
+}
+
+/// Resolves an IP address back to a host name using the system's
+/// `getnameinfo`.
+///
+/// An error of kind [`NotFound`] is returned if the address has no name,
+/// rather than its numeric form.
+///
+/// [`NotFound`]: io::ErrorKind::NotFound
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(dns_resolver)]
+/// use std::net::{lookup_addr, Ipv4Addr};
+///
+/// let name = lookup_addr(Ipv4Addr::LOCALHOST.into()).expect("reverse lookup failed");
+/// println!("127.0.0.1 is {}", name);
+/// ```
+#[unstable(feature = "dns_resolver", issue = "none")]
+pub fn lookup_addr(addr: IpAddr) -> io::Result<String> {
+    net_imp::lookup_addr(&addr)
+}
@@ -0,0 +1,48 @@
+use crate::io::ErrorKind;
+use crate::net::test::sa4;
+use crate::net::*;
+
+#[test]
+fn lookup_localhost_ipv4_only() {
+    let addrs: Vec<_> = LookupOptions::new()
+        .family(AddressFamily::Ipv4)
+        .lookup("localhost", 8080)
+        .unwrap()
+        .collect();
+    assert!(addrs.contains(&sa4(Ipv4Addr::LOCALHOST, 8080)));
+    assert!(addrs.iter().all(|addr| addr.is_ipv4()));
+}
+
+#[test]
+fn lookup_numeric_host() {
+    let mut opts = LookupOptions::new();
+    opts.numeric_host(true);
+    let addrs: Vec<_> = opts.lookup("127.0.0.1", 80).unwrap().collect();
+    assert!(addrs.iter().all(|addr| *addr == sa4(Ipv4Addr::LOCALHOST, 80)));
+
+    // A name is never resolved when only numeric hosts are allowed.
+    let err = opts.lookup("localhost", 80).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn lookup_canonical_name() {
+    let mut opts = LookupOptions::new();
+    assert_eq!(opts.lookup("localhost", 0).unwrap().canonical_name(), None);
+    opts.canonical_name(true);
+    assert!(opts.lookup("localhost", 0).unwrap().canonical_name().is_some());
+}
+
+#[test]
+fn lookup_addr_without_name() {
+    // Addresses in TEST-NET-1 never have a reverse mapping, but the lookup may
+    // also fail temporarily if no name server is reachable.
+    match lookup_addr(Ipv4Addr::new(192, 0, 2, 1).into()) {
+        Ok(name) => panic!("unexpected name {:?}", name),
+        Err(e) => assert!(
+            matches!(e.kind(), ErrorKind::NotFound | ErrorKind::TemporaryFailure),
+            "unexpected error {:?}",
+            e
+        ),
+    }
+}
@@ -23,16 +23,18 @@
 pub use self::addr::{SocketAddr, SocketAddrV4, SocketAddrV6, ToSocketAddrs};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::ip::{IpAddr, Ipv4Addr, Ipv6Addr, Ipv6MulticastScope};
-#[stable(feature = "rust1", since = "1.0.0")]
-pub use self::parser::AddrParseError;
+#[unstable(feature = "dns_resolver", issue = "none")]
+pub use self::lookup::{lookup_addr, AddressFamily, LookupHost, LookupOptions};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::tcp::{Incoming, TcpListener, TcpStream};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::udp::UdpSocket;
+#[stable(feature = "rust1", since = "1.0.0")]
+pub use core::net::AddrParseError;
 
 mod addr;
 mod ip;
-mod parser;
+mod lookup;
 mod tcp;
 #[cfg(test)]
 mod test;
@@ -4,6 +4,16 @@
 
 use crate::fs::Metadata;
 use crate::sys_common::AsInner;
+#[cfg(target_os = "linux")]
+use crate::{
+    ffi::OsStr,
+    fs::File,
+    io,
+    ops::{BitOr, BitOrAssign},
+    os::unix::io::AsRawFd,
+    sys,
+    sys_common::FromInner,
+};
 
 #[allow(deprecated)]
 use crate::os::linux::raw;
@@ -378,3 +388,328 @@ fn st_blocks(&self) -> u64 {
         self.as_inner().as_inner().st_blocks as u64
     }
 }
+
+/// Options and flags which can be used to configure how an anonymous
+/// memory-backed file is created with `memfd_create(2)`.
+///
+/// The returned [`File`] lives entirely in memory, has no path in the
+/// filesystem and is released once all references to it are dropped. The
+/// close-on-exec flag is always set.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_memfd)]
+/// use std::io::Write;
+/// use std::os::linux::fs::{FileExt, MemfdOptions, Seals};
+///
+/// fn main() -> std::io::Result<()> {
+///     let mut file = MemfdOptions::new().allow_sealing(true).create("payload")?;
+///     file.write_all(b"immutable contents")?;
+///     file.add_seals(Seals::SHRINK | Seals::GROW | Seals::WRITE | Seals::SEAL)?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(target_os = "linux")]
+#[derive(Clone, Debug)]
+#[unstable(feature = "linux_memfd", issue = "none")]
+pub struct MemfdOptions {
+    allow_sealing: bool,
+    hugetlb: bool,
+}
+
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_memfd", issue = "none")]
+impl MemfdOptions {
+    /// Creates a blank set of options with sealing and huge pages disabled.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub fn new() -> MemfdOptions {
+        MemfdOptions { allow_sealing: false, hugetlb: false }
+    }
+
+    /// Sets whether seals may be added to the file with [`FileExt::add_seals`].
+    ///
+    /// Without this option the file is created with [`Seals::SEAL`] already
+    /// set, so no further seals can be applied.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub fn allow_sealing(&mut self, allow_sealing: bool) -> &mut MemfdOptions {
+        self.allow_sealing = allow_sealing;
+        self
+    }
+
+    /// Sets whether the file is backed by huge pages from the hugetlbfs
+    /// filesystem. Requires Linux 4.14 to be combined with sealing.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub fn hugetlb(&mut self, hugetlb: bool) -> &mut MemfdOptions {
+        self.hugetlb = hugetlb;
+        self
+    }
+
+    /// Creates the anonymous file with the options specified by `self`.
+    ///
+    /// `name` is only used for debugging; it appears as the target of the
+    /// symlink in `/proc/self/fd` and does not need to be unique.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`] if `name` contains a nul
+    /// byte, and any error reported by `memfd_create(2)`.
+    ///
+    /// [`InvalidInput`]: io::ErrorKind::InvalidInput
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub fn create<N: AsRef<OsStr>>(&self, name: N) -> io::Result<File> {
+        let mut flags = 0;
+        if self.allow_sealing {
+            flags |= libc::MFD_ALLOW_SEALING;
+        }
+        if self.hugetlb {
+            flags |= libc::MFD_HUGETLB;
+        }
+        sys::fs::File::memfd_create(name.as_ref(), flags).map(File::from_inner)
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_memfd", issue = "none")]
+impl Default for MemfdOptions {
+    fn default() -> MemfdOptions {
+        MemfdOptions::new()
+    }
+}
+
+/// A set of seals restricting the operations allowed on a memfd-backed file.
+///
+/// See `fcntl(2)` for the exact semantics of each seal.
+#[cfg(target_os = "linux")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[unstable(feature = "linux_memfd", issue = "none")]
+pub struct Seals(libc::c_int);
+
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_memfd", issue = "none")]
+impl Seals {
+    /// Prevents any further seals from being added.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub const SEAL: Seals = Seals(libc::F_SEAL_SEAL);
+    /// Prevents the file from being truncated to a smaller size.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub const SHRINK: Seals = Seals(libc::F_SEAL_SHRINK);
+    /// Prevents the file from being extended.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub const GROW: Seals = Seals(libc::F_SEAL_GROW);
+    /// Prevents any modification of the file contents. Fails while writable
+    /// shared mappings of the file exist.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub const WRITE: Seals = Seals(libc::F_SEAL_WRITE);
+    /// Prevents new writes and writable mappings while allowing existing
+    /// shared mappings to keep writing. Requires Linux 5.1.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub const FUTURE_WRITE: Seals = Seals(libc::F_SEAL_FUTURE_WRITE);
+
+    /// Returns an empty set of seals.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub const fn empty() -> Seals {
+        Seals(0)
+    }
+
+    /// Returns `true` if no seals are set.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if every seal in `other` is also set in `self`.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    pub const fn contains(self, other: Seals) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_memfd", issue = "none")]
+impl BitOr for Seals {
+    type Output = Seals;
+
+    fn bitor(self, rhs: Seals) -> Seals {
+        Seals(self.0 | rhs.0)
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_memfd", issue = "none")]
+impl BitOrAssign for Seals {
+    fn bitor_assign(&mut self, rhs: Seals) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Linux-specific extensions to [`fs::File`].
+///
+/// [`fs::File`]: crate::fs::File
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_memfd", issue = "none")]
+pub trait FileExt {
+    /// Adds `seals` to the set of seals on this file.
+    ///
+    /// The file must have been created by [`MemfdOptions`] with sealing
+    /// allowed. Seals can never be removed once added.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    fn add_seals(&self, seals: Seals) -> io::Result<()>;
+
+    /// Returns the set of seals currently applied to this file.
+    #[unstable(feature = "linux_memfd", issue = "none")]
+    fn seals(&self) -> io::Result<Seals>;
+}
+
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_memfd", issue = "none")]
+impl FileExt for File {
+    fn add_seals(&self, seals: Seals) -> io::Result<()> {
+        self.as_inner().add_seals(seals.0)
+    }
+
+    fn seals(&self) -> io::Result<Seals> {
+        self.as_inner().seals().map(Seals)
+    }
+}
+
+/// Flags modifying the behavior of [`splice`].
+#[cfg(target_os = "linux")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[unstable(feature = "linux_zero_copy", issue = "none")]
+pub struct SpliceFlags(libc::c_uint);
+
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_zero_copy", issue = "none")]
+impl SpliceFlags {
+    /// Hints that pages should be moved rather than copied.
+    #[unstable(feature = "linux_zero_copy", issue = "none")]
+    pub const MOVE: SpliceFlags = SpliceFlags(libc::SPLICE_F_MOVE);
+    /// Makes the pipe operations non-blocking. The other descriptor may
+    /// still block unless it is in non-blocking mode itself.
+    #[unstable(feature = "linux_zero_copy", issue = "none")]
+    pub const NONBLOCK: SpliceFlags = SpliceFlags(libc::SPLICE_F_NONBLOCK);
+    /// Hints that more data will be sent in a subsequent splice, like
+    /// `MSG_MORE` for sockets.
+    #[unstable(feature = "linux_zero_copy", issue = "none")]
+    pub const MORE: SpliceFlags = SpliceFlags(libc::SPLICE_F_MORE);
+
+    /// Returns an empty set of flags.
+    #[unstable(feature = "linux_zero_copy", issue = "none")]
+    pub const fn empty() -> SpliceFlags {
+        SpliceFlags(0)
+    }
+
+    /// Returns `true` if every flag in `other` is also set in `self`.
+    #[unstable(feature = "linux_zero_copy", issue = "none")]
+    pub const fn contains(self, other: SpliceFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_zero_copy", issue = "none")]
+impl BitOr for SpliceFlags {
+    type Output = SpliceFlags;
+
+    fn bitor(self, rhs: SpliceFlags) -> SpliceFlags {
+        SpliceFlags(self.0 | rhs.0)
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_zero_copy", issue = "none")]
+impl BitOrAssign for SpliceFlags {
+    fn bitor_assign(&mut self, rhs: SpliceFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Copies up to `len` bytes from `input` to `output` inside the kernel
+/// using `sendfile(2)`, returning the number of bytes transferred.
+///
+/// `input` must support mmap-like operations, which in practice means a
+/// regular file or block device; `output` may be any file descriptor. If
+/// `offset` is given, reading starts there and the offset is advanced by the
+/// number of bytes transferred while the file position of `input` is left
+/// untouched. Otherwise the read starts at, and advances, the current file
+/// position.
+///
+/// A single call may transfer fewer bytes than requested. A return value of
+/// `0` means `input` has reached end of file.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_zero_copy)]
+/// use std::fs::File;
+/// use std::net::TcpStream;
+/// use std::os::linux::fs::sendfile;
+///
+/// fn main() -> std::io::Result<()> {
+///     let file = File::open("payload.bin")?;
+///     let stream = TcpStream::connect("127.0.0.1:8080")?;
+///     let mut offset = 0;
+///     while sendfile(&file, Some(&mut offset), &stream, 1 << 20)? > 0 {}
+///     Ok(())
+/// }
+/// ```
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_zero_copy", issue = "none")]
+pub fn sendfile<I: AsRawFd, O: AsRawFd>(
+    input: &I,
+    offset: Option<&mut u64>,
+    output: &O,
+    len: usize,
+) -> io::Result<usize> {
+    sys::kernel_copy::sendfile_with_offset(input.as_raw_fd(), offset, output.as_raw_fd(), len)
+}
+
+/// Moves up to `len` bytes from `input` to `output` using `splice(2)`,
+/// returning the number of bytes moved.
+///
+/// At least one of the two descriptors must refer to a pipe. An offset may
+/// only be given for a descriptor that is not a pipe; it is then used instead
+/// of, and leaves untouched, that descriptor's file position, and is advanced
+/// by the number of bytes moved.
+///
+/// A return value of `0` means there was no data to move and, if `input` is
+/// a pipe, that it has no writers left.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_zero_copy)]
+/// #![feature(anonymous_pipe)]
+/// use std::net::TcpStream;
+/// use std::os::linux::fs::{splice, SpliceFlags};
+///
+/// fn main() -> std::io::Result<()> {
+///     let upstream = TcpStream::connect("127.0.0.1:8080")?;
+///     let client = TcpStream::connect("127.0.0.1:9090")?;
+///     let (reader, writer) = std::io::pipe()?;
+///     let flags = SpliceFlags::MOVE | SpliceFlags::MORE;
+///     let n = splice(&upstream, None, &writer, None, 65536, flags)?;
+///     splice(&reader, None, &client, None, n, flags)?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(target_os = "linux")]
+#[unstable(feature = "linux_zero_copy", issue = "none")]
+pub fn splice<I: AsRawFd, O: AsRawFd>(
+    input: &I,
+    in_offset: Option<&mut u64>,
+    output: &O,
+    out_offset: Option<&mut u64>,
+    len: usize,
+    flags: SpliceFlags,
+) -> io::Result<usize> {
+    sys::kernel_copy::splice_with_offsets(
+        input.as_raw_fd(),
+        in_offset,
+        output.as_raw_fd(),
+        out_offset,
+        len,
+        flags.0,
+    )
+}
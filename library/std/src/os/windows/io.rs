@@ -124,6 +124,56 @@ fn into_raw_handle(self) -> RawHandle {
     }
 }
 
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawHandle for io::PipeReader {
+    #[inline]
+    fn as_raw_handle(&self) -> RawHandle {
+        self.as_inner().handle().raw() as *mut _
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl FromRawHandle for io::PipeReader {
+    #[inline]
+    unsafe fn from_raw_handle(handle: RawHandle) -> io::PipeReader {
+        let handle = sys::handle::Handle::new(handle as c::HANDLE);
+        io::PipeReader::from_inner(sys::pipe::AnonPipe::from_handle(handle))
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawHandle for io::PipeReader {
+    #[inline]
+    fn into_raw_handle(self) -> RawHandle {
+        self.into_inner().into_handle().into_raw() as *mut _
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawHandle for io::PipeWriter {
+    #[inline]
+    fn as_raw_handle(&self) -> RawHandle {
+        self.as_inner().handle().raw() as *mut _
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl FromRawHandle for io::PipeWriter {
+    #[inline]
+    unsafe fn from_raw_handle(handle: RawHandle) -> io::PipeWriter {
+        let handle = sys::handle::Handle::new(handle as c::HANDLE);
+        io::PipeWriter::from_inner(sys::pipe::AnonPipe::from_handle(handle))
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawHandle for io::PipeWriter {
+    #[inline]
+    fn into_raw_handle(self) -> RawHandle {
+        self.into_inner().into_handle().into_raw() as *mut _
+    }
+}
+
 /// Extracts raw sockets.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub trait AsRawSocket {
@@ -0,0 +1,164 @@
+use crate::path::Path;
+use crate::sys::c;
+use crate::sys::net::cvt;
+use crate::{fmt, io, mem, str};
+
+use libc::c_int;
+
+fn sun_path_offset(addr: &c::sockaddr_un) -> usize {
+    // Work with an actual instance of the type since using a null pointer is UB
+    let base = addr as *const _ as usize;
+    let path = &addr.sun_path as *const _ as usize;
+    path - base
+}
+
+pub(super) fn sockaddr_un(path: &Path) -> io::Result<(c::sockaddr_un, c_int)> {
+    let mut addr: c::sockaddr_un = unsafe { mem::zeroed() };
+    addr.sun_family = c::AF_UNIX as c::ADDRESS_FAMILY;
+
+    // Windows expects the path to be encoded as UTF-8.
+    let bytes = match path.to_str() {
+        Some(path) => path.as_bytes(),
+        None => {
+            return Err(io::Error::new_const(
+                io::ErrorKind::InvalidInput,
+                &"paths must be valid unicode",
+            ));
+        }
+    };
+
+    if bytes.contains(&0) {
+        return Err(io::Error::new_const(
+            io::ErrorKind::InvalidInput,
+            &"paths may not contain interior null bytes",
+        ));
+    }
+
+    if bytes.len() >= addr.sun_path.len() {
+        return Err(io::Error::new_const(
+            io::ErrorKind::InvalidInput,
+            &"path must be shorter than UNIX_PATH_MAX",
+        ));
+    }
+    for (dst, src) in addr.sun_path.iter_mut().zip(bytes) {
+        *dst = *src as c::CHAR;
+    }
+    // null byte for pathname addresses is already there because we zeroed the
+    // struct
+
+    let len = sun_path_offset(&addr) + bytes.len() + 1;
+    Ok((addr, len as c_int))
+}
+
+/// An address associated with a Unix socket.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(windows_unix_domain_sockets)]
+/// use std::os::windows::net::UnixListener;
+///
+/// let socket = match UnixListener::bind(r"C:\path\to\the\socket") {
+///     Ok(sock) => sock,
+///     Err(e) => {
+///         println!("Couldn't bind: {:?}", e);
+///         return
+///     }
+/// };
+/// let addr = socket.local_addr().expect("Couldn't get local address");
+/// ```
+#[derive(Clone)]
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+pub struct SocketAddr {
+    addr: c::sockaddr_un,
+    len: c_int,
+}
+
+impl SocketAddr {
+    pub(super) fn new<F>(f: F) -> io::Result<SocketAddr>
+    where
+        F: FnOnce(*mut c::SOCKADDR, *mut c_int) -> c_int,
+    {
+        let mut addr: c::sockaddr_un = unsafe { mem::zeroed() };
+        let mut len = mem::size_of::<c::sockaddr_un>() as c_int;
+        cvt(f(&mut addr as *mut _ as *mut _, &mut len))?;
+        SocketAddr::from_parts(addr, len)
+    }
+
+    pub(super) fn from_parts(addr: c::sockaddr_un, mut len: c_int) -> io::Result<SocketAddr> {
+        if len == 0 {
+            // An unbound socket may report a zero-length address.
+            len = sun_path_offset(&addr) as c_int;
+        } else if addr.sun_family != c::AF_UNIX as c::ADDRESS_FAMILY {
+            return Err(io::Error::new_const(
+                io::ErrorKind::InvalidInput,
+                &"socket did not correspond to a Unix socket",
+            ));
+        }
+
+        Ok(SocketAddr { addr, len })
+    }
+
+    /// Returns `true` if the address is unnamed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(windows_unix_domain_sockets)]
+    /// use std::os::windows::net::UnixListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixListener::bind(r"C:\path\to\the\socket")?;
+    ///     let addr = socket.local_addr().expect("Couldn't get local address");
+    ///     assert_eq!(addr.is_unnamed(), false);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn is_unnamed(&self) -> bool {
+        self.path_bytes().is_empty()
+    }
+
+    /// Returns the contents of this address if it is a pathname address.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(windows_unix_domain_sockets)]
+    /// use std::os::windows::net::UnixListener;
+    /// use std::path::Path;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixListener::bind(r"C:\path\to\the\socket")?;
+    ///     let addr = socket.local_addr().expect("Couldn't get local address");
+    ///     assert_eq!(addr.as_pathname(), Some(Path::new(r"C:\path\to\the\socket")));
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn as_pathname(&self) -> Option<&Path> {
+        let bytes = self.path_bytes();
+        if bytes.is_empty() { None } else { str::from_utf8(bytes).ok().map(Path::new) }
+    }
+
+    fn path_bytes(&self) -> &[u8] {
+        let len = (self.len as usize).saturating_sub(sun_path_offset(&self.addr));
+        let path = &self.addr.sun_path[..len.min(c::UNIX_PATH_MAX)];
+        let path = unsafe { &*(path as *const [c::CHAR] as *const [u8]) };
+        // The reported length may or may not include the trailing null byte.
+        match path.iter().position(|&b| b == 0) {
+            Some(end) => &path[..end],
+            None => path,
+        }
+    }
+}
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+impl fmt::Debug for SocketAddr {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_pathname() {
+            None => write!(fmt, "(unnamed)"),
+            Some(path) => write!(fmt, "{:?} (pathname)", path),
+        }
+    }
+}
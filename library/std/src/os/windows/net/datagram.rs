@@ -0,0 +1,284 @@
+use super::{sockaddr_un, SocketAddr};
+use crate::cmp;
+use crate::net::Shutdown;
+use crate::os::windows::io::{AsRawSocket, FromRawSocket, IntoRawSocket, RawSocket};
+use crate::path::Path;
+use crate::sys::c;
+use crate::sys::net::{cvt, init, Socket};
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::time::Duration;
+use crate::{fmt, io};
+
+use libc::c_int;
+
+/// A Unix datagram socket.
+///
+/// Windows does not implement datagram sockets for the `AF_UNIX` address
+/// family yet, so [`bind`] and [`unbound`] currently fail with the error
+/// reported by the system. The type exists so that code written for
+/// `std::os::unix::net` compiles on Windows as well.
+///
+/// [`bind`]: UnixDatagram::bind
+/// [`unbound`]: UnixDatagram::unbound
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(windows_unix_domain_sockets)]
+/// use std::os::windows::net::UnixDatagram;
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = UnixDatagram::bind(r"C:\path\to\my\socket")?;
+///     socket.send_to(b"hello world", r"C:\path\to\other\socket")?;
+///     let mut buf = [0; 100];
+///     let (count, address) = socket.recv_from(&mut buf)?;
+///     println!("socket {:?} sent {:?}", address, &buf[..count]);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+pub struct UnixDatagram(Socket);
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+impl fmt::Debug for UnixDatagram {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixDatagram");
+        builder.field("socket", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        if let Ok(addr) = self.peer_addr() {
+            builder.field("peer", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixDatagram {
+    /// Creates a Unix datagram socket bound to the given path.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixDatagram> {
+        let socket = UnixDatagram::unbound()?;
+        let (addr, len) = sockaddr_un(path.as_ref())?;
+
+        cvt(unsafe { c::bind(*socket.0.as_inner(), &addr as *const _ as *const _, len) })?;
+        Ok(socket)
+    }
+
+    /// Creates a Unix datagram socket which is not bound to any address.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn unbound() -> io::Result<UnixDatagram> {
+        init();
+        let inner = Socket::new_raw(c::AF_UNIX, c::SOCK_DGRAM)?;
+        Ok(UnixDatagram(inner))
+    }
+
+    /// Connects the socket to the specified address.
+    ///
+    /// The [`send`] method may be used to send data to the specified address.
+    /// [`recv`] and [`recv_from`] will only receive data from that address.
+    ///
+    /// [`send`]: UnixDatagram::send
+    /// [`recv`]: UnixDatagram::recv
+    /// [`recv_from`]: UnixDatagram::recv_from
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn connect<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let (addr, len) = sockaddr_un(path.as_ref())?;
+
+        cvt(unsafe { c::connect(*self.0.as_inner(), &addr as *const _ as *const _, len) })?;
+        Ok(())
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixDatagram` is a reference to the same socket that this
+    /// object references. Both handles can be used to send and receive
+    /// datagrams and options set on one side will affect the other.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixDatagram> {
+        self.0.duplicate().map(UnixDatagram)
+    }
+
+    /// Returns the address of this socket.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { c::getsockname(*self.0.as_inner(), addr, len) })
+    }
+
+    /// Returns the address of this socket's peer.
+    ///
+    /// The [`connect`] method will connect the socket to a peer.
+    ///
+    /// [`connect`]: UnixDatagram::connect
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { c::getpeername(*self.0.as_inner(), addr, len) })
+    }
+
+    fn recv_from_flags(&self, buf: &mut [u8], flags: c_int) -> io::Result<(usize, SocketAddr)> {
+        let mut count = 0;
+        let length = cmp::min(buf.len(), c_int::MAX as usize) as c_int;
+        let addr = SocketAddr::new(|addr, len| unsafe {
+            count = c::recvfrom(
+                *self.0.as_inner(),
+                buf.as_mut_ptr() as *mut _,
+                length,
+                flags,
+                addr,
+                len,
+            );
+            if count < 0 { -1 } else { 0 }
+        })?;
+
+        Ok((count as usize, addr))
+    }
+
+    /// Receives data from the socket.
+    ///
+    /// On success, returns the number of bytes read and the address from
+    /// whence the data came.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        self.recv_from_flags(buf, 0)
+    }
+
+    /// Receives data from the socket.
+    ///
+    /// On success, returns the number of bytes read.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    /// Sends data on the socket to the specified address.
+    ///
+    /// On success, returns the number of bytes written.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn send_to<P: AsRef<Path>>(&self, buf: &[u8], path: P) -> io::Result<usize> {
+        let (addr, len) = sockaddr_un(path.as_ref())?;
+        let length = cmp::min(buf.len(), c_int::MAX as usize) as c_int;
+
+        let count = cvt(unsafe {
+            c::sendto(
+                *self.0.as_inner(),
+                buf.as_ptr() as *const _,
+                length,
+                0,
+                &addr as *const _ as *const _,
+                len,
+            )
+        })?;
+        Ok(count as usize)
+    }
+
+    /// Sends data on the socket to the socket's peer.
+    ///
+    /// The peer address may be set by the `connect` method, and this method
+    /// will return an error if the socket has not already been connected.
+    ///
+    /// On success, returns the number of bytes written.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    /// Sets the read timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`recv`] and [`recv_from`]
+    /// calls will block indefinitely. An [`Err`] is returned if the zero
+    /// [`Duration`] is passed to this method.
+    ///
+    /// [`recv`]: UnixDatagram::recv
+    /// [`recv_from`]: UnixDatagram::recv_from
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, c::SO_RCVTIMEO)
+    }
+
+    /// Sets the write timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`send`] and [`send_to`] calls
+    /// will block indefinitely. An [`Err`] is returned if the zero
+    /// [`Duration`] is passed to this method.
+    ///
+    /// [`send`]: UnixDatagram::send
+    /// [`send_to`]: UnixDatagram::send_to
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, c::SO_SNDTIMEO)
+    }
+
+    /// Returns the read timeout of this socket.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn read_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(c::SO_RCVTIMEO)
+    }
+
+    /// Returns the write timeout of this socket.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(c::SO_SNDTIMEO)
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+
+    /// Shut down the read, write, or both halves of this connection.
+    ///
+    /// This function will cause all pending and future I/O calls on the
+    /// specified portions to immediately return with an appropriate value
+    /// (see the documentation of [`Shutdown`]).
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        self.0.shutdown(how)
+    }
+
+    /// Receives data on the socket from the remote address to which it is
+    /// connected, without removing that data from the queue. On success,
+    /// returns the number of bytes peeked.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.peek(buf)
+    }
+
+    /// Receives a single datagram message on the socket, without removing it
+    /// from the queue. On success, returns the number of bytes read and the
+    /// origin.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn peek_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        self.recv_from_flags(buf, c::MSG_PEEK)
+    }
+}
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+impl AsRawSocket for UnixDatagram {
+    #[inline]
+    fn as_raw_socket(&self) -> RawSocket {
+        *self.0.as_inner()
+    }
+}
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+impl FromRawSocket for UnixDatagram {
+    #[inline]
+    unsafe fn from_raw_socket(sock: RawSocket) -> UnixDatagram {
+        UnixDatagram(Socket::from_inner(sock))
+    }
+}
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+impl IntoRawSocket for UnixDatagram {
+    #[inline]
+    fn into_raw_socket(self) -> RawSocket {
+        self.0.into_inner()
+    }
+}
@@ -0,0 +1,221 @@
+use super::{sockaddr_un, SocketAddr, UnixStream};
+use crate::os::windows::io::{AsRawSocket, FromRawSocket, IntoRawSocket, RawSocket};
+use crate::path::Path;
+use crate::sys::c;
+use crate::sys::net::{cvt, init, Socket};
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::{fmt, io, mem};
+
+use libc::c_int;
+
+/// A structure representing a Unix domain socket server.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(windows_unix_domain_sockets)]
+/// use std::thread;
+/// use std::os::windows::net::{UnixStream, UnixListener};
+///
+/// fn handle_client(stream: UnixStream) {
+///     // ...
+/// }
+///
+/// fn main() -> std::io::Result<()> {
+///     let listener = UnixListener::bind(r"C:\path\to\the\socket")?;
+///
+///     // accept connections and process them, spawning a new thread for each one
+///     for stream in listener.incoming() {
+///         match stream {
+///             Ok(stream) => {
+///                 /* connection succeeded */
+///                 thread::spawn(|| handle_client(stream));
+///             }
+///             Err(err) => {
+///                 /* connection failed */
+///                 break;
+///             }
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+pub struct UnixListener(Socket);
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+impl fmt::Debug for UnixListener {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixListener");
+        builder.field("socket", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixListener {
+    /// Creates a new `UnixListener` bound to the specified socket.
+    ///
+    /// As on Unix, binding creates a file at `path`, which has to be removed
+    /// once the socket is no longer needed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(windows_unix_domain_sockets)]
+    /// use std::os::windows::net::UnixListener;
+    ///
+    /// let listener = match UnixListener::bind(r"C:\path\to\the\socket") {
+    ///     Ok(sock) => sock,
+    ///     Err(e) => {
+    ///         println!("Couldn't connect: {:?}", e);
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixListener> {
+        init();
+        let inner = Socket::new_raw(c::AF_UNIX, c::SOCK_STREAM)?;
+        let (addr, len) = sockaddr_un(path.as_ref())?;
+
+        unsafe {
+            cvt(c::bind(*inner.as_inner(), &addr as *const _ as *const _, len))?;
+            cvt(c::listen(*inner.as_inner(), 128))?;
+        }
+
+        Ok(UnixListener(inner))
+    }
+
+    /// Accepts a new incoming connection to this listener.
+    ///
+    /// This function will block the calling thread until a new Unix connection
+    /// is established. When established, the corresponding [`UnixStream`] and
+    /// the remote peer's address will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(windows_unix_domain_sockets)]
+    /// use std::os::windows::net::UnixListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixListener::bind(r"C:\path\to\the\socket")?;
+    ///
+    ///     match listener.accept() {
+    ///         Ok((socket, addr)) => println!("Got a client: {:?}", addr),
+    ///         Err(e) => println!("accept function failed: {:?}", e),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn accept(&self) -> io::Result<(UnixStream, SocketAddr)> {
+        let mut storage: c::sockaddr_un = unsafe { mem::zeroed() };
+        let mut len = mem::size_of_val(&storage) as c_int;
+        let sock = self.0.accept(&mut storage as *mut _ as *mut _, &mut len)?;
+        let addr = SocketAddr::from_parts(storage, len)?;
+        Ok((UnixStream(sock), addr))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixListener` is a reference to the same socket that this
+    /// object references. Both handles can be used to accept incoming
+    /// connections and options set on one listener will affect the other.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixListener> {
+        self.0.duplicate().map(UnixListener)
+    }
+
+    /// Returns the local socket address of this listener.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { c::getsockname(*self.0.as_inner(), addr, len) })
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    ///
+    /// This will result in the `accept` operation becoming nonblocking,
+    /// i.e., immediately returning from their calls. If the IO operation is
+    /// successful, `Ok` is returned and no further action is required. If the
+    /// IO operation could not be completed and needs to be retried, an error
+    /// with kind [`io::ErrorKind::WouldBlock`] is returned.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+
+    /// Returns an iterator over incoming connections.
+    ///
+    /// The iterator will never return [`None`] and will also not yield the
+    /// peer's [`SocketAddr`] structure.
+    #[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+    pub fn incoming(&self) -> Incoming<'_> {
+        Incoming { listener: self }
+    }
+}
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+impl AsRawSocket for UnixListener {
+    #[inline]
+    fn as_raw_socket(&self) -> RawSocket {
+        *self.0.as_inner()
+    }
+}
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+impl FromRawSocket for UnixListener {
+    #[inline]
+    unsafe fn from_raw_socket(sock: RawSocket) -> UnixListener {
+        UnixListener(Socket::from_inner(sock))
+    }
+}
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+impl IntoRawSocket for UnixListener {
+    #[inline]
+    fn into_raw_socket(self) -> RawSocket {
+        self.0.into_inner()
+    }
+}
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+impl<'a> IntoIterator for &'a UnixListener {
+    type Item = io::Result<UnixStream>;
+    type IntoIter = Incoming<'a>;
+
+    fn into_iter(self) -> Incoming<'a> {
+        self.incoming()
+    }
+}
+
+/// An iterator over incoming connections to a [`UnixListener`].
+///
+/// It will never return [`None`].
+#[derive(Debug)]
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+pub struct Incoming<'a> {
+    listener: &'a UnixListener,
+}
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+impl<'a> Iterator for Incoming<'a> {
+    type Item = io::Result<UnixStream>;
+
+    fn next(&mut self) -> Option<io::Result<UnixStream>> {
+        Some(self.listener.accept().map(|s| s.0))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
@@ -0,0 +1,31 @@
+//! Windows-specific networking functionality
+//!
+//! Windows 10 (version 1803 and later) supports Unix domain sockets with the
+//! `AF_UNIX` address family. This module provides the same [`UnixStream`],
+//! [`UnixListener`] and [`UnixDatagram`] types as `std::os::unix::net`, so that
+//! code using Unix sockets for local communication can be shared between both
+//! platforms.
+//!
+//! Only pathname addresses are supported, and paths must be valid Unicode.
+//! Windows has no `socketpair`, so there are no `pair` constructors, and file
+//! descriptors or credentials can not be passed over these sockets. Windows
+//! currently only implements stream sockets, so creating a [`UnixDatagram`]
+//! fails with the error reported by the system.
+
+#![unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+
+mod addr;
+mod datagram;
+mod listener;
+mod stream;
+#[cfg(test)]
+mod tests;
+
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+pub use self::addr::*;
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+pub use self::datagram::*;
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+pub use self::listener::*;
+#[unstable(feature = "windows_unix_domain_sockets", issue = "none")]
+pub use self::stream::*;
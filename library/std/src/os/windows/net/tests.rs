@@ -0,0 +1,88 @@
+use super::*;
+use crate::io::prelude::*;
+use crate::io::ErrorKind;
+use crate::net::Shutdown;
+use crate::sys_common::io::test::tmpdir;
+use crate::thread;
+use crate::time::Duration;
+
+macro_rules! or_panic {
+    ($e:expr) => {
+        match $e {
+            Ok(e) => e,
+            Err(e) => panic!("{}", e),
+        }
+    };
+}
+
+#[test]
+fn basic() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("sock");
+    let msg1 = b"hello";
+    let msg2 = b"world!";
+
+    let listener = or_panic!(UnixListener::bind(&socket_path));
+    let thread = thread::spawn(move || {
+        let mut stream = or_panic!(listener.accept()).0;
+        let mut buf = [0; 5];
+        or_panic!(stream.read_exact(&mut buf));
+        assert_eq!(&msg1[..], &buf[..]);
+        or_panic!(stream.write_all(msg2));
+    });
+
+    let mut stream = or_panic!(UnixStream::connect(&socket_path));
+    assert_eq!(Some(&*socket_path), stream.peer_addr().unwrap().as_pathname());
+    or_panic!(stream.write_all(msg1));
+    let mut buf = vec![];
+    or_panic!(stream.read_to_end(&mut buf));
+    assert_eq!(&msg2[..], &buf[..]);
+    drop(stream);
+
+    thread.join().unwrap();
+}
+
+#[test]
+fn listener_addr() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("sock");
+
+    let listener = or_panic!(UnixListener::bind(&socket_path));
+    let addr = or_panic!(listener.local_addr());
+    assert!(!addr.is_unnamed());
+    assert_eq!(addr.as_pathname(), Some(&*socket_path));
+}
+
+#[test]
+fn shutdown_and_timeouts() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("sock");
+
+    let listener = or_panic!(UnixListener::bind(&socket_path));
+    let stream = or_panic!(UnixStream::connect(&socket_path));
+    let (mut peer, _) = or_panic!(listener.accept());
+
+    let dur = Duration::from_millis(100);
+    or_panic!(stream.set_read_timeout(Some(dur)));
+    assert_eq!(or_panic!(stream.read_timeout()), Some(dur));
+    let err = stream.set_write_timeout(Some(Duration::new(0, 0))).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    or_panic!(stream.shutdown(Shutdown::Write));
+    let mut buf = [0; 1];
+    assert_eq!(or_panic!(peer.read(&mut buf)), 0);
+}
+
+#[test]
+fn long_path() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join(
+        "asdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfa\
+                                sasdfasdfasdasdfasdfasdfadfasdfasdfasdfasdfasdf",
+    );
+
+    let err = UnixListener::bind(&socket_path).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    let err = UnixStream::connect(&socket_path).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
@@ -12,6 +12,7 @@
 use core::panic::{BoxMeUp, Location, PanicInfo};
 
 use crate::any::Any;
+use crate::backtrace::Backtrace;
 use crate::fmt;
 use crate::intrinsics;
 use crate::mem::{self, ManuallyDrop};
@@ -177,6 +178,61 @@ pub fn take_hook() -> Box<dyn Fn(&PanicInfo<'_>) + 'static + Sync + Send> {
     }
 }
 
+/// Atomically updates the panic hook, wrapping the current one.
+///
+/// The new hook is built by `hook_fn`, which receives the previous hook (or
+/// the default hook, if no custom hook is registered) and the `PanicInfo` of
+/// each panic. It can run code before and after calling the previous hook, or
+/// skip it entirely.
+///
+/// Unlike a [`take_hook`] followed by a [`set_hook`], this cannot lose a hook
+/// installed by another thread in between, and there is no window in which
+/// a panic would go to the default hook.
+///
+/// # Panics
+///
+/// Panics if called from a panicking thread.
+///
+/// # Examples
+///
+/// The following will print "Custom panic hook" before the default message:
+///
+/// ```should_panic
+/// #![feature(panic_update_hook)]
+/// use std::panic;
+///
+/// panic::update_hook(|prev, info| {
+///     println!("Custom panic hook");
+///     prev(info);
+/// });
+///
+/// panic!("Normal panic");
+/// ```
+#[unstable(feature = "panic_update_hook", issue = "none")]
+pub fn update_hook<F>(hook_fn: F)
+where
+    F: Fn(&(dyn Fn(&PanicInfo<'_>) + Send + Sync + 'static), &PanicInfo<'_>)
+        + Sync
+        + Send
+        + 'static,
+{
+    if thread::panicking() {
+        panic!("cannot modify the panic hook from a panicking thread");
+    }
+
+    unsafe {
+        let guard = HOOK_LOCK.write();
+        let prev: Box<dyn Fn(&PanicInfo<'_>) + Send + Sync + 'static> = match HOOK {
+            Hook::Default => Box::new(default_hook),
+            Hook::Custom(ptr) => Box::from_raw(ptr),
+        };
+        let hook: Box<dyn Fn(&PanicInfo<'_>) + Send + Sync + 'static> =
+            Box::new(move |info| hook_fn(&prev, info));
+        HOOK = Hook::Custom(Box::into_raw(hook));
+        drop(guard);
+    }
+}
+
 fn default_hook(info: &PanicInfo<'_>) {
     // If this is a double panic, make sure that we print a backtrace
     // for this panic. Otherwise only print it if logging is enabled.
@@ -196,8 +252,7 @@ fn default_hook(info: &PanicInfo<'_>) {
             None => "Box<dyn Any>",
         },
     };
-    let thread = thread_info::current_thread();
-    let name = thread.as_ref().and_then(|t| t.name()).unwrap_or("<unnamed>");
+    let name = info.thread_name().unwrap_or("<unnamed>");
 
     let write = |err: &mut dyn crate::io::Write| {
         let _ = writeln!(err, "thread '{}' panicked at '{}', {}", name, msg, location);
@@ -606,8 +661,14 @@ fn rust_panic_with_hook(
         intrinsics::abort()
     }
 
+    crate::io::flush_on_panic();
+
     unsafe {
+        let thread = thread_info::current_thread();
+        let captured;
         let mut info = PanicInfo::internal_constructor(message, location);
+        info.set_thread_name(thread.as_ref().and_then(|t| t.name()));
+        info.set_nested(panics > 1);
         let _guard = HOOK_LOCK.read();
         match HOOK {
             // Some platforms (like wasm) know that printing to stderr won't ever actually
@@ -622,6 +683,13 @@ fn rust_panic_with_hook(
                 default_hook(&info);
             }
             Hook::Custom(ptr) => {
+                // Capture a backtrace whenever the default hook would have
+                // printed one.
+                if panics > 1 || matches!(backtrace::rust_backtrace_env(), RustBacktrace::Print(_))
+                {
+                    captured = Backtrace::force_capture();
+                    info.set_backtrace(Some(&captured));
+                }
                 info.set_payload(payload.get());
                 (*ptr)(&info);
             }
@@ -113,11 +113,12 @@
 use crate::num::NonZeroI32;
 use crate::path::Path;
 use crate::str;
-use crate::sys::pipe::{read2, AnonPipe};
+use crate::sys::pipe::{read2, read2_deadline, AnonPipe};
 use crate::sys::process as imp;
 #[unstable(feature = "command_access", issue = "44434")]
 pub use crate::sys_common::process::CommandEnvs;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+use crate::time::{Duration, Instant};
 
 /// Representation of a running or exited child process.
 ///
@@ -889,6 +890,40 @@ pub fn output(&mut self) -> io::Result<Output> {
             .and_then(|p| p.wait_with_output())
     }
 
+    /// Executes the command as a child process, collecting all of its output
+    /// and waiting at most `timeout` for it to finish.
+    ///
+    /// Stdio is configured exactly as for [`output`]. If the child has not
+    /// exited and closed its output streams once `timeout` has elapsed, it is
+    /// killed and reaped, and an error of kind [`io::ErrorKind::TimedOut`]
+    /// is returned.
+    ///
+    /// See [`Child::wait_with_output_timeout`] for details.
+    ///
+    /// [`output`]: Command::output
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_wait_timeout)]
+    /// use std::io::ErrorKind;
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// match Command::new("make").arg("test").output_timeout(Duration::from_secs(60)) {
+    ///     Ok(output) => println!("status: {}", output.status),
+    ///     Err(e) if e.kind() == ErrorKind::TimedOut => println!("tests took too long"),
+    ///     Err(e) => println!("failed to run tests: {}", e),
+    /// }
+    /// ```
+    #[unstable(feature = "process_wait_timeout", issue = "none")]
+    pub fn output_timeout(&mut self, timeout: Duration) -> io::Result<Output> {
+        self.inner
+            .spawn(imp::Stdio::MakePipe, false)
+            .map(Child::from_inner)
+            .and_then(|p| p.wait_with_output_timeout(timeout))
+    }
+
     /// Executes a command as a child process, waiting for it to finish and
     /// collecting its status.
     ///
@@ -1378,6 +1413,27 @@ fn from(file: fs::File) -> Stdio {
     }
 }
 
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeReader> for Stdio {
+    /// Converts the read end of a pipe created with [`io::pipe`] into a
+    /// `Stdio`, so that the child reads its input from the pipe.
+    fn from(pipe: io::PipeReader) -> Stdio {
+        Stdio::from_inner(pipe.into_inner().into())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeWriter> for Stdio {
+    /// Converts the write end of a pipe created with [`io::pipe`] into a
+    /// `Stdio`, so that the child writes its output into the pipe.
+    ///
+    /// Use [`io::PipeWriter::try_clone`] to pass the same pipe to several
+    /// streams or children.
+    fn from(pipe: io::PipeWriter) -> Stdio {
+        Stdio::from_inner(pipe.into_inner().into())
+    }
+}
+
 /// Describes the result of a process after it has terminated.
 ///
 /// This `struct` is used to represent the exit status or other termination of a child process.
@@ -1776,6 +1832,66 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         Ok(self.handle.try_wait()?.map(ExitStatus))
     }
 
+    /// Waits for the child to exit, blocking the calling thread for at most
+    /// `timeout`.
+    ///
+    /// If the child exits before the timeout elapses, `Ok(Some(status))` is
+    /// returned and, on Unix, the process ID is reaped. If the timeout
+    /// elapses first, `Ok(None)` is returned and the child keeps running. If
+    /// an error occurs, then that error is returned.
+    ///
+    /// Note that like `try_wait`, and unlike `wait`, this function will not
+    /// attempt to drop stdin, so the child can still be fed input between
+    /// calls.
+    ///
+    /// ## Platform-specific behavior
+    ///
+    /// Waiting is event-driven, and notices the exit of the child right away,
+    /// on these platforms:
+    ///
+    /// - Linux 5.3 and later, which waits on a pidfd;
+    /// - macOS, iOS, FreeBSD, NetBSD, OpenBSD and DragonFly BSD, which use
+    ///   kqueue's `EVFILT_PROC` filter;
+    /// - Windows, which uses `WaitForSingleObject`;
+    /// - Fuchsia, which waits on the process handle.
+    ///
+    /// Everywhere else, including older Linux kernels, there is no way to wait
+    /// for one particular child with a timeout without installing a `SIGCHLD`
+    /// handler, which would take the signal away from the application.
+    /// Instead, the child is polled with `waitpid`, at intervals starting at 1
+    /// millisecond and growing to 50 milliseconds. So the exit of a long-running child may be noticed up to
+    /// 50 milliseconds late, and the calling thread wakes up periodically
+    /// while it waits. This also applies to
+    /// [`wait_with_output_timeout`](Child::wait_with_output_timeout) and
+    /// [`Command::output_timeout`].
+    /// **Note that this [may change in the future][changes]**.
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_wait_timeout)]
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// let mut child = Command::new("sleep").arg("60").spawn().unwrap();
+    ///
+    /// match child.wait_timeout(Duration::from_secs(1)) {
+    ///     Ok(Some(status)) => println!("exited with: {}", status),
+    ///     Ok(None) => {
+    ///         println!("still running after a second, killing it");
+    ///         child.kill().unwrap();
+    ///         child.wait().unwrap();
+    ///     }
+    ///     Err(e) => println!("error attempting to wait: {}", e),
+    /// }
+    /// ```
+    #[unstable(feature = "process_wait_timeout", issue = "none")]
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        Ok(self.handle.wait_timeout(timeout)?.map(ExitStatus))
+    }
+
     /// Simultaneously waits for the child to exit and collect all remaining
     /// output on the stdout/stderr handles, returning an `Output`
     /// instance.
@@ -1832,6 +1948,74 @@ pub fn wait_with_output(mut self) -> io::Result<Output> {
         let status = self.wait()?;
         Ok(Output { status, stdout, stderr })
     }
+
+    /// Like [`wait_with_output`], but gives up once `timeout` has elapsed.
+    ///
+    /// The stdin handle to the child process, if any, is closed before
+    /// waiting, and any stdout/stderr pipes are read until they are closed.
+    /// If the child has not exited and closed its pipes by the time `timeout`
+    /// elapses, it is killed and reaped, and an error of kind
+    /// [`io::ErrorKind::TimedOut`] is returned. Output collected up to that
+    /// point is discarded.
+    ///
+    /// Note that the pipes stay open for as long as any process holds them,
+    /// so a child that leaves behind a background process which inherited
+    /// stdout or stderr will run into the timeout, even if the child itself
+    /// exited in time.
+    ///
+    /// [`wait_with_output`]: Child::wait_with_output
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_wait_timeout)]
+    /// use std::process::{Command, Stdio};
+    /// use std::time::Duration;
+    ///
+    /// let child = Command::new("/bin/cat")
+    ///     .arg("file.txt")
+    ///     .stdout(Stdio::piped())
+    ///     .spawn()
+    ///     .expect("failed to execute child");
+    ///
+    /// let output = child
+    ///     .wait_with_output_timeout(Duration::from_secs(5))
+    ///     .expect("failed to wait on child");
+    ///
+    /// assert!(output.status.success());
+    /// ```
+    #[unstable(feature = "process_wait_timeout", issue = "none")]
+    pub fn wait_with_output_timeout(mut self, timeout: Duration) -> io::Result<Output> {
+        let deadline = match Instant::now().checked_add(timeout) {
+            Some(deadline) => deadline,
+            None => return self.wait_with_output(),
+        };
+        drop(self.stdin.take());
+
+        let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+        let out = self.stdout.take().map(|out| out.inner);
+        let err = self.stderr.take().map(|err| err.inner);
+        let res = read2_deadline(out, &mut stdout, err, &mut stderr, deadline).and_then(|()| {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            self.handle.wait_timeout(remaining)?.ok_or_else(|| {
+                io::Error::new_const(io::ErrorKind::TimedOut, &"timed out waiting for child")
+            })
+        });
+
+        match res {
+            Ok(status) => Ok(Output { status: ExitStatus(status), stdout, stderr }),
+            Err(e) => {
+                if e.kind() == io::ErrorKind::TimedOut {
+                    // Don't leave the child running past the deadline. It may
+                    // have exited on its own in the meantime, so a failure to
+                    // kill it is not an error.
+                    let _ = self.handle.kill();
+                    self.handle.wait()?;
+                }
+                Err(e)
+            }
+        }
+    }
 }
 
 /// Terminates the current process with the specified exit code.
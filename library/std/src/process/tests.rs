@@ -203,6 +203,41 @@ fn test_wait_with_output_once() {
     assert_eq!(stderr, Vec::new());
 }
 
+#[test]
+#[cfg(unix)]
+#[cfg_attr(any(target_os = "vxworks", target_os = "android"), ignore)]
+fn test_wait_timeout() {
+    use crate::time::Duration;
+
+    let mut prog = Command::new("sleep").arg("10").spawn().unwrap();
+    assert!(prog.wait_timeout(Duration::from_millis(50)).unwrap().is_none());
+    prog.kill().unwrap();
+    assert!(prog.wait_timeout(Duration::from_secs(10)).unwrap().is_some());
+
+    let mut prog = Command::new("true").spawn().unwrap();
+    assert!(prog.wait_timeout(Duration::from_secs(10)).unwrap().unwrap().success());
+}
+
+#[test]
+#[cfg(unix)]
+#[cfg_attr(any(target_os = "vxworks", target_os = "android"), ignore)]
+fn test_output_timeout() {
+    use crate::time::Duration;
+
+    let Output { status, stdout, stderr } = Command::new("/bin/sh")
+        .arg("-c")
+        .arg("echo hello; echo world >&2")
+        .output_timeout(Duration::from_secs(10))
+        .unwrap();
+    assert!(status.success());
+    assert_eq!(stdout, b"hello\n");
+    assert_eq!(stderr, b"world\n");
+
+    let err =
+        Command::new("sleep").arg("10").output_timeout(Duration::from_millis(50)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TimedOut);
+}
+
 #[cfg(all(unix, not(target_os = "android")))]
 pub fn env_cmd() -> Command {
     Command::new("env")
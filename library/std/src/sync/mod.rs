@@ -169,6 +169,8 @@
 pub use self::poison::{LockResult, PoisonError, TryLockError, TryLockResult};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[unstable(feature = "rwlock_upgrade", issue = "none")]
+pub use self::rwlock::RwLockUpgradableReadGuard;
 
 pub mod mpsc;
 
@@ -6,6 +6,7 @@
 use crate::ops::{Deref, DerefMut};
 use crate::sync::{poison, LockResult, TryLockError, TryLockResult};
 use crate::sys_common::mutex as sys;
+use crate::time::Duration;
 
 /// A mutual exclusion primitive useful for protecting shared data
 ///
@@ -333,6 +334,64 @@ pub fn try_lock(&self) -> TryLockResult<MutexGuard<'_, T>> {
         }
     }
 
+    /// Attempts to acquire this lock, blocking the current thread for at most
+    /// `timeout`.
+    ///
+    /// If the lock could not be acquired before the timeout expired, then
+    /// `Err` is returned. Otherwise, an RAII guard is returned. The lock will
+    /// be unlocked when the guard is dropped.
+    ///
+    /// This is useful on paths that must not deadlock, such as diagnostics
+    /// that inspect state which may be held by a stuck thread.
+    ///
+    /// # Errors
+    ///
+    /// If another user of this mutex panicked while holding the mutex, then
+    /// this call will return the [`Poisoned`] error if the mutex would
+    /// otherwise be acquired.
+    ///
+    /// If the mutex could not be acquired before the timeout expired, then
+    /// this call will return the [`WouldBlock`] error.
+    ///
+    /// [`Poisoned`]: TryLockError::Poisoned
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to `pthread_mutex_timedlock` on
+    /// Linux. On other platforms it polls the lock, sleeping for increasing
+    /// amounts of time in between, so it may return somewhat after the lock
+    /// became available. **Note that this [may change in the future][changes]**.
+    ///
+    /// [changes]: crate::io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mutex_try_lock_for)]
+    /// use std::sync::Mutex;
+    /// use std::time::Duration;
+    ///
+    /// let mutex = Mutex::new(0);
+    ///
+    /// let guard = mutex.lock().unwrap();
+    /// assert!(mutex.try_lock_for(Duration::from_millis(10)).is_err());
+    /// drop(guard);
+    ///
+    /// *mutex.try_lock_for(Duration::from_millis(10)).unwrap() = 10;
+    /// assert_eq!(*mutex.lock().unwrap(), 10);
+    /// ```
+    #[unstable(feature = "mutex_try_lock_for", issue = "none")]
+    pub fn try_lock_for(&self, timeout: Duration) -> TryLockResult<MutexGuard<'_, T>> {
+        unsafe {
+            if self.inner.try_lock_for(timeout) {
+                Ok(MutexGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
     /// Determines whether the mutex is poisoned.
     ///
     /// If another thread is active, the mutex can still become poisoned at any
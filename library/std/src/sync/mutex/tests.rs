@@ -2,6 +2,7 @@
 use crate::sync::mpsc::channel;
 use crate::sync::{Arc, Condvar, Mutex};
 use crate::thread;
+use crate::time::Duration;
 
 struct Packet<T>(Arc<(Mutex<T>, Condvar)>);
 
@@ -57,6 +58,26 @@ fn try_lock() {
     *m.try_lock().unwrap() = ();
 }
 
+#[test]
+fn try_lock_for() {
+    let m = Arc::new(Mutex::new(0));
+    let guard = m.lock().unwrap();
+    assert!(m.try_lock_for(Duration::from_millis(10)).is_err());
+
+    // The lock is acquired as soon as another thread releases it.
+    let m2 = m.clone();
+    let (tx, rx) = channel();
+    let t = thread::spawn(move || {
+        tx.send(()).unwrap();
+        *m2.try_lock_for(Duration::from_secs(60)).unwrap() += 1;
+    });
+    rx.recv().unwrap();
+    thread::sleep(Duration::from_millis(10));
+    drop(guard);
+    t.join().unwrap();
+    assert_eq!(*m.try_lock_for(Duration::ZERO).unwrap(), 1);
+}
+
 #[test]
 fn test_into_inner() {
     let m = Mutex::new(NonCopy(10));
@@ -3,6 +3,7 @@
 
 use crate::cell::UnsafeCell;
 use crate::fmt;
+use crate::mem;
 use crate::ops::{Deref, DerefMut};
 use crate::sync::{poison, LockResult, TryLockError, TryLockResult};
 use crate::sys_common::rwlock as sys;
@@ -115,6 +116,32 @@ impl<T: ?Sized> !Send for RwLockWriteGuard<'_, T> {}
 #[stable(feature = "rwlock_guard_sync", since = "1.23.0")]
 unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
 
+/// RAII structure used to release the upgradable read access of a lock when
+/// dropped.
+///
+/// An upgradable read guard gives shared access like [`RwLockReadGuard`],
+/// but can be atomically turned into a [`RwLockWriteGuard`] with
+/// [`upgrade`]. Only one upgradable read guard can exist for a lock at a
+/// time; it blocks writers, but not plain readers.
+///
+/// This structure is created by the [`upgradable_read`] and
+/// [`try_upgradable_read`] methods on [`RwLock`].
+///
+/// [`upgrade`]: RwLockUpgradableReadGuard::upgrade
+/// [`upgradable_read`]: RwLock::upgradable_read
+/// [`try_upgradable_read`]: RwLock::try_upgradable_read
+#[must_use = "if unused the RwLock will immediately unlock"]
+#[unstable(feature = "rwlock_upgrade", issue = "none")]
+pub struct RwLockUpgradableReadGuard<'a, T: ?Sized + 'a> {
+    lock: &'a RwLock<T>,
+}
+
+#[unstable(feature = "rwlock_upgrade", issue = "none")]
+impl<T: ?Sized> !Send for RwLockUpgradableReadGuard<'_, T> {}
+
+#[unstable(feature = "rwlock_upgrade", issue = "none")]
+unsafe impl<T: ?Sized + Sync> Sync for RwLockUpgradableReadGuard<'_, T> {}
+
 impl<T> RwLock<T> {
     /// Creates a new instance of an `RwLock<T>` which is unlocked.
     ///
@@ -321,6 +348,99 @@ pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<'_, T>> {
         }
     }
 
+    /// Locks this rwlock with upgradable shared read access, blocking the
+    /// current thread until it can be acquired.
+    ///
+    /// The returned guard gives shared access, and can later be turned into
+    /// exclusive access with [`RwLockUpgradableReadGuard::upgrade`] without
+    /// letting any writer in between. Other threads may hold plain read
+    /// locks at the same time, but no writer and no other upgradable reader.
+    ///
+    /// Returns an RAII guard which will release this thread's upgradable
+    /// access once it is dropped.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the RwLock is poisoned. An RwLock
+    /// is poisoned whenever a writer panics while holding an exclusive lock.
+    /// The failure will occur immediately after the lock has been acquired.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when called if the lock is already held by
+    /// the current thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgrade)]
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(Vec::new());
+    ///
+    /// let cache = lock.upgradable_read().unwrap();
+    /// if cache.is_empty() {
+    ///     // Nobody else can have filled the cache in the meantime.
+    ///     let mut cache = RwLockUpgradableReadGuard::upgrade(cache);
+    ///     cache.push(42);
+    /// }
+    /// assert_eq!(*lock.read().unwrap(), [42]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "rwlock_upgrade", issue = "none")]
+    pub fn upgradable_read(&self) -> LockResult<RwLockUpgradableReadGuard<'_, T>> {
+        unsafe {
+            self.inner.upgradable_read();
+            RwLockUpgradableReadGuard::new(self)
+        }
+    }
+
+    /// Attempts to acquire this rwlock with upgradable shared read access.
+    ///
+    /// If the access could not be granted at this time, then `Err` is
+    /// returned. Otherwise, an RAII guard is returned which will release the
+    /// upgradable access when it is dropped.
+    ///
+    /// This function does not block.
+    ///
+    /// # Errors
+    ///
+    /// This function will return the [`Poisoned`] error if the RwLock is
+    /// poisoned. `Poisoned` will only be returned if the lock would have
+    /// otherwise been acquired.
+    ///
+    /// This function will return the [`WouldBlock`] error if the RwLock could
+    /// not be acquired because it was already locked exclusively or by
+    /// another upgradable reader.
+    ///
+    /// [`Poisoned`]: TryLockError::Poisoned
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgrade)]
+    /// use std::sync::RwLock;
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let n = lock.upgradable_read().unwrap();
+    /// assert!(lock.try_upgradable_read().is_err());
+    /// assert!(lock.try_read().is_ok());
+    /// drop(n);
+    /// ```
+    #[inline]
+    #[unstable(feature = "rwlock_upgrade", issue = "none")]
+    pub fn try_upgradable_read(&self) -> TryLockResult<RwLockUpgradableReadGuard<'_, T>> {
+        unsafe {
+            if self.inner.try_upgradable_read() {
+                Ok(RwLockUpgradableReadGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
     /// Determines whether the lock is poisoned.
     ///
     /// If another thread is active, the lock can still become poisoned at any
@@ -459,6 +579,122 @@ impl<'rwlock, T: ?Sized> RwLockWriteGuard<'rwlock, T> {
     unsafe fn new(lock: &'rwlock RwLock<T>) -> LockResult<RwLockWriteGuard<'rwlock, T>> {
         poison::map_result(lock.poison.borrow(), |guard| RwLockWriteGuard { lock, poison: guard })
     }
+
+    /// Atomically downgrades a write lock into a read lock, without allowing
+    /// any writer to take exclusive access in the meantime.
+    ///
+    /// Other readers waiting for the lock may get in once this returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgrade)]
+    /// use std::sync::{RwLock, RwLockWriteGuard};
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let mut n = lock.write().unwrap();
+    /// *n = 2;
+    ///
+    /// let n = RwLockWriteGuard::downgrade(n);
+    /// assert_eq!(*n, 2);
+    /// assert!(lock.try_read().is_ok());
+    /// ```
+    #[unstable(feature = "rwlock_upgrade", issue = "none")]
+    pub fn downgrade(s: Self) -> RwLockReadGuard<'rwlock, T> {
+        let lock = s.lock;
+        lock.poison.done(&s.poison);
+        mem::forget(s);
+        unsafe { lock.inner.downgrade() };
+        RwLockReadGuard { lock }
+    }
+
+    /// Atomically downgrades a write lock into an upgradable read lock,
+    /// without allowing any writer to take exclusive access in the meantime.
+    #[unstable(feature = "rwlock_upgrade", issue = "none")]
+    pub fn downgrade_to_upgradable(s: Self) -> RwLockUpgradableReadGuard<'rwlock, T> {
+        let lock = s.lock;
+        lock.poison.done(&s.poison);
+        mem::forget(s);
+        unsafe { lock.inner.downgrade_to_upgradable() };
+        RwLockUpgradableReadGuard { lock }
+    }
+}
+
+impl<'rwlock, T: ?Sized> RwLockUpgradableReadGuard<'rwlock, T> {
+    unsafe fn new(lock: &'rwlock RwLock<T>) -> LockResult<RwLockUpgradableReadGuard<'rwlock, T>> {
+        poison::map_result(lock.poison.borrow(), |_| RwLockUpgradableReadGuard { lock })
+    }
+
+    /// Atomically upgrades an upgradable read lock into a write lock,
+    /// blocking the current thread until all other readers have released
+    /// the lock.
+    ///
+    /// No writer can modify the data between the upgradable read lock being
+    /// acquired and this function returning.
+    ///
+    /// The lock cannot become poisoned while an upgradable read lock is held,
+    /// so any poisoning was already reported when it was acquired.
+    #[unstable(feature = "rwlock_upgrade", issue = "none")]
+    pub fn upgrade(s: Self) -> RwLockWriteGuard<'rwlock, T> {
+        let lock = s.lock;
+        mem::forget(s);
+        unsafe { lock.inner.upgrade() };
+        RwLockWriteGuard::from_upgraded(lock)
+    }
+
+    /// Attempts to atomically upgrade an upgradable read lock into a write
+    /// lock, returning the upgradable read lock back if other readers still
+    /// hold the lock.
+    ///
+    /// This function does not block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgrade)]
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let reader = lock.read().unwrap();
+    /// let n = lock.upgradable_read().unwrap();
+    /// let n = RwLockUpgradableReadGuard::try_upgrade(n).unwrap_err();
+    ///
+    /// drop(reader);
+    /// let mut n = RwLockUpgradableReadGuard::try_upgrade(n).unwrap();
+    /// *n += 1;
+    /// ```
+    #[unstable(feature = "rwlock_upgrade", issue = "none")]
+    pub fn try_upgrade(s: Self) -> Result<RwLockWriteGuard<'rwlock, T>, Self> {
+        if unsafe { s.lock.inner.try_upgrade() } {
+            let lock = s.lock;
+            mem::forget(s);
+            Ok(RwLockWriteGuard::from_upgraded(lock))
+        } else {
+            Err(s)
+        }
+    }
+
+    /// Downgrades an upgradable read lock into a plain read lock, allowing
+    /// other threads to acquire upgradable read locks.
+    #[unstable(feature = "rwlock_upgrade", issue = "none")]
+    pub fn downgrade(s: Self) -> RwLockReadGuard<'rwlock, T> {
+        let lock = s.lock;
+        mem::forget(s);
+        unsafe { lock.inner.downgrade_upgradable() };
+        RwLockReadGuard { lock }
+    }
+}
+
+impl<'rwlock, T: ?Sized> RwLockWriteGuard<'rwlock, T> {
+    fn from_upgraded(lock: &'rwlock RwLock<T>) -> RwLockWriteGuard<'rwlock, T> {
+        let poison = match lock.poison.borrow() {
+            Ok(guard) => guard,
+            Err(err) => err.into_inner(),
+        };
+        RwLockWriteGuard { lock, poison }
+    }
 }
 
 #[stable(feature = "std_debug", since = "1.16.0")]
@@ -498,6 +734,29 @@ fn deref(&self) -> &T {
     }
 }
 
+#[unstable(feature = "rwlock_upgrade", issue = "none")]
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLockUpgradableReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[unstable(feature = "rwlock_upgrade", issue = "none")]
+impl<T: ?Sized + fmt::Display> fmt::Display for RwLockUpgradableReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[unstable(feature = "rwlock_upgrade", issue = "none")]
+impl<T: ?Sized> Deref for RwLockUpgradableReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
     type Target = T;
@@ -523,6 +782,15 @@ fn drop(&mut self) {
     }
 }
 
+#[unstable(feature = "rwlock_upgrade", issue = "none")]
+impl<T: ?Sized> Drop for RwLockUpgradableReadGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            self.lock.inner.upgradable_read_unlock();
+        }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
     fn drop(&mut self) {
@@ -1,6 +1,6 @@
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sync::mpsc::channel;
-use crate::sync::{Arc, RwLock, TryLockError};
+use crate::sync::{Arc, RwLock, RwLockUpgradableReadGuard, RwLockWriteGuard, TryLockError};
 use crate::thread;
 use rand::{self, Rng};
 
@@ -181,6 +181,107 @@ fn test_rwlock_try_write() {
     drop(read_guard);
 }
 
+#[test]
+fn test_rwlock_upgradable_read() {
+    let lock = RwLock::new(0);
+    let upgradable = lock.upgradable_read().unwrap();
+
+    // Readers may share the lock, but writers and other upgradable readers
+    // may not.
+    drop(lock.try_read().unwrap());
+    assert!(matches!(lock.try_write(), Err(TryLockError::WouldBlock)));
+    assert!(matches!(lock.try_upgradable_read(), Err(TryLockError::WouldBlock)));
+
+    let reader = lock.read().unwrap();
+    let upgradable = RwLockUpgradableReadGuard::try_upgrade(upgradable).unwrap_err();
+    drop(reader);
+
+    let mut writer = RwLockUpgradableReadGuard::upgrade(upgradable);
+    *writer = 1;
+    assert!(lock.try_read().is_err());
+
+    let upgradable = RwLockWriteGuard::downgrade_to_upgradable(writer);
+    assert_eq!(*upgradable, 1);
+    assert!(lock.try_write().is_err());
+
+    let reader = RwLockUpgradableReadGuard::downgrade(upgradable);
+    drop(lock.try_upgradable_read().unwrap());
+    assert!(lock.try_write().is_err());
+    drop(reader);
+
+    let writer = lock.write().unwrap();
+    let reader = RwLockWriteGuard::downgrade(writer);
+    assert_eq!(*reader, 1);
+    assert!(lock.try_write().is_err());
+    drop(lock.try_read().unwrap());
+    drop(reader);
+
+    assert!(lock.try_write().is_ok());
+}
+
+#[test]
+fn test_rwlock_upgrade_excludes_writers() {
+    const N: usize = 10;
+
+    let lock = Arc::new(RwLock::new(0));
+    let (tx, rx) = channel();
+    for _ in 0..N {
+        let lock = lock.clone();
+        let tx = tx.clone();
+        thread::spawn(move || {
+            // Check-then-act under an upgradable read lock: the value seen
+            // before upgrading must still be there afterwards.
+            let guard = lock.upgradable_read().unwrap();
+            let seen = *guard;
+            let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
+            assert_eq!(*guard, seen);
+            *guard += 1;
+            drop(guard);
+            *lock.write().unwrap() += 1;
+            tx.send(()).unwrap();
+        });
+    }
+    drop(tx);
+    for _ in 0..N {
+        rx.recv().unwrap();
+    }
+    assert_eq!(*lock.read().unwrap(), 2 * N);
+}
+
+#[test]
+fn test_rwlock_downgrade_excludes_writers() {
+    const N: usize = 10;
+
+    let lock = Arc::new(RwLock::new(0));
+    let (tx, rx) = channel();
+    for i in 0..2 * N {
+        let lock = lock.clone();
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let mut guard = lock.write().unwrap();
+            *guard += 1;
+            let seen = *guard;
+            // Plain writers keep hammering the lock while the others
+            // downgrade; none of them may get in between.
+            if i % 2 == 0 {
+                let guard = RwLockWriteGuard::downgrade(guard);
+                assert_eq!(*guard, seen);
+            } else {
+                let guard = RwLockWriteGuard::downgrade_to_upgradable(guard);
+                assert_eq!(*guard, seen);
+                let guard = RwLockUpgradableReadGuard::upgrade(guard);
+                assert_eq!(*guard, seen);
+            }
+            tx.send(()).unwrap();
+        });
+    }
+    drop(tx);
+    for _ in 0..2 * N {
+        rx.recv().unwrap();
+    }
+    assert_eq!(*lock.read().unwrap(), 2 * N);
+}
+
 #[test]
 fn test_into_inner() {
     let m = RwLock::new(NonCopy(10));
@@ -12,7 +12,7 @@
 use crate::sys::time::SystemTime;
 use crate::sys::unsupported;
 
-pub use crate::sys_common::fs::{copy, try_exists};
+pub use crate::sys_common::fs::{copy, create_temp, create_temp_dir, try_exists};
 //pub use crate::sys_common::fs::remove_dir_all;
 
 fn cstr(path: &Path) -> io::Result<CString> {
@@ -6,6 +6,8 @@
 use crate::ptr;
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sys::hermit::abi;
+use crate::sys_common::mutex::try_lock_with_backoff;
+use crate::time::Duration;
 
 /// This type provides a lock based on busy waiting to realize mutual exclusion
 ///
@@ -208,6 +210,11 @@ pub unsafe fn try_lock(&self) -> bool {
         guard.locked
     }
 
+    #[inline]
+    pub unsafe fn try_lock_for(&self, dur: Duration) -> bool {
+        try_lock_with_backoff(|| self.try_lock(), dur)
+    }
+
     #[inline]
     pub unsafe fn destroy(&self) {}
 }
@@ -1,7 +1,7 @@
 use crate::convert::TryFrom;
 use crate::fmt;
 use crate::io::{self, ErrorKind, IoSlice, IoSliceMut};
-use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, LookupOptions, Shutdown, SocketAddr};
 use crate::str;
 use crate::sync::Arc;
 use crate::sys::hermit::abi;
@@ -417,6 +417,14 @@ impl LookupHost {
     pub fn port(&self) -> u16 {
         self.0
     }
+
+    pub fn with_options(_host: &str, _port: u16, _opts: &LookupOptions) -> io::Result<LookupHost> {
+        unsupported()
+    }
+
+    pub fn canonical_name(&self) -> Option<&str> {
+        self.0
+    }
 }
 
 impl Iterator for LookupHost {
@@ -442,6 +450,10 @@ fn try_from(_v: (&'a str, u16)) -> io::Result<LookupHost> {
     }
 }
 
+pub fn lookup_addr(_addr: &IpAddr) -> io::Result<String> {
+    unsupported()
+}
+
 #[allow(nonstandard_style)]
 pub mod netc {
     pub const AF_INET: u8 = 0;
@@ -7,6 +7,7 @@
 use crate::num::NonZeroUsize;
 use crate::sys::hermit::abi;
 use crate::sys::hermit::thread_local_dtor::run_dtors;
+use crate::thread::Priority;
 use crate::time::Duration;
 
 pub type Tid = abi::Tid;
@@ -71,6 +72,14 @@ pub fn set_name(_name: &CStr) {
         // nope
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_priority(_priority: Priority) -> io::Result<()> {
+        unsupported()
+    }
+
     #[inline]
     pub fn sleep(dur: Duration) {
         unsafe {
@@ -3,6 +3,8 @@
 use super::abi::thread;
 
 use super::waitqueue::{try_lock_or_false, NotifiedTcs, SpinMutex, WaitQueue, WaitVariable};
+use crate::sys_common::mutex::try_lock_with_backoff;
+use crate::time::Duration;
 
 pub struct Mutex {
     inner: SpinMutex<WaitVariable<bool>>,
@@ -56,6 +58,11 @@ pub unsafe fn try_lock(&self) -> bool {
         }
     }
 
+    #[inline]
+    pub unsafe fn try_lock_for(&self, dur: Duration) -> bool {
+        try_lock_with_backoff(|| self.try_lock(), dur)
+    }
+
     #[inline]
     pub unsafe fn destroy(&self) {}
 }
@@ -2,7 +2,9 @@
 use crate::error;
 use crate::fmt;
 use crate::io::{self, IoSlice, IoSliceMut};
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, ToSocketAddrs};
+use crate::net::{
+    AddressFamily, IpAddr, Ipv4Addr, Ipv6Addr, LookupOptions, Shutdown, SocketAddr, ToSocketAddrs,
+};
 use crate::sync::Arc;
 use crate::sys::fd::FileDesc;
 use crate::sys::{sgx_ineffective, unsupported, AsInner, FromInner, IntoInner, TryIntoInner};
@@ -472,6 +474,23 @@ fn new(host: String) -> io::Result<LookupHost> {
     pub fn port(&self) -> u16 {
         self.0
     }
+
+    pub fn with_options(host: &str, port: u16, opts: &LookupOptions) -> io::Result<LookupHost> {
+        // The host name is resolved by the enclave runner, which only gets
+        // to see the `NonIpSockAddr`, so none of the options can be passed on.
+        if opts.family != AddressFamily::Any
+            || opts.canonical_name
+            || opts.addr_config
+            || opts.numeric_host
+        {
+            return unsupported();
+        }
+        LookupHost::new(format!("{}:{}", host, port))
+    }
+
+    pub fn canonical_name(&self) -> Option<&str> {
+        self.0
+    }
 }
 
 impl Iterator for LookupHost {
@@ -497,6 +516,10 @@ fn try_from((host, port): (&'a str, u16)) -> io::Result<LookupHost> {
     }
 }
 
+pub fn lookup_addr(_addr: &IpAddr) -> io::Result<String> {
+    unsupported()
+}
+
 #[allow(bad_style)]
 pub mod netc {
     pub const AF_INET: u8 = 0;
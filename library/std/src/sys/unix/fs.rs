@@ -10,12 +10,22 @@
 use crate::sys::fd::FileDesc;
 use crate::sys::time::SystemTime;
 use crate::sys::{cvt, cvt_r};
+use crate::sys_common::fs::create_temp_with;
 use crate::sys_common::{AsInner, FromInner};
 
 use libc::{c_int, mode_t};
 
 #[cfg(any(target_os = "linux", target_os = "emscripten", target_os = "android"))]
 use libc::dirfd;
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "emscripten",
+    target_os = "android",
+    target_os = "l4re",
+    target_os = "redox",
+    target_os = "vxworks"
+)))]
+use libc::fstatat as fstatat64;
 #[cfg(any(target_os = "linux", target_os = "emscripten"))]
 use libc::fstatat64;
 #[cfg(not(any(
@@ -421,6 +431,35 @@ fn from_inner(mode: u32) -> FilePermissions {
     }
 }
 
+impl ReadDir {
+    fn new(dirp: Dir, root: PathBuf) -> ReadDir {
+        ReadDir {
+            inner: Arc::new(InnerReadDir { dirp, root }),
+            #[cfg(not(any(
+                target_os = "solaris",
+                target_os = "illumos",
+                target_os = "fuchsia",
+                target_os = "redox",
+            )))]
+            end_of_stream: false,
+        }
+    }
+
+    /// Returns the attributes of the directory being read.
+    #[cfg(not(any(target_os = "redox", target_os = "vxworks")))]
+    pub fn file_attr(&self) -> io::Result<FileAttr> {
+        let fd = cvt(unsafe { libc::dirfd(self.inner.dirp.0) })?;
+        let mut stat: stat64 = unsafe { mem::zeroed() };
+        cvt(unsafe { fstat64(fd, &mut stat) })?;
+        Ok(FileAttr::from_stat64(stat))
+    }
+
+    #[cfg(any(target_os = "redox", target_os = "vxworks"))]
+    pub fn file_attr(&self) -> io::Result<FileAttr> {
+        stat(&self.inner.root)
+    }
+}
+
 impl fmt::Debug for ReadDir {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // This will only be called from std::fs::ReadDir, which will add a "ReadDir()" frame.
@@ -647,6 +686,48 @@ fn name_bytes(&self) -> &[u8] {
     fn name_bytes(&self) -> &[u8] {
         &*self.name
     }
+
+    /// Opens this entry as a directory relative to the directory it was read from, so that
+    /// renaming any of its ancestors cannot redirect the open. Unless `follow_symlink` is set,
+    /// the open fails if the entry has been replaced by a symlink in the meantime.
+    #[cfg(not(any(target_os = "redox", target_os = "vxworks")))]
+    pub fn open_dir(&self, follow_symlink: bool) -> io::Result<ReadDir> {
+        let name = CString::new(self.name_bytes())?;
+        let mut flags = libc::O_RDONLY | libc::O_DIRECTORY | libc::O_CLOEXEC;
+        if !follow_symlink {
+            flags |= libc::O_NOFOLLOW;
+        }
+        let parent = cvt(unsafe { libc::dirfd(self.dir.dirp.0) })?;
+        let fd = FileDesc::new(cvt_r(|| unsafe { libc::openat(parent, name.as_ptr(), flags) })?);
+        let ptr = unsafe { libc::fdopendir(fd.raw()) };
+        if ptr.is_null() {
+            return Err(Error::last_os_error());
+        }
+        // The descriptor is now owned by the directory stream.
+        fd.into_raw();
+        Ok(ReadDir::new(Dir(ptr), self.path()))
+    }
+
+    #[cfg(any(target_os = "redox", target_os = "vxworks"))]
+    pub fn open_dir(&self, _follow_symlink: bool) -> io::Result<ReadDir> {
+        readdir(&self.path())
+    }
+
+    /// Returns the attributes of the file this entry refers to, following a symlink. Like
+    /// `open_dir`, the entry is looked up relative to the directory it was read from.
+    #[cfg(not(any(target_os = "l4re", target_os = "redox", target_os = "vxworks")))]
+    pub fn metadata_follow(&self) -> io::Result<FileAttr> {
+        let name = CString::new(self.name_bytes())?;
+        let parent = cvt(unsafe { libc::dirfd(self.dir.dirp.0) })?;
+        let mut stat: stat64 = unsafe { mem::zeroed() };
+        cvt(unsafe { fstatat64(parent, name.as_ptr(), &mut stat, 0) })?;
+        Ok(FileAttr::from_stat64(stat))
+    }
+
+    #[cfg(any(target_os = "l4re", target_os = "redox", target_os = "vxworks"))]
+    pub fn metadata_follow(&self) -> io::Result<FileAttr> {
+        stat(&self.path())
+    }
 }
 
 impl OpenOptions {
@@ -890,6 +971,27 @@ pub fn set_permissions(&self, perm: FilePermissions) -> io::Result<()> {
         cvt_r(|| unsafe { libc::fchmod(self.0.raw(), perm.mode) })?;
         Ok(())
     }
+
+    #[cfg(target_os = "linux")]
+    pub fn memfd_create(name: &OsStr, flags: libc::c_uint) -> io::Result<File> {
+        syscall! {
+            fn memfd_create(name: *const libc::c_char, flags: libc::c_uint) -> c_int
+        }
+        let name = CString::new(name.as_bytes())?;
+        let fd = cvt(unsafe { memfd_create(name.as_ptr(), flags | libc::MFD_CLOEXEC) })?;
+        Ok(File(FileDesc::new(fd)))
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn add_seals(&self, seals: c_int) -> io::Result<()> {
+        cvt(unsafe { libc::fcntl(self.0.raw(), libc::F_ADD_SEALS, seals) })?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn seals(&self) -> io::Result<c_int> {
+        cvt(unsafe { libc::fcntl(self.0.raw(), libc::F_GET_SEALS) })
+    }
 }
 
 impl DirBuilder {
@@ -1001,21 +1103,7 @@ pub fn readdir(p: &Path) -> io::Result<ReadDir> {
     let p = cstr(p)?;
     unsafe {
         let ptr = libc::opendir(p.as_ptr());
-        if ptr.is_null() {
-            Err(Error::last_os_error())
-        } else {
-            let inner = InnerReadDir { dirp: Dir(ptr), root };
-            Ok(ReadDir {
-                inner: Arc::new(inner),
-                #[cfg(not(any(
-                    target_os = "solaris",
-                    target_os = "illumos",
-                    target_os = "fuchsia",
-                    target_os = "redox",
-                )))]
-                end_of_stream: false,
-            })
-        }
+        if ptr.is_null() { Err(Error::last_os_error()) } else { Ok(ReadDir::new(Dir(ptr), root)) }
     }
 }
 
@@ -1044,6 +1132,57 @@ pub fn rmdir(p: &Path) -> io::Result<()> {
     Ok(())
 }
 
+pub fn create_temp(dir: &Path) -> io::Result<File> {
+    // An `O_TMPFILE` file never has a name, so it can neither be opened by anyone else nor
+    // outlive the process.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        let mut opts = OpenOptions::new();
+        opts.read(true);
+        opts.write(true);
+        opts.mode(0o600);
+        opts.custom_flags(libc::O_TMPFILE | libc::O_EXCL);
+        match File::open(dir, &opts) {
+            Ok(file) => return Ok(file),
+            // Not supported by the kernel or the file system. Depending on the kernel version and
+            // file system this is reported as `EOPNOTSUPP`, `EISDIR`, `EINVAL` or `ENOENT`; if the
+            // directory really is missing, the fallback below reports that instead.
+            Err(e)
+                if matches!(
+                    e.raw_os_error(),
+                    Some(libc::EOPNOTSUPP | libc::EISDIR | libc::EINVAL | libc::ENOENT)
+                ) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    let mut opts = OpenOptions::new();
+    opts.read(true);
+    opts.write(true);
+    opts.create_new(true);
+    opts.mode(0o600);
+    create_temp_with(dir, |path| {
+        let file = File::open(path, &opts)?;
+        if let Err(e) = unlink(path) {
+            // Don't leave the file behind. Some file systems, like CIFS, refuse to remove open
+            // files, so close it first and try again.
+            drop(file);
+            let _ = unlink(path);
+            return Err(e);
+        }
+        Ok(file)
+    })
+}
+
+pub fn create_temp_dir(dir: &Path) -> io::Result<PathBuf> {
+    let mut builder = DirBuilder::new();
+    builder.set_mode(0o700);
+    create_temp_with(dir, |path| {
+        builder.mkdir(path)?;
+        Ok(path.to_path_buf())
+    })
+}
+
 pub fn readlink(p: &Path) -> io::Result<PathBuf> {
     let c_path = cstr(p)?;
     let p = c_path.as_ptr();
@@ -605,23 +605,23 @@ enum SpliceMode {
     Splice,
 }
 
+syscall! {
+    fn splice(
+        srcfd: libc::c_int,
+        src_offset: *mut i64,
+        dstfd: libc::c_int,
+        dst_offset: *mut i64,
+        len: libc::size_t,
+        flags: libc::c_uint
+    ) -> libc::ssize_t
+}
+
 /// performs splice or sendfile between file descriptors
 /// Does _not_ fall back to a generic copy loop.
 fn sendfile_splice(mode: SpliceMode, reader: RawFd, writer: RawFd, len: u64) -> CopyResult {
     static HAS_SENDFILE: AtomicBool = AtomicBool::new(true);
     static HAS_SPLICE: AtomicBool = AtomicBool::new(true);
 
-    syscall! {
-        fn splice(
-            srcfd: libc::c_int,
-            src_offset: *const i64,
-            dstfd: libc::c_int,
-            dst_offset: *const i64,
-            len: libc::size_t,
-            flags: libc::c_int
-        ) -> libc::ssize_t
-    }
-
     match mode {
         SpliceMode::Sendfile if !HAS_SENDFILE.load(Ordering::Relaxed) => {
             return CopyResult::Fallback(0);
@@ -676,3 +676,67 @@ fn splice(
     }
     CopyResult::Ended(written)
 }
+
+/// Converts an optional caller-provided file offset into the `loff_t` the kernel expects.
+#[cfg(target_os = "linux")]
+fn kernel_offset(offset: &Option<&mut u64>) -> Result<Option<i64>> {
+    match offset {
+        Some(offset) => match (**offset).try_into() {
+            Ok(offset) => Ok(Some(offset)),
+            Err(_) => Err(Error::new_const(
+                crate::io::ErrorKind::InvalidInput,
+                &"file offset is too large for the kernel",
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Performs a single `sendfile(2)` call, reading from `reader` at `offset` if one is given
+/// and updating it with the number of bytes transferred.
+#[cfg(target_os = "linux")]
+pub(crate) fn sendfile_with_offset(
+    reader: RawFd,
+    offset: Option<&mut u64>,
+    writer: RawFd,
+    len: usize,
+) -> Result<usize> {
+    #[cfg(target_env = "musl")]
+    use libc::sendfile as sendfile64;
+    #[cfg(not(target_env = "musl"))]
+    use libc::sendfile64;
+
+    let mut kernel_off = kernel_offset(&offset)?;
+    let off_ptr = kernel_off.as_mut().map_or(ptr::null_mut(), |off| off as *mut i64);
+    let len = min(len, 0x7ffff000);
+    let ret = cvt(unsafe { sendfile64(writer, reader, off_ptr, len) })?;
+    if let (Some(offset), Some(kernel_off)) = (offset, kernel_off) {
+        *offset = kernel_off as u64;
+    }
+    Ok(ret as usize)
+}
+
+/// Performs a single `splice(2)` call. At least one of the two descriptors must be a pipe;
+/// offsets may only be given for the non-pipe ends and are updated on success.
+#[cfg(target_os = "linux")]
+pub(crate) fn splice_with_offsets(
+    reader: RawFd,
+    in_offset: Option<&mut u64>,
+    writer: RawFd,
+    out_offset: Option<&mut u64>,
+    len: usize,
+    flags: libc::c_uint,
+) -> Result<usize> {
+    let mut kernel_in = kernel_offset(&in_offset)?;
+    let mut kernel_out = kernel_offset(&out_offset)?;
+    let in_ptr = kernel_in.as_mut().map_or(ptr::null_mut(), |off| off as *mut i64);
+    let out_ptr = kernel_out.as_mut().map_or(ptr::null_mut(), |off| off as *mut i64);
+    let ret = cvt(unsafe { splice(reader, in_ptr, writer, out_ptr, len, flags) })?;
+    if let (Some(offset), Some(kernel_off)) = (in_offset, kernel_in) {
+        *offset = kernel_off as u64;
+    }
+    if let (Some(offset), Some(kernel_off)) = (out_offset, kernel_out) {
+        *offset = kernel_off as u64;
+    }
+    Ok(ret as usize)
+}
@@ -12,7 +12,7 @@ pub mod net {
     use crate::convert::TryFrom;
     use crate::fmt;
     use crate::io::{self, IoSlice, IoSliceMut};
-    use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+    use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, LookupOptions, Shutdown, SocketAddr};
     use crate::sys::fd::FileDesc;
     use crate::sys_common::{AsInner, FromInner, IntoInner};
     use crate::time::Duration;
@@ -483,6 +483,18 @@ impl LookupHost {
         pub fn port(&self) -> u16 {
             unimpl!();
         }
+
+        pub fn with_options(
+            _host: &str,
+            _port: u16,
+            _opts: &LookupOptions,
+        ) -> io::Result<LookupHost> {
+            unimpl!();
+        }
+
+        pub fn canonical_name(&self) -> Option<&str> {
+            None
+        }
     }
 
     unsafe impl Sync for LookupHost {}
@@ -503,4 +515,8 @@ fn try_from(_v: (&'a str, u16)) -> io::Result<LookupHost> {
             unimpl!();
         }
     }
+
+    pub fn lookup_addr(_addr: &IpAddr) -> io::Result<String> {
+        unimpl!();
+    }
 }
@@ -1,6 +1,7 @@
 use crate::cell::UnsafeCell;
 use crate::mem::MaybeUninit;
 use crate::sys::cvt_nz;
+use crate::time::Duration;
 
 pub struct Mutex {
     inner: UnsafeCell<libc::pthread_mutex_t>,
@@ -72,6 +73,35 @@ pub unsafe fn unlock(&self) {
     pub unsafe fn try_lock(&self) -> bool {
         libc::pthread_mutex_trylock(self.inner.get()) == 0
     }
+    #[cfg(target_os = "linux")]
+    pub unsafe fn try_lock_for(&self, dur: Duration) -> bool {
+        use crate::convert::TryFrom;
+        use crate::mem;
+
+        // `pthread_mutex_timedlock` takes an absolute `CLOCK_REALTIME` deadline.
+        let mut now: libc::timespec = mem::zeroed();
+        let r = libc::clock_gettime(libc::CLOCK_REALTIME, &mut now);
+        assert_eq!(r, 0);
+
+        // Nanosecond calculations can't overflow because both values are below 1e9.
+        let nsec = dur.subsec_nanos() + now.tv_nsec as u32;
+        let sec = libc::time_t::try_from(dur.as_secs())
+            .ok()
+            .and_then(|s| s.checked_add((nsec / 1_000_000_000) as libc::time_t))
+            .and_then(|s| s.checked_add(now.tv_sec));
+        let deadline = match sec {
+            Some(sec) => libc::timespec { tv_sec: sec, tv_nsec: (nsec % 1_000_000_000) as _ },
+            None => libc::timespec { tv_sec: libc::time_t::MAX, tv_nsec: 1_000_000_000 - 1 },
+        };
+
+        let r = libc::pthread_mutex_timedlock(self.inner.get(), &deadline);
+        debug_assert!(r == 0 || r == libc::ETIMEDOUT);
+        r == 0
+    }
+    #[cfg(not(target_os = "linux"))]
+    pub unsafe fn try_lock_for(&self, dur: Duration) -> bool {
+        crate::sys_common::mutex::try_lock_with_backoff(|| self.try_lock(), dur)
+    }
     #[inline]
     #[cfg(not(target_os = "dragonfly"))]
     pub unsafe fn destroy(&self) {
@@ -38,11 +38,23 @@ pub fn cvt_gai(err: c_int) -> io::Result<()> {
         str::from_utf8(CStr::from_ptr(libc::gai_strerror(err)).to_bytes()).unwrap().to_owned()
     };
     Err(io::Error::new(
-        io::ErrorKind::Other,
+        decode_gai_error_kind(err),
         &format!("failed to lookup address information: {}", detail)[..],
     ))
 }
 
+fn decode_gai_error_kind(err: c_int) -> io::ErrorKind {
+    match err {
+        libc::EAI_NONAME => io::ErrorKind::NotFound,
+        #[cfg(all(target_os = "linux", target_env = "gnu"))]
+        libc::EAI_NODATA => io::ErrorKind::NotFound,
+        libc::EAI_AGAIN => io::ErrorKind::TemporaryFailure,
+        libc::EAI_MEMORY => io::ErrorKind::OutOfMemory,
+        libc::EAI_BADFLAGS | libc::EAI_FAMILY | libc::EAI_SERVICE => io::ErrorKind::InvalidInput,
+        _ => io::ErrorKind::Other,
+    }
+}
+
 impl Socket {
     pub fn new(addr: &SocketAddr, ty: c_int) -> io::Result<Socket> {
         let fam = match *addr {
@@ -124,8 +136,8 @@ pub fn new_pair(_fam: c_int, _ty: c_int) -> io::Result<(Socket, Socket)> {
     pub fn connect_timeout(&self, addr: &SocketAddr, timeout: Duration) -> io::Result<()> {
         self.set_nonblocking(true)?;
         let r = unsafe {
-            let (addrp, len) = addr.into_inner();
-            cvt(libc::connect(self.0.raw(), addrp, len))
+            let (addr, len) = addr.into_inner();
+            cvt(libc::connect(self.0.raw(), addr.as_ptr(), len))
         };
         self.set_nonblocking(false)?;
 
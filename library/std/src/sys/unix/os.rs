@@ -454,6 +454,30 @@ pub fn current_exe() -> io::Result<PathBuf> {
     path.canonicalize()
 }
 
+/// Returns the path of the loaded module (the executable or a shared library)
+/// containing `addr`, as reported by the dynamic loader.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "fuchsia"
+))]
+pub fn module_path(addr: usize) -> Option<PathBuf> {
+    let mut info: libc::Dl_info = unsafe { mem::zeroed() };
+    if unsafe { libc::dladdr(addr as *const c_void, &mut info) } == 0 || info.dli_fname.is_null() {
+        return None;
+    }
+    let name = unsafe { CStr::from_ptr(info.dli_fname) };
+    Some(PathBuf::from(OsStr::from_bytes(name.to_bytes())))
+}
+
 pub struct Env {
     iter: vec::IntoIter<(OsString, OsString)>,
 }
@@ -1,7 +1,9 @@
+use crate::cmp;
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::mem;
 use crate::sys::fd::FileDesc;
 use crate::sys::{cvt, cvt_r};
+use crate::time::Instant;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Anonymous pipes
@@ -38,7 +40,21 @@ pub fn anon_pipe() -> io::Result<(AnonPipe, AnonPipe)> {
     }
 }
 
+/// Creates a pipe for use outside of process spawning. Both ends are
+/// close-on-exec, exactly like the ones `anon_pipe` hands to `Command`.
+pub fn pipe() -> io::Result<(AnonPipe, AnonPipe)> {
+    anon_pipe()
+}
+
 impl AnonPipe {
+    pub fn from_fd(fd: FileDesc) -> AnonPipe {
+        AnonPipe(fd)
+    }
+
+    pub fn try_clone(&self) -> io::Result<AnonPipe> {
+        self.0.duplicate().map(AnonPipe)
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
@@ -99,23 +115,82 @@ pub fn read2(p1: AnonPipe, v1: &mut Vec<u8>, p2: AnonPipe, v2: &mut Vec<u8>) ->
             return p1.read_to_end(v1).map(drop);
         }
     }
+}
+
+/// Like `read2`, but either pipe may be absent and reading gives up with a
+/// `TimedOut` error once `deadline` has passed.
+///
+/// Everything read before the deadline is left in `v1` and `v2`.
+pub fn read2_deadline(
+    p1: Option<AnonPipe>,
+    v1: &mut Vec<u8>,
+    p2: Option<AnonPipe>,
+    v2: &mut Vec<u8>,
+    deadline: Instant,
+) -> io::Result<()> {
+    let p1 = p1.map(AnonPipe::into_fd);
+    let p2 = p2.map(AnonPipe::into_fd);
+
+    // `poll` ignores entries with a negative fd, which is how pipes that are
+    // absent or have already reached EOF drop out of the loop below.
+    let mut fds: [libc::pollfd; 2] = unsafe { mem::zeroed() };
+    for (pollfd, fd) in fds.iter_mut().zip([&p1, &p2]) {
+        pollfd.fd = match fd {
+            Some(fd) => {
+                fd.set_nonblocking(true)?;
+                fd.raw()
+            }
+            None => -1,
+        };
+        pollfd.events = libc::POLLIN;
+    }
+
+    while fds[0].fd >= 0 || fds[1].fd >= 0 {
+        // Round the remaining time up to whole milliseconds so we never wake
+        // up just before the deadline and spin.
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let timeout = remaining
+            .as_secs()
+            .saturating_mul(1_000)
+            .saturating_add((remaining.subsec_nanos() as u64 + 999_999) / 1_000_000);
+        let timeout = cmp::min(timeout, libc::c_int::MAX as u64) as libc::c_int;
+
+        let ready = match cvt(unsafe { libc::poll(fds.as_mut_ptr(), 2, timeout) }) {
+            Ok(ready) => ready,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if ready == 0 {
+            return Err(io::Error::new_const(
+                io::ErrorKind::TimedOut,
+                &"timed out reading from child process",
+            ));
+        }
+
+        if fds[0].revents != 0 && read(p1.as_ref().unwrap(), v1)? {
+            fds[0].fd = -1;
+        }
+        if fds[1].revents != 0 && read(p2.as_ref().unwrap(), v2)? {
+            fds[1].fd = -1;
+        }
+    }
+    Ok(())
+}
 
-    // Read as much as we can from each pipe, ignoring EWOULDBLOCK or
-    // EAGAIN. If we hit EOF, then this will happen because the underlying
-    // reader will return Ok(0), in which case we'll see `Ok` ourselves. In
-    // this case we flip the other fd back into blocking mode and read
-    // whatever's leftover on that file descriptor.
-    fn read(fd: &FileDesc, dst: &mut Vec<u8>) -> Result<bool, io::Error> {
-        match fd.read_to_end(dst) {
-            Ok(_) => Ok(true),
-            Err(e) => {
-                if e.raw_os_error() == Some(libc::EWOULDBLOCK)
-                    || e.raw_os_error() == Some(libc::EAGAIN)
-                {
-                    Ok(false)
-                } else {
-                    Err(e)
-                }
+// Read as much as we can from each pipe, ignoring EWOULDBLOCK or
+// EAGAIN. If we hit EOF, then this will happen because the underlying
+// reader will return Ok(0), in which case we'll see `Ok` ourselves. In
+// this case the caller either flips the other fd back into blocking mode and
+// reads whatever's leftover on that file descriptor, or stops polling this one.
+fn read(fd: &FileDesc, dst: &mut Vec<u8>) -> Result<bool, io::Error> {
+    match fd.read_to_end(dst) {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            if e.raw_os_error() == Some(libc::EWOULDBLOCK) || e.raw_os_error() == Some(libc::EAGAIN)
+            {
+                Ok(false)
+            } else {
+                Err(e)
             }
         }
     }
@@ -6,7 +6,8 @@
 use crate::ptr;
 
 use crate::sys::process::process_common::*;
-use crate::sys::process::zircon::{zx_handle_t, Handle};
+use crate::sys::process::zircon::{zx_handle_t, zx_time_t, Handle};
+use crate::time::Duration;
 
 use libc::{c_int, size_t};
 
@@ -195,6 +196,17 @@ pub fn wait(&mut self) -> io::Result<ExitStatus> {
     }
 
     pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        self.wait_deadline(0)
+    }
+
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        use crate::sys::process::zircon::*;
+
+        let nanos = timeout.as_nanos().try_into().unwrap_or(zx_duration_t::MAX);
+        self.wait_deadline(unsafe { zx_deadline_after(nanos) })
+    }
+
+    fn wait_deadline(&mut self, deadline: zx_time_t) -> io::Result<Option<ExitStatus>> {
         use crate::default::Default;
         use crate::sys::process::zircon::*;
 
@@ -203,8 +215,12 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         let mut avail: size_t = 0;
 
         unsafe {
-            let status =
-                zx_object_wait_one(self.handle.raw(), ZX_TASK_TERMINATED, 0, ptr::null_mut());
+            let status = zx_object_wait_one(
+                self.handle.raw(),
+                ZX_TASK_TERMINATED,
+                deadline,
+                ptr::null_mut(),
+            );
             match status {
                 0 => {} // Success
                 x if x == ERR_TIMED_OUT => {
@@ -1,3 +1,4 @@
+use crate::cmp;
 use crate::convert::{TryFrom, TryInto};
 use crate::fmt;
 use crate::io::{self, Error, ErrorKind};
@@ -7,7 +8,18 @@
 use crate::ptr;
 use crate::sys;
 use crate::sys::cvt;
+#[cfg(any(
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly",
+))]
+use crate::sys::fd::FileDesc;
 use crate::sys::process::process_common::*;
+use crate::time::{Duration, Instant};
 
 #[cfg(target_os = "vxworks")]
 use libc::RTP_ID as pid_t;
@@ -478,6 +490,135 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
             Ok(Some(ExitStatus::new(status)))
         }
     }
+
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        if let Some(status) = self.try_wait()? {
+            return Ok(Some(status));
+        }
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.wait_deadline(deadline),
+            None => self.wait().map(Some),
+        }
+    }
+
+    // None of the ways to wait below touch the process-wide SIGCHLD
+    // disposition, which belongs to the application.
+
+    // The BSDs and Apple's platforms report the exit of a specific process
+    // through kqueue's `EVFILT_PROC` filter.
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly",
+    ))]
+    fn wait_deadline(&mut self, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+        // kqueues are not inherited by child processes, so there is no need
+        // for `FD_CLOEXEC`.
+        let kq = FileDesc::new(cvt(unsafe { libc::kqueue() })?);
+        let mut event: libc::kevent = unsafe { mem::zeroed() };
+        event.ident = self.pid as _;
+        event.filter = libc::EVFILT_PROC as _;
+        event.flags = libc::EV_ADD as _;
+        event.fflags = libc::NOTE_EXIT as _;
+        match cvt(unsafe { libc::kevent(kq.raw(), &event, 1, ptr::null_mut(), 0, ptr::null()) }) {
+            Ok(_) => {}
+            // The child has already exited, so this won't block.
+            Err(ref e) if e.raw_os_error() == Some(libc::ESRCH) => return self.wait().map(Some),
+            Err(e) => return Err(e),
+        }
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let timeout = libc::timespec {
+                tv_sec: cmp::min(remaining.as_secs(), libc::time_t::MAX as u64) as libc::time_t,
+                tv_nsec: remaining.subsec_nanos() as _,
+            };
+            match cvt(unsafe { libc::kevent(kq.raw(), ptr::null(), 0, &mut event, 1, &timeout) }) {
+                Ok(0) => return Ok(None),
+                // The child may not be reapable yet when `NOTE_EXIT` fires, so
+                // use a blocking wait rather than `try_wait`.
+                Ok(_) => return self.wait().map(Some),
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly",
+    )))]
+    fn wait_deadline(&mut self, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+        // On Linux a pidfd becomes readable once the process has exited, so we
+        // can sleep in `poll`.
+        #[cfg(target_os = "linux")]
+        if let Some(pidfd) = pidfd_open(self.pid)? {
+            let mut pollfd = libc::pollfd { fd: pidfd.raw(), events: libc::POLLIN, revents: 0 };
+            loop {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                let timeout = remaining
+                    .as_secs()
+                    .saturating_mul(1_000)
+                    .saturating_add((remaining.subsec_nanos() as u64 + 999_999) / 1_000_000);
+                let timeout = cmp::min(timeout, c_int::MAX as u64) as c_int;
+                match cvt(unsafe { libc::poll(&mut pollfd, 1, timeout) }) {
+                    Ok(0) => return Ok(None),
+                    Ok(_) => return self.try_wait(),
+                    Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        // Everywhere else (and on Linux before 5.3) there is no way to wait for
+        // one specific child with a timeout short of installing a SIGCHLD
+        // handler. Fall back to polling `waitpid` with a growing interval, as
+        // documented on `Child::wait_timeout`.
+        let mut interval = Duration::from_millis(1);
+        loop {
+            if let Some(status) = self.try_wait()? {
+                return Ok(Some(status));
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(None);
+            }
+            crate::thread::sleep(cmp::min(interval, deadline - now));
+            interval = cmp::min(interval * 2, Duration::from_millis(50));
+        }
+    }
+}
+
+/// Opens a pidfd for `pid`, or returns `None` if the kernel does not support
+/// them (they were added in Linux 5.3) or a seccomp filter forbids the call.
+#[cfg(target_os = "linux")]
+fn pidfd_open(pid: pid_t) -> io::Result<Option<FileDesc>> {
+    use crate::sync::atomic::{AtomicBool, Ordering};
+
+    static HAS_PIDFD: AtomicBool = AtomicBool::new(true);
+
+    syscall! {
+        fn pidfd_open(pid: pid_t, flags: libc::c_uint) -> c_int
+    }
+
+    if !HAS_PIDFD.load(Ordering::Relaxed) {
+        return Ok(None);
+    }
+    // The returned descriptor always has `FD_CLOEXEC` set.
+    match cvt(unsafe { pidfd_open(pid, 0) }) {
+        Ok(fd) => Ok(Some(FileDesc::new(fd))),
+        Err(e) if matches!(e.raw_os_error(), Some(libc::ENOSYS) | Some(libc::EPERM)) => {
+            HAS_PIDFD.store(false, Ordering::Relaxed);
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
 }
 
 /// Unix exit statuses
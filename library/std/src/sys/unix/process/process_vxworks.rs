@@ -1,3 +1,4 @@
+use crate::cmp;
 use crate::convert::{TryFrom, TryInto};
 use crate::fmt;
 use crate::io::{self, Error, ErrorKind};
@@ -7,6 +8,7 @@
 use crate::sys::cvt;
 use crate::sys::process::process_common::*;
 use crate::sys_common::thread;
+use crate::time::{Duration, Instant};
 use libc::RTP_ID;
 use libc::{self, c_char, c_int};
 
@@ -175,6 +177,27 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
             Ok(Some(ExitStatus::new(status)))
         }
     }
+
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        let deadline = match Instant::now().checked_add(timeout) {
+            Some(deadline) => deadline,
+            None => return self.wait().map(Some),
+        };
+        // There is no way to wait for an RTP with a timeout, so poll `waitpid`
+        // with a growing interval instead.
+        let mut interval = Duration::from_millis(1);
+        loop {
+            if let Some(status) = self.try_wait()? {
+                return Ok(Some(status));
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(None);
+            }
+            crate::thread::sleep(cmp::min(interval, deadline - now));
+            interval = cmp::min(interval * 2, Duration::from_millis(50));
+        }
+    }
 }
 
 /// Unix exit statuses
@@ -4,7 +4,8 @@
 use crate::mem;
 use crate::num::NonZeroUsize;
 use crate::ptr;
-use crate::sys::{os, stack_overflow};
+use crate::sys::{cvt, os, stack_overflow};
+use crate::thread::Priority;
 use crate::time::Duration;
 
 #[cfg(not(any(target_os = "l4re", target_os = "vxworks")))]
@@ -148,6 +149,109 @@ pub fn set_name(_name: &CStr) {
         // FIXME: determine whether Fuchsia has a way to set a thread name.
     }
 
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn set_affinity(cpus: &[usize]) -> io::Result<()> {
+        unsafe {
+            let mut set: libc::cpu_set_t = mem::zeroed();
+            for &cpu in cpus {
+                if cpu >= mem::size_of::<libc::cpu_set_t>() * 8 {
+                    return Err(io::Error::new_const(
+                        io::ErrorKind::InvalidInput,
+                        &"CPU index is out of range for a cpu_set_t",
+                    ));
+                }
+                libc::CPU_SET(cpu, &mut set);
+            }
+            // A pid of 0 refers to the calling thread.
+            cvt(libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set))?;
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        Err(io::Error::new_const(
+            io::ErrorKind::Unsupported,
+            &"Setting the thread affinity is not supported on the target platform",
+        ))
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn set_priority(priority: Priority) -> io::Result<()> {
+        // Threads under the default `SCHED_OTHER` policy all share the same
+        // static priority on Linux and are weighted by their nice value
+        // instead, which (unlike POSIX specifies) is a per-thread attribute.
+        //
+        // The levels are relative to the nice value inherited from the
+        // spawning thread, as lowering it needs `CAP_SYS_NICE`: `Normal`
+        // leaves it alone, so it works in an already niced process.
+        let offset = match priority {
+            Priority::Lowest => 39,
+            Priority::BelowNormal => 10,
+            Priority::Normal => return Ok(()),
+            Priority::AboveNormal => -5,
+            Priority::Highest => -10,
+        };
+        unsafe {
+            let tid = libc::syscall(libc::SYS_gettid) as libc::id_t;
+            // Unlike the libc wrapper, whose result of -1 is ambiguous, the
+            // system call returns `20 - nice`, in the range 1..=40.
+            let inherited = 20 - cvt(libc::syscall(
+                libc::SYS_getpriority,
+                libc::PRIO_PROCESS as libc::c_int,
+                tid,
+            ))? as libc::c_int;
+            let nice = cmp::max(-20, cmp::min(19, inherited + offset));
+            cvt(libc::setpriority(libc::PRIO_PROCESS as _, tid, nice))?;
+        }
+        Ok(())
+    }
+
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "dragonfly"
+    ))]
+    pub fn set_priority(priority: Priority) -> io::Result<()> {
+        unsafe {
+            let policy = libc::SCHED_OTHER;
+            let min = cvt(libc::sched_get_priority_min(policy))?;
+            let max = cvt(libc::sched_get_priority_max(policy))?;
+            // Spread the priority classes evenly over the range of the
+            // policy, with `Normal` in the middle.
+            let step = (max - min) / 4;
+            let priority = match priority {
+                Priority::Lowest => min,
+                Priority::BelowNormal => min + step,
+                Priority::Normal => min + 2 * step,
+                Priority::AboveNormal => max - step,
+                Priority::Highest => max,
+            };
+            let mut param: libc::sched_param = mem::zeroed();
+            param.sched_priority = priority;
+            match libc::pthread_setschedparam(libc::pthread_self(), policy, &param) {
+                0 => Ok(()),
+                err => Err(io::Error::from_raw_os_error(err)),
+            }
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "dragonfly"
+    )))]
+    pub fn set_priority(_priority: Priority) -> io::Result<()> {
+        Err(io::Error::new_const(
+            io::ErrorKind::Unsupported,
+            &"Setting the thread priority is not supported on the target platform",
+        ))
+    }
+
     pub fn sleep(dur: Duration) {
         let mut secs = dur.as_secs();
         let mut nsecs = dur.subsec_nanos() as _;
@@ -279,6 +279,14 @@ pub fn try_exists(_path: &Path) -> io::Result<bool> {
     unsupported()
 }
 
+pub fn create_temp(_dir: &Path) -> io::Result<File> {
+    unsupported()
+}
+
+pub fn create_temp_dir(_dir: &Path) -> io::Result<PathBuf> {
+    unsupported()
+}
+
 pub fn readlink(_p: &Path) -> io::Result<PathBuf> {
     unsupported()
 }
@@ -1,4 +1,5 @@
 use crate::cell::Cell;
+use crate::time::Duration;
 
 pub struct Mutex {
     // This platform has no threads, so we can use a Cell here.
@@ -33,6 +34,12 @@ pub unsafe fn try_lock(&self) -> bool {
         self.locked.replace(true) == false
     }
 
+    #[inline]
+    pub unsafe fn try_lock_for(&self, _dur: Duration) -> bool {
+        // Without other threads, nothing could unlock the mutex in the meantime.
+        self.try_lock()
+    }
+
     #[inline]
     pub unsafe fn destroy(&self) {}
 }
@@ -1,7 +1,7 @@
 use crate::convert::TryFrom;
 use crate::fmt;
 use crate::io::{self, IoSlice, IoSliceMut};
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, LookupOptions, Shutdown, SocketAddr};
 use crate::sys::unsupported;
 use crate::time::Duration;
 
@@ -297,6 +297,14 @@ impl LookupHost {
     pub fn port(&self) -> u16 {
         self.0
     }
+
+    pub fn with_options(_host: &str, _port: u16, _opts: &LookupOptions) -> io::Result<LookupHost> {
+        unsupported()
+    }
+
+    pub fn canonical_name(&self) -> Option<&str> {
+        self.0
+    }
 }
 
 impl Iterator for LookupHost {
@@ -322,6 +330,10 @@ fn try_from(_v: (&'a str, u16)) -> io::Result<LookupHost> {
     }
 }
 
+pub fn lookup_addr(_addr: &IpAddr) -> io::Result<String> {
+    unsupported()
+}
+
 #[allow(nonstandard_style)]
 pub mod netc {
     pub const AF_INET: u8 = 0;
@@ -2,6 +2,7 @@
 use crate::ffi::CStr;
 use crate::io;
 use crate::num::NonZeroUsize;
+use crate::thread::Priority;
 use crate::time::Duration;
 
 pub struct Thread(!);
@@ -22,6 +23,14 @@ pub fn set_name(_name: &CStr) {
         // nope
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_priority(_priority: Priority) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn sleep(_dur: Duration) {
         panic!("can't sleep");
     }
@@ -14,7 +14,7 @@
 use crate::sys::unsupported;
 use crate::sys_common::FromInner;
 
-pub use crate::sys_common::fs::{remove_dir_all, try_exists};
+pub use crate::sys_common::fs::{create_temp, create_temp_dir, remove_dir_all, try_exists};
 
 pub struct File {
     fd: WasiFd,
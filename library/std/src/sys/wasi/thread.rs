@@ -5,6 +5,7 @@
 use crate::mem;
 use crate::num::NonZeroUsize;
 use crate::sys::unsupported;
+use crate::thread::Priority;
 use crate::time::Duration;
 
 pub struct Thread(!);
@@ -26,6 +27,14 @@ pub fn set_name(_name: &CStr) {
         // nope
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_priority(_priority: Priority) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn sleep(dur: Duration) {
         let nanos = dur.as_nanos();
         assert!(nanos <= u64::MAX as u128);
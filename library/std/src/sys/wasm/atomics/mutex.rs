@@ -1,8 +1,10 @@
 use crate::arch::wasm32;
 use crate::cell::UnsafeCell;
+use crate::convert::TryInto;
 use crate::mem;
 use crate::sync::atomic::{AtomicU32, AtomicUsize, Ordering::SeqCst};
 use crate::sys::thread;
+use crate::time::{Duration, Instant};
 
 pub struct Mutex {
     locked: AtomicUsize,
@@ -53,6 +55,28 @@ pub unsafe fn try_lock(&self) -> bool {
         self.locked.compare_exchange(0, 1, SeqCst, SeqCst).is_ok()
     }
 
+    pub unsafe fn try_lock_for(&self, dur: Duration) -> bool {
+        // A deadline too far in the future to represent is never reached.
+        let deadline = Instant::now().checked_add(dur);
+        while !self.try_lock() {
+            let timeout = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) if remaining > Duration::ZERO => {
+                        remaining.as_nanos().try_into().unwrap_or(i64::MAX)
+                    }
+                    _ => return false,
+                },
+                None => -1,
+            };
+            // SAFETY: the caller must uphold the safety contract for `memory_atomic_wait32`.
+            let val = unsafe { wasm32::memory_atomic_wait32(self.ptr(), 1, timeout) };
+            // we either woke up (0), got a not-equal due to a race (1) or timed
+            // out (2), in all of which cases the lock is worth another try.
+            debug_assert!(val == 0 || val == 1 || val == 2);
+        }
+        true
+    }
+
     #[inline]
     pub unsafe fn destroy(&self) {
         // nothing to do
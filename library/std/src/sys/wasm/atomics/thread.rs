@@ -3,6 +3,7 @@
 use crate::io;
 use crate::num::NonZeroUsize;
 use crate::sys::unsupported;
+use crate::thread::Priority;
 use crate::time::Duration;
 
 pub struct Thread(!);
@@ -19,6 +20,14 @@ pub fn yield_now() {}
 
     pub fn set_name(_name: &CStr) {}
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_priority(_priority: Priority) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn sleep(dur: Duration) {
         use crate::arch::wasm32;
         use crate::cmp;
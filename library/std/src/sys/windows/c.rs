@@ -74,6 +74,10 @@
 pub const FILE_ATTRIBUTE_DIRECTORY: DWORD = 0x10;
 pub const FILE_ATTRIBUTE_REPARSE_POINT: DWORD = 0x400;
 
+pub const FILE_TYPE_PIPE: DWORD = 3;
+
+pub const MAX_PATH: usize = 260;
+
 pub const FILE_SHARE_DELETE: DWORD = 0x4;
 pub const FILE_SHARE_READ: DWORD = 0x1;
 pub const FILE_SHARE_WRITE: DWORD = 0x2;
@@ -102,6 +106,8 @@
 
 pub const FILE_FLAG_OPEN_REPARSE_POINT: DWORD = 0x00200000;
 pub const FILE_FLAG_BACKUP_SEMANTICS: DWORD = 0x02000000;
+pub const FILE_FLAG_DELETE_ON_CLOSE: DWORD = 0x04000000;
+pub const FILE_ATTRIBUTE_TEMPORARY: DWORD = 0x100;
 pub const SECURITY_SQOS_PRESENT: DWORD = 0x00100000;
 
 pub const FIONBIO: c_ulong = 0x8004667e;
@@ -146,6 +152,9 @@ fn clone(&self) -> Self {
 pub const WSAESHUTDOWN: c_int = 10058;
 pub const WSAETIMEDOUT: c_int = 10060;
 pub const WSAECONNREFUSED: c_int = 10061;
+pub const WSAHOST_NOT_FOUND: c_int = 11001;
+pub const WSATRY_AGAIN: c_int = 11002;
+pub const WSANO_DATA: c_int = 11004;
 
 pub const MAX_PROTOCOL_CHAIN: DWORD = 7;
 
@@ -229,8 +238,15 @@ fn clone(&self) -> Self {
 pub const CREATE_UNICODE_ENVIRONMENT: DWORD = 0x00000400;
 pub const STARTF_USESTDHANDLES: DWORD = 0x00000100;
 
+pub const AF_UNIX: c_int = 1;
 pub const AF_INET: c_int = 2;
 pub const AF_INET6: c_int = 23;
+pub const AF_UNSPEC: c_int = 0;
+pub const AI_CANONNAME: c_int = 0x2;
+pub const AI_NUMERICHOST: c_int = 0x4;
+pub const AI_ADDRCONFIG: c_int = 0x400;
+pub const NI_NAMEREQD: c_int = 0x4;
+pub const NI_MAXHOST: usize = 1025;
 pub const SD_BOTH: c_int = 2;
 pub const SD_RECEIVE: c_int = 0;
 pub const SD_SEND: c_int = 1;
@@ -293,6 +309,12 @@ pub struct ipv6_mreq {
 
 pub const STACK_SIZE_PARAM_IS_A_RESERVATION: DWORD = 0x00010000;
 
+pub const THREAD_PRIORITY_LOWEST: c_int = -2;
+pub const THREAD_PRIORITY_BELOW_NORMAL: c_int = -1;
+pub const THREAD_PRIORITY_NORMAL: c_int = 0;
+pub const THREAD_PRIORITY_ABOVE_NORMAL: c_int = 1;
+pub const THREAD_PRIORITY_HIGHEST: c_int = 2;
+
 pub const STATUS_SUCCESS: NTSTATUS = 0x00000000;
 
 #[repr(C)]
@@ -607,6 +629,15 @@ pub struct sockaddr_in6 {
     pub sin6_scope_id: c_ulong,
 }
 
+pub const UNIX_PATH_MAX: usize = 108;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sockaddr_un {
+    pub sun_family: ADDRESS_FAMILY,
+    pub sun_path: [CHAR; UNIX_PATH_MAX],
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct in_addr {
@@ -707,6 +738,9 @@ pub struct BY_HANDLE_FILE_INFORMATION {
 
     pub const HANDLE_FLAG_INHERIT: DWORD = 0x00000001;
 
+    pub const GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT: DWORD = 0x00000002;
+    pub const GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS: DWORD = 0x00000004;
+
     pub const TOKEN_READ: DWORD = 0x20008;
 
     #[link(name = "advapi32")]
@@ -769,6 +803,11 @@ pub fn CreateHardLinkW(
             lpSecurityAttributes: LPSECURITY_ATTRIBUTES,
         ) -> BOOL;
         pub fn SetThreadStackGuarantee(_size: *mut c_ulong) -> BOOL;
+        pub fn GetModuleHandleExW(
+            dwFlags: DWORD,
+            lpModuleName: LPCWSTR,
+            phModule: *mut HMODULE,
+        ) -> BOOL;
     }
 }
 }
@@ -796,15 +835,6 @@ pub fn BCryptGenRandom(
             dwFlags: ULONG,
         ) -> LONG;
     }
-    #[link(name = "kernel32")]
-    extern "system" {
-        pub fn GetFileInformationByHandleEx(
-            hFile: HANDLE,
-            fileInfoClass: FILE_INFO_BY_HANDLE_CLASS,
-            lpFileInformation: LPVOID,
-            dwBufferSize: DWORD,
-        ) -> BOOL;
-    }
 }
 }
 
@@ -826,6 +856,8 @@ pub fn GetFileInformationByHandleEx(
     pub fn GetTempPathW(nBufferLength: DWORD, lpBuffer: LPCWSTR) -> DWORD;
     pub fn GetCurrentProcess() -> HANDLE;
     pub fn GetCurrentThread() -> HANDLE;
+    pub fn SetThreadAffinityMask(hThread: HANDLE, dwThreadAffinityMask: DWORD_PTR) -> DWORD_PTR;
+    pub fn SetThreadPriority(hThread: HANDLE, nPriority: c_int) -> BOOL;
     pub fn GetStdHandle(which: DWORD) -> HANDLE;
     pub fn ExitProcess(uExitCode: c_uint) -> !;
     pub fn DeviceIoControl(
@@ -965,6 +997,12 @@ pub fn WaitForMultipleObjects(
         bWaitAll: BOOL,
         dwMilliseconds: DWORD,
     ) -> DWORD;
+    pub fn CreatePipe(
+        hReadPipe: *mut HANDLE,
+        hWritePipe: *mut HANDLE,
+        lpPipeAttributes: LPSECURITY_ATTRIBUTES,
+        nSize: DWORD,
+    ) -> BOOL;
     pub fn CreateNamedPipeW(
         lpName: LPCWSTR,
         dwOpenMode: DWORD,
@@ -993,6 +1031,13 @@ pub fn GetFinalPathNameByHandleW(
         cchFilePath: DWORD,
         dwFlags: DWORD,
     ) -> DWORD;
+    pub fn GetFileInformationByHandleEx(
+        hFile: HANDLE,
+        fileInfoClass: FILE_INFO_BY_HANDLE_CLASS,
+        lpFileInformation: LPVOID,
+        dwBufferSize: DWORD,
+    ) -> BOOL;
+    pub fn GetFileType(hFile: HANDLE) -> DWORD;
     pub fn SetFileInformationByHandle(
         hFile: HANDLE,
         FileInformationClass: FILE_INFO_BY_HANDLE_CLASS,
@@ -1101,6 +1146,15 @@ pub fn getaddrinfo(
         res: *mut *mut ADDRINFOA,
     ) -> c_int;
     pub fn freeaddrinfo(res: *mut ADDRINFOA);
+    pub fn getnameinfo(
+        sa: *const SOCKADDR,
+        salen: socklen_t,
+        host: *mut c_char,
+        hostlen: DWORD,
+        serv: *mut c_char,
+        servlen: DWORD,
+        flags: c_int,
+    ) -> c_int;
     pub fn select(
         nfds: c_int,
         readfds: *mut fd_set,
@@ -15,6 +15,8 @@
 
 use super::to_u16s;
 
+pub use crate::sys_common::fs::create_temp_dir;
+
 pub struct File {
     handle: Handle,
 }
@@ -722,6 +724,17 @@ pub fn rmdir(p: &Path) -> io::Result<()> {
     Ok(())
 }
 
+pub fn create_temp(dir: &Path) -> io::Result<File> {
+    let mut opts = OpenOptions::new();
+    opts.read(true);
+    opts.write(true);
+    opts.create_new(true);
+    // The file is deleted once its last handle is closed, even if the process is killed.
+    opts.custom_flags(c::FILE_FLAG_DELETE_ON_CLOSE);
+    opts.attributes(c::FILE_ATTRIBUTE_TEMPORARY);
+    crate::sys_common::fs::create_temp_with(dir, |path| File::open(path, &opts))
+}
+
 pub fn remove_dir_all(path: &Path) -> io::Result<()> {
     let filetype = lstat(path)?.file_type();
     if filetype.is_symlink() {
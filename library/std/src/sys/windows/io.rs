@@ -1,4 +1,9 @@
 use crate::marker::PhantomData;
+#[cfg(not(target_vendor = "uwp"))]
+use crate::mem::size_of;
+use crate::os::windows::io::AsRawHandle;
+#[cfg(not(target_vendor = "uwp"))]
+use crate::os::windows::io::RawHandle;
 use crate::slice;
 use crate::sys::c;
 
@@ -78,3 +83,75 @@ pub fn as_mut_slice(&mut self) -> &mut [u8] {
         unsafe { slice::from_raw_parts_mut(self.vec.buf as *mut u8, self.vec.len as usize) }
     }
 }
+
+#[cfg(not(target_vendor = "uwp"))]
+pub fn is_terminal(h: &impl AsRawHandle) -> bool {
+    unsafe { handle_is_console(h.as_raw_handle()) }
+}
+
+// UWP apps have no console, and the console API isn't available to them.
+#[cfg(target_vendor = "uwp")]
+pub fn is_terminal(_h: &impl AsRawHandle) -> bool {
+    false
+}
+
+#[cfg(not(target_vendor = "uwp"))]
+unsafe fn handle_is_console(handle: RawHandle) -> bool {
+    let handle = handle as c::HANDLE;
+    // A null handle means the process has no console.
+    if handle.is_null() {
+        return false;
+    }
+
+    let mut out = 0;
+    if c::GetConsoleMode(handle, &mut out) != 0 {
+        // False positives aren't possible. If we got a console then we
+        // definitely have a console.
+        return true;
+    }
+
+    // Otherwise, we fall back to an msys hack to see if we can detect the
+    // presence of a pty.
+    msys_tty_on(handle)
+}
+
+/// Returns true if there is an MSYS/cygwin tty on the given handle.
+///
+/// MSYS terminals such as mintty are not Windows consoles. Instead they
+/// connect programs to named pipes with distinctive names, which is what we
+/// look for here.
+#[cfg(not(target_vendor = "uwp"))]
+unsafe fn msys_tty_on(handle: c::HANDLE) -> bool {
+    // Early return if the handle is not a pipe.
+    if c::GetFileType(handle) != c::FILE_TYPE_PIPE {
+        return false;
+    }
+
+    #[repr(C)]
+    #[allow(non_snake_case)]
+    struct FILE_NAME_INFO {
+        FileNameLength: u32,
+        FileName: [u16; c::MAX_PATH],
+    }
+    let mut name_info = FILE_NAME_INFO { FileNameLength: 0, FileName: [0; c::MAX_PATH] };
+    let res = c::GetFileInformationByHandleEx(
+        handle,
+        c::FileNameInfo,
+        &mut name_info as *mut _ as c::LPVOID,
+        size_of::<FILE_NAME_INFO>() as c::DWORD,
+    );
+    if res == 0 {
+        return false;
+    }
+
+    // The length is in bytes and the name is not nul-terminated.
+    let len = crate::cmp::min(name_info.FileNameLength as usize / 2, c::MAX_PATH);
+    let name = String::from_utf16_lossy(&name_info.FileName[..len]);
+    // This checks whether 'pty' exists in the file name, which indicates that
+    // a pseudo-terminal is attached. To mitigate against false positives
+    // (e.g., an actual file name that contains 'pty'), we also require that
+    // the file name begins with either the strings 'msys-' or 'cygwin-'.
+    let is_msys = name.starts_with("\\msys-") || name.starts_with("\\cygwin-");
+    let is_pty = name.contains("-pty");
+    is_msys && is_pty
+}
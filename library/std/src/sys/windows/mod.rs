@@ -102,6 +102,8 @@ pub fn decode_error_kind(errno: i32) -> ErrorKind {
         c::WSAENOTCONN => ErrorKind::NotConnected,
         c::WSAEWOULDBLOCK => ErrorKind::WouldBlock,
         c::WSAETIMEDOUT => ErrorKind::TimedOut,
+        c::WSAHOST_NOT_FOUND | c::WSANO_DATA => ErrorKind::NotFound,
+        c::WSATRY_AGAIN => ErrorKind::TemporaryFailure,
 
         _ => ErrorKind::Other,
     }
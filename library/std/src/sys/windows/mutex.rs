@@ -17,6 +17,8 @@
 use crate::cell::UnsafeCell;
 use crate::mem::MaybeUninit;
 use crate::sys::c;
+use crate::sys_common::mutex::try_lock_with_backoff;
+use crate::time::Duration;
 
 pub struct Mutex {
     srwlock: UnsafeCell<c::SRWLOCK>,
@@ -50,6 +52,11 @@ pub unsafe fn try_lock(&self) -> bool {
         c::TryAcquireSRWLockExclusive(raw(self)) != 0
     }
 
+    #[inline]
+    pub unsafe fn try_lock_for(&self, dur: Duration) -> bool {
+        try_lock_with_backoff(|| self.try_lock(), dur)
+    }
+
     #[inline]
     pub unsafe fn unlock(&self) {
         c::ReleaseSRWLockExclusive(raw(self));
@@ -97,6 +97,10 @@ pub fn new(addr: &SocketAddr, ty: c_int) -> io::Result<Socket> {
             SocketAddr::V4(..) => c::AF_INET,
             SocketAddr::V6(..) => c::AF_INET6,
         };
+        Socket::new_raw(family, ty)
+    }
+
+    pub fn new_raw(family: c_int, ty: c_int) -> io::Result<Socket> {
         let socket = unsafe {
             c::WSASocketW(
                 family,
@@ -133,8 +137,8 @@ pub fn new(addr: &SocketAddr, ty: c_int) -> io::Result<Socket> {
     pub fn connect_timeout(&self, addr: &SocketAddr, timeout: Duration) -> io::Result<()> {
         self.set_nonblocking(true)?;
         let result = {
-            let (addrp, len) = addr.into_inner();
-            let result = unsafe { c::connect(self.0, addrp, len) };
+            let (addr, len) = addr.into_inner();
+            let result = unsafe { c::connect(self.0, addr.as_ptr(), len) };
             cvt(result).map(drop)
         };
         self.set_nonblocking(false)?;
@@ -356,6 +360,12 @@ pub fn peek_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
         self.recv_from_with_flags(buf, c::MSG_PEEK)
     }
 
+    pub fn write(&self, buf: &[u8]) -> io::Result<usize> {
+        let length = cmp::min(buf.len(), <wrlen_t>::MAX as usize) as wrlen_t;
+        let result = unsafe { c::send(self.0, buf.as_ptr() as *const _, length, 0) };
+        cvt(result).map(|n| n as usize)
+    }
+
     pub fn write_vectored(&self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
         let length = cmp::min(bufs.len(), c::DWORD::MAX as usize) as c::DWORD;
         let mut nwritten = 0;
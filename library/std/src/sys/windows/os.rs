@@ -241,6 +241,23 @@ pub fn current_exe() -> io::Result<PathBuf> {
     )
 }
 
+/// Returns the path of the loaded module (the executable or a DLL) containing
+/// `addr`.
+#[cfg(not(target_vendor = "uwp"))]
+pub fn module_path(addr: usize) -> Option<PathBuf> {
+    let mut module = ptr::null_mut();
+    let flags =
+        c::GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS | c::GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT;
+    if unsafe { c::GetModuleHandleExW(flags, addr as c::LPCWSTR, &mut module) } == 0 {
+        return None;
+    }
+    super::fill_utf16_buf(
+        |buf, sz| unsafe { c::GetModuleFileNameW(module, buf, sz) },
+        super::os2path,
+    )
+    .ok()
+}
+
 pub fn getcwd() -> io::Result<PathBuf> {
     super::fill_utf16_buf(|buf, sz| unsafe { c::GetCurrentDirectoryW(sz, buf) }, super::os2path)
 }
@@ -9,9 +9,11 @@
 use crate::sync::atomic::AtomicUsize;
 use crate::sync::atomic::Ordering::SeqCst;
 use crate::sys::c;
+use crate::sys::cvt;
 use crate::sys::fs::{File, OpenOptions};
 use crate::sys::handle::Handle;
 use crate::sys::hashmap_random_keys;
+use crate::time::Instant;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Anonymous pipes
@@ -155,6 +157,18 @@ pub fn anon_pipe(ours_readable: bool, their_handle_inheritable: bool) -> io::Res
     }
 }
 
+/// Creates a pipe whose ends are both synchronous and not inheritable.
+///
+/// Unlike `anon_pipe`, this is meant for pipes handed out to user code, which
+/// may pass either end to a child process or read it from any thread. Handles
+/// are made inheritable only when they are actually passed to a child.
+pub fn pipe() -> io::Result<(AnonPipe, AnonPipe)> {
+    let mut read = ptr::null_mut();
+    let mut write = ptr::null_mut();
+    cvt(unsafe { c::CreatePipe(&mut read, &mut write, ptr::null_mut(), 0) })?;
+    Ok((AnonPipe { inner: Handle::new(read) }, AnonPipe { inner: Handle::new(write) }))
+}
+
 fn random_number() -> usize {
     static N: AtomicUsize = AtomicUsize::new(0);
     loop {
@@ -167,6 +181,9 @@ fn random_number() -> usize {
 }
 
 impl AnonPipe {
+    pub fn from_handle(handle: Handle) -> AnonPipe {
+        AnonPipe { inner: handle }
+    }
     pub fn handle(&self) -> &Handle {
         &self.inner
     }
@@ -174,6 +191,11 @@ pub fn into_handle(self) -> Handle {
         self.inner
     }
 
+    pub fn try_clone(&self) -> io::Result<AnonPipe> {
+        let inner = self.inner.duplicate(0, false, c::DUPLICATE_SAME_ACCESS)?;
+        Ok(AnonPipe { inner })
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.inner.read(buf)
     }
@@ -233,6 +255,60 @@ pub fn read2(p1: AnonPipe, v1: &mut Vec<u8>, p2: AnonPipe, v2: &mut Vec<u8>) ->
     }
 }
 
+/// Like `read2`, but either pipe may be absent and reading gives up with a
+/// `TimedOut` error once `deadline` has passed.
+///
+/// Everything read before the deadline is left in `v1` and `v2`.
+pub fn read2_deadline(
+    p1: Option<AnonPipe>,
+    v1: &mut Vec<u8>,
+    p2: Option<AnonPipe>,
+    v2: &mut Vec<u8>,
+    deadline: Instant,
+) -> io::Result<()> {
+    let p1 = p1.map(|p| AsyncPipe::new(p.into_handle(), v1)).transpose()?;
+    let p2 = p2.map(|p| AsyncPipe::new(p.into_handle(), v2)).transpose()?;
+    let mut pipes = [p1, p2];
+
+    // Same loop as in `read2`, except that pipes drop out of the wait set once
+    // they reach EOF instead of being finished off with a blocking read. Any
+    // read still pending when we give up is cancelled by `AsyncPipe`'s
+    // destructor.
+    loop {
+        let mut objs = [ptr::null_mut(); 2];
+        let mut idxs = [0; 2];
+        let mut n = 0;
+        for (idx, pipe) in pipes.iter().enumerate() {
+            if let Some(pipe) = pipe {
+                objs[n] = pipe.event.raw();
+                idxs[n] = idx;
+                n += 1;
+            }
+        }
+        if n == 0 {
+            return Ok(());
+        }
+
+        let timeout = super::dur2timeout(deadline.saturating_duration_since(Instant::now()));
+        let res =
+            unsafe { c::WaitForMultipleObjects(n as c::DWORD, objs.as_ptr(), c::FALSE, timeout) };
+        if res == c::WAIT_TIMEOUT {
+            return Err(io::Error::new_const(
+                io::ErrorKind::TimedOut,
+                &"timed out reading from child process",
+            ));
+        }
+        let i = res.wrapping_sub(c::WAIT_OBJECT_0) as usize;
+        if i >= n {
+            return Err(io::Error::last_os_error());
+        }
+        let pipe = pipes[idxs[i]].as_mut().unwrap();
+        if !pipe.result()? || !pipe.schedule_read()? {
+            pipes[idxs[i]] = None;
+        }
+    }
+}
+
 struct AsyncPipe<'a> {
     pipe: Handle,
     event: Handle,
@@ -27,6 +27,7 @@
 use crate::sys_common::mutex::StaticMutex;
 use crate::sys_common::process::{CommandEnv, CommandEnvs};
 use crate::sys_common::AsInner;
+use crate::time::Duration;
 
 use libc::{c_void, EXIT_FAILURE, EXIT_SUCCESS};
 
@@ -366,6 +367,23 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         }
     }
 
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        // `dur2timeout` saturates to `INFINITE`, which is what we want for
+        // timeouts too large to represent.
+        unsafe {
+            match c::WaitForSingleObject(self.handle.raw(), super::dur2timeout(timeout)) {
+                c::WAIT_OBJECT_0 => {}
+                c::WAIT_TIMEOUT => {
+                    return Ok(None);
+                }
+                _ => return Err(io::Error::last_os_error()),
+            }
+            let mut status = 0;
+            cvt(c::GetExitCodeProcess(self.handle.raw(), &mut status))?;
+            Ok(Some(ExitStatus(status)))
+        }
+    }
+
     pub fn handle(&self) -> &Handle {
         &self.handle
     }
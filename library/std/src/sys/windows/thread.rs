@@ -3,8 +3,10 @@
 use crate::num::NonZeroUsize;
 use crate::ptr;
 use crate::sys::c;
+use crate::sys::cvt;
 use crate::sys::handle::Handle;
 use crate::sys::stack_overflow;
+use crate::thread::Priority;
 use crate::time::Duration;
 
 use libc::c_void;
@@ -70,6 +72,39 @@ pub fn set_name(name: &CStr) {
         };
     }
 
+    pub fn set_affinity(cpus: &[usize]) -> io::Result<()> {
+        // Only the processor group the thread currently runs in can be
+        // addressed through an affinity mask.
+        let mut mask: c::DWORD_PTR = 0;
+        for &cpu in cpus {
+            if cpu >= c::DWORD_PTR::BITS as usize {
+                return Err(io::Error::new_const(
+                    io::ErrorKind::InvalidInput,
+                    &"CPU index is out of range for the thread's processor group",
+                ));
+            }
+            mask |= 1 << cpu;
+        }
+        unsafe {
+            cvt(c::SetThreadAffinityMask(c::GetCurrentThread(), mask))?;
+        }
+        Ok(())
+    }
+
+    pub fn set_priority(priority: Priority) -> io::Result<()> {
+        let priority = match priority {
+            Priority::Lowest => c::THREAD_PRIORITY_LOWEST,
+            Priority::BelowNormal => c::THREAD_PRIORITY_BELOW_NORMAL,
+            Priority::Normal => c::THREAD_PRIORITY_NORMAL,
+            Priority::AboveNormal => c::THREAD_PRIORITY_ABOVE_NORMAL,
+            Priority::Highest => c::THREAD_PRIORITY_HIGHEST,
+        };
+        unsafe {
+            cvt(c::SetThreadPriority(c::GetCurrentThread(), priority))?;
+        }
+        Ok(())
+    }
+
     pub fn join(self) {
         let rc = unsafe { c::WaitForSingleObject(self.handle.raw(), c::INFINITE) };
         if rc == c::WAIT_FAILED {
@@ -191,6 +191,44 @@ pub fn rust_backtrace_env() -> RustBacktrace {
     format
 }
 
+/// Returns the path of the module containing `addr`, on the platforms where
+/// this can be looked up without resolving any symbols.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "fuchsia",
+    all(windows, not(target_vendor = "uwp"))
+))]
+pub fn module_path(addr: usize) -> Option<PathBuf> {
+    crate::sys::os::module_path(addr)
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "fuchsia",
+    all(windows, not(target_vendor = "uwp"))
+)))]
+pub fn module_path(_addr: usize) -> Option<PathBuf> {
+    None
+}
+
 /// Prints the filename of the backtrace frame.
 ///
 /// See also `output`.
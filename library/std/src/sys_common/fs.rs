@@ -2,7 +2,8 @@
 
 use crate::fs;
 use crate::io::{self, Error, ErrorKind};
-use crate::path::Path;
+use crate::path::{Path, PathBuf};
+use crate::sys::fs as fs_imp;
 
 pub(crate) const NOT_FILE_ERROR: Error = Error::new_const(
     ErrorKind::InvalidInput,
@@ -49,3 +50,42 @@ pub fn try_exists(path: &Path) -> io::Result<bool> {
         Err(error) => Err(error),
     }
 }
+
+// Bounds the number of names tried when every random name is taken, which can only
+// happen if the platform has no real source of randomness.
+const TEMP_ATTEMPTS: u32 = 1 << 10;
+
+/// Calls `create` with random paths inside `dir` until it succeeds or fails with an
+/// error other than `AlreadyExists`.
+pub fn create_temp_with<T, F>(dir: &Path, mut create: F) -> io::Result<T>
+where
+    F: FnMut(&Path) -> io::Result<T>,
+{
+    for _ in 0..TEMP_ATTEMPTS {
+        let (k0, k1) = crate::sys::hashmap_random_keys();
+        match create(&dir.join(format!(".tmp{:016x}", k0 ^ k1.rotate_left(32)))) {
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            res => return res,
+        }
+    }
+    Err(Error::new_const(ErrorKind::AlreadyExists, &"too many temporary files exist"))
+}
+
+pub fn create_temp(dir: &Path) -> io::Result<fs_imp::File> {
+    let mut opts = fs_imp::OpenOptions::new();
+    opts.read(true);
+    opts.write(true);
+    opts.create_new(true);
+    create_temp_with(dir, |path| {
+        let file = fs_imp::File::open(path, &opts)?;
+        fs_imp::unlink(path)?;
+        Ok(file)
+    })
+}
+
+pub fn create_temp_dir(dir: &Path) -> io::Result<PathBuf> {
+    create_temp_with(dir, |path| {
+        fs_imp::DirBuilder::new().mkdir(path)?;
+        Ok(path.to_path_buf())
+    })
+}
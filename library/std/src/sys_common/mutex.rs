@@ -1,4 +1,7 @@
+use crate::cmp;
 use crate::sys::mutex as imp;
+use crate::thread;
+use crate::time::{Duration, Instant};
 
 /// An OS-based mutual exclusion lock, meant for use in static variables.
 ///
@@ -83,6 +86,13 @@ pub fn try_lock(&self) -> bool {
         unsafe { self.0.try_lock() }
     }
 
+    /// Attempts to lock the mutex, blocking the current thread for at most
+    /// `dur`, returning whether it was successfully acquired or not.
+    #[inline]
+    pub fn try_lock_for(&self, dur: Duration) -> bool {
+        unsafe { self.0.try_lock_for(dur) }
+    }
+
     /// Unlocks the mutex.
     ///
     /// Behavior is undefined if the current thread does not actually hold the
@@ -98,3 +108,31 @@ fn drop(&mut self) {
         unsafe { self.0.destroy() };
     }
 }
+
+/// Repeatedly calls `try_lock` until it succeeds or `dur` has elapsed,
+/// sleeping for an increasing amount of time in between.
+///
+/// This is the implementation of `try_lock_for` for mutexes whose platform
+/// offers no way to block on a lock with a timeout.
+pub fn try_lock_with_backoff(mut try_lock: impl FnMut() -> bool, dur: Duration) -> bool {
+    if try_lock() {
+        return true;
+    }
+    // A deadline too far in the future to represent is never reached.
+    let deadline = Instant::now().checked_add(dur);
+    let mut backoff = Duration::from_micros(10);
+    loop {
+        let sleep = match deadline {
+            Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) if remaining > Duration::ZERO => cmp::min(backoff, remaining),
+                _ => return false,
+            },
+            None => backoff,
+        };
+        thread::sleep(sleep);
+        if try_lock() {
+            return true;
+        }
+        backoff = cmp::min(backoff * 2, Duration::from_millis(1));
+    }
+}
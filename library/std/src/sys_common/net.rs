@@ -3,11 +3,11 @@
 
 use crate::cmp;
 use crate::convert::{TryFrom, TryInto};
-use crate::ffi::CString;
+use crate::ffi::{CStr, CString};
 use crate::fmt;
 use crate::io::{self, Error, ErrorKind, IoSlice, IoSliceMut};
 use crate::mem;
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+use crate::net::{AddressFamily, IpAddr, Ipv4Addr, Ipv6Addr, LookupOptions, Shutdown, SocketAddr};
 use crate::ptr;
 use crate::sys::net::netc as c;
 use crate::sys::net::{cvt, cvt_gai, cvt_r, init, wrlen_t, Socket};
@@ -112,6 +112,37 @@ pub fn sockaddr_to_addr(storage: &c::sockaddr_storage, len: usize) -> io::Result
     }
 }
 
+/// A type with the same memory layout as `c::sockaddr`. Used in converting Rust level
+/// `SocketAddr*` types into their system representation. The benefit of this specific
+/// type over using `c::sockaddr_storage` is that this type is exactly as large as it
+/// needs to be and not a lot larger.
+#[repr(C)]
+pub(crate) union SocketAddrCRepr {
+    v4: c::sockaddr_in,
+    v6: c::sockaddr_in6,
+}
+
+impl SocketAddrCRepr {
+    pub fn as_ptr(&self) -> *const c::sockaddr {
+        self as *const _ as *const c::sockaddr
+    }
+}
+
+impl<'a> IntoInner<(SocketAddrCRepr, c::socklen_t)> for &'a SocketAddr {
+    fn into_inner(self) -> (SocketAddrCRepr, c::socklen_t) {
+        match *self {
+            SocketAddr::V4(ref a) => {
+                let sockaddr = SocketAddrCRepr { v4: a.into_inner() };
+                (sockaddr, mem::size_of::<c::sockaddr_in>() as c::socklen_t)
+            }
+            SocketAddr::V6(ref a) => {
+                let sockaddr = SocketAddrCRepr { v6: a.into_inner() };
+                (sockaddr, mem::size_of::<c::sockaddr_in6>() as c::socklen_t)
+            }
+        }
+    }
+}
+
 #[cfg(target_os = "android")]
 fn to_ipv6mr_interface(value: u32) -> c_int {
     value as c_int
@@ -136,6 +167,44 @@ impl LookupHost {
     pub fn port(&self) -> u16 {
         self.port
     }
+
+    pub fn with_options(host: &str, port: u16, opts: &LookupOptions) -> io::Result<LookupHost> {
+        init();
+
+        let c_host = CString::new(host)?;
+        let mut hints: c::addrinfo = unsafe { mem::zeroed() };
+        hints.ai_socktype = c::SOCK_STREAM;
+        hints.ai_family = match opts.family {
+            AddressFamily::Ipv4 => c::AF_INET,
+            AddressFamily::Ipv6 => c::AF_INET6,
+            AddressFamily::Any => c::AF_UNSPEC,
+        };
+        if opts.canonical_name {
+            hints.ai_flags |= c::AI_CANONNAME;
+        }
+        if opts.addr_config {
+            hints.ai_flags |= c::AI_ADDRCONFIG;
+        }
+        if opts.numeric_host {
+            hints.ai_flags |= c::AI_NUMERICHOST;
+        }
+        let mut res = ptr::null_mut();
+        unsafe {
+            cvt_gai(c::getaddrinfo(c_host.as_ptr(), ptr::null(), &hints, &mut res))
+                .map(|_| LookupHost { original: res, cur: res, port })
+        }
+    }
+
+    pub fn canonical_name(&self) -> Option<&str> {
+        // Only the first entry of the list carries the canonical name.
+        unsafe {
+            let first = self.original.as_ref()?;
+            if first.ai_canonname.is_null() {
+                return None;
+            }
+            CStr::from_ptr(first.ai_canonname).to_str().ok()
+        }
+    }
 }
 
 impl Iterator for LookupHost {
@@ -187,16 +256,31 @@ impl<'a> TryFrom<(&'a str, u16)> for LookupHost {
     type Error = io::Error;
 
     fn try_from((host, port): (&'a str, u16)) -> io::Result<LookupHost> {
-        init();
+        LookupHost::with_options(host, port, &LookupOptions::new())
+    }
+}
 
-        let c_host = CString::new(host)?;
-        let mut hints: c::addrinfo = unsafe { mem::zeroed() };
-        hints.ai_socktype = c::SOCK_STREAM;
-        let mut res = ptr::null_mut();
-        unsafe {
-            cvt_gai(c::getaddrinfo(c_host.as_ptr(), ptr::null(), &hints, &mut res))
-                .map(|_| LookupHost { original: res, cur: res, port })
-        }
+pub fn lookup_addr(addr: &IpAddr) -> io::Result<String> {
+    init();
+
+    // Large enough for any host name, see `NI_MAXHOST`.
+    let mut host = [0 as libc::c_char; 1025];
+    let sockaddr = SocketAddr::new(*addr, 0);
+    let (addr, len) = sockaddr.into_inner();
+    unsafe {
+        cvt_gai(c::getnameinfo(
+            addr.as_ptr(),
+            len,
+            host.as_mut_ptr(),
+            host.len() as _,
+            ptr::null_mut(),
+            0,
+            c::NI_NAMEREQD,
+        ))?;
+        let name = CStr::from_ptr(host.as_ptr());
+        name.to_str()
+            .map(|name| name.to_owned())
+            .map_err(|_| Error::new_const(ErrorKind::InvalidData, &"host name is not valid UTF-8"))
     }
 }
 
@@ -216,8 +300,8 @@ pub fn connect(addr: io::Result<&SocketAddr>) -> io::Result<TcpStream> {
 
         let sock = Socket::new(addr, c::SOCK_STREAM)?;
 
-        let (addrp, len) = addr.into_inner();
-        cvt_r(|| unsafe { c::connect(*sock.as_inner(), addrp, len) })?;
+        let (addr, len) = addr.into_inner();
+        cvt_r(|| unsafe { c::connect(*sock.as_inner(), addr.as_ptr(), len) })?;
         Ok(TcpStream { inner: sock })
     }
 
@@ -379,8 +463,8 @@ pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
         setsockopt(&sock, c::SOL_SOCKET, c::SO_REUSEADDR, 1 as c_int)?;
 
         // Bind our new socket
-        let (addrp, len) = addr.into_inner();
-        cvt(unsafe { c::bind(*sock.as_inner(), addrp, len as _) })?;
+        let (addr, len) = addr.into_inner();
+        cvt(unsafe { c::bind(*sock.as_inner(), addr.as_ptr(), len as _) })?;
 
         // Start listening
         cvt(unsafe { c::listen(*sock.as_inner(), 128) })?;
@@ -472,8 +556,8 @@ pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
         init();
 
         let sock = Socket::new(addr, c::SOCK_DGRAM)?;
-        let (addrp, len) = addr.into_inner();
-        cvt(unsafe { c::bind(*sock.as_inner(), addrp, len as _) })?;
+        let (addr, len) = addr.into_inner();
+        cvt(unsafe { c::bind(*sock.as_inner(), addr.as_ptr(), len as _) })?;
         Ok(UdpSocket { inner: sock })
     }
 
@@ -503,14 +587,14 @@ pub fn peek_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
 
     pub fn send_to(&self, buf: &[u8], dst: &SocketAddr) -> io::Result<usize> {
         let len = cmp::min(buf.len(), <wrlen_t>::MAX as usize) as wrlen_t;
-        let (dstp, dstlen) = dst.into_inner();
+        let (dst, dstlen) = dst.into_inner();
         let ret = cvt(unsafe {
             c::sendto(
                 *self.inner.as_inner(),
                 buf.as_ptr() as *const c_void,
                 len,
                 MSG_NOSIGNAL,
-                dstp,
+                dst.as_ptr(),
                 dstlen,
             )
         })?;
@@ -593,7 +677,7 @@ pub fn join_multicast_v4(&self, multiaddr: &Ipv4Addr, interface: &Ipv4Addr) -> i
 
     pub fn join_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> io::Result<()> {
         let mreq = c::ipv6_mreq {
-            ipv6mr_multiaddr: *multiaddr.as_inner(),
+            ipv6mr_multiaddr: multiaddr.into_inner(),
             ipv6mr_interface: to_ipv6mr_interface(interface),
         };
         setsockopt(&self.inner, c::IPPROTO_IPV6, IPV6_ADD_MEMBERSHIP, mreq)
@@ -609,7 +693,7 @@ pub fn leave_multicast_v4(&self, multiaddr: &Ipv4Addr, interface: &Ipv4Addr) ->
 
     pub fn leave_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> io::Result<()> {
         let mreq = c::ipv6_mreq {
-            ipv6mr_multiaddr: *multiaddr.as_inner(),
+            ipv6mr_multiaddr: multiaddr.into_inner(),
             ipv6mr_interface: to_ipv6mr_interface(interface),
         };
         setsockopt(&self.inner, c::IPPROTO_IPV6, IPV6_DROP_MEMBERSHIP, mreq)
@@ -649,8 +733,8 @@ pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
     }
 
     pub fn connect(&self, addr: io::Result<&SocketAddr>) -> io::Result<()> {
-        let (addrp, len) = addr?.into_inner();
-        cvt_r(|| unsafe { c::connect(*self.inner.as_inner(), addrp, len) }).map(drop)
+        let (addr, len) = addr?.into_inner();
+        cvt_r(|| unsafe { c::connect(*self.inner.as_inner(), addr.as_ptr(), len) }).map(drop)
     }
 }
 
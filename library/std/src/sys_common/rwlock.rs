@@ -1,4 +1,8 @@
+use crate::mem;
+use crate::ptr;
+use crate::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
 use crate::sys::rwlock as imp;
+use crate::sys_common::mutex::MovableMutex;
 
 /// An OS-based reader-writer lock, meant for use in static variables.
 ///
@@ -69,19 +73,49 @@ fn drop(&mut self) {
 /// This is either a wrapper around `Box<imp::RWLock>` or `imp::RWLock`,
 /// depending on the platform. It is boxed on platforms where `imp::RWLock` may
 /// not be moved.
-pub struct MovableRWLock(imp::MovableRWLock);
+///
+/// Besides shared and exclusive access, this lock supports upgradable shared
+/// access, which can be atomically turned into exclusive access, and
+/// downgrading exclusive access. The platform locks support neither, so
+/// switching between the two modes releases the platform lock and acquires it
+/// again. Two things keep writers from getting in between:
+///
+/// - An additional mutex is held by the (single) upgradable reader, by a
+///   writer that came from upgrading until it gives up its access, and by a
+///   writer while it downgrades.
+/// - A count of the threads in the middle of such a switch. A writer that
+///   acquires the platform lock while it is non-zero releases it again and
+///   waits for the mutex before retrying.
+///
+/// Plain readers and writers never touch the mutex, so they only pay for
+/// reading the count. The mutex is allocated the first time it is needed, so
+/// locks that are never upgraded or downgraded don't pay for it either.
+pub struct MovableRWLock {
+    inner: imp::MovableRWLock,
+    // Null until first used, see `upgrade_mutex`.
+    upgrade: AtomicPtr<MovableMutex>,
+    transitions: AtomicUsize,
+    // Whether the current writer holds `upgrade`. Only accessed by the thread
+    // holding exclusive access.
+    writer_holds_upgrade: AtomicBool,
+}
 
 impl MovableRWLock {
     /// Creates a new reader-writer lock for use.
     pub fn new() -> Self {
-        Self(imp::MovableRWLock::from(imp::RWLock::new()))
+        Self {
+            inner: imp::MovableRWLock::from(imp::RWLock::new()),
+            upgrade: AtomicPtr::new(ptr::null_mut()),
+            transitions: AtomicUsize::new(0),
+            writer_holds_upgrade: AtomicBool::new(false),
+        }
     }
 
     /// Acquires shared access to the underlying lock, blocking the current
     /// thread to do so.
     #[inline]
     pub fn read(&self) {
-        unsafe { self.0.read() }
+        unsafe { self.inner.read() }
     }
 
     /// Attempts to acquire shared access to this lock, returning whether it
@@ -90,14 +124,66 @@ pub fn read(&self) {
     /// This function does not block the current thread.
     #[inline]
     pub fn try_read(&self) -> bool {
-        unsafe { self.0.try_read() }
+        unsafe { self.inner.try_read() }
+    }
+
+    /// Acquires upgradable shared access to the underlying lock, blocking the
+    /// current thread to do so.
+    ///
+    /// At most one thread has upgradable access at a time; it excludes writers
+    /// but not other readers.
+    pub fn upgradable_read(&self) {
+        loop {
+            let upgrade = UpgradeGuard::lock(self.upgrade_mutex());
+            if unsafe { self.inner.try_read() } {
+                mem::forget(upgrade);
+                return;
+            }
+            // Wait for the writer without holding `upgrade`, as it may need it
+            // to downgrade into an upgradable reader.
+            drop(upgrade);
+            unsafe {
+                self.inner.read();
+                self.inner.read_unlock();
+            }
+        }
+    }
+
+    /// Attempts to acquire upgradable shared access to this lock, returning
+    /// whether it succeeded or not.
+    ///
+    /// This function does not block the current thread.
+    #[inline]
+    pub fn try_upgradable_read(&self) -> bool {
+        let mutex = self.upgrade_mutex();
+        if !mutex.try_lock() {
+            return false;
+        }
+        let upgrade = UpgradeGuard(mutex);
+        if unsafe { self.inner.try_read() } {
+            mem::forget(upgrade);
+            true
+        } else {
+            false
+        }
     }
 
     /// Acquires write access to the underlying lock, blocking the current thread
     /// to do so.
     #[inline]
     pub fn write(&self) {
-        unsafe { self.0.write() }
+        loop {
+            unsafe { self.inner.write() };
+            // The platform lock orders this with the increment made before
+            // the switching thread released it.
+            if self.transitions.load(Ordering::Relaxed) == 0 {
+                return;
+            }
+            unsafe { self.inner.write_unlock() };
+            // The switching thread holds the mutex, so this blocks until it
+            // is done.
+            drop(UpgradeGuard::lock(self.upgrade_mutex()));
+        }
     }
 
     /// Attempts to acquire exclusive access to this lock, returning whether it
@@ -106,7 +192,88 @@ pub fn write(&self) {
     /// This function does not block the current thread.
     #[inline]
     pub fn try_write(&self) -> bool {
-        unsafe { self.0.try_write() }
+        if !unsafe { self.inner.try_write() } {
+            return false;
+        }
+        if self.transitions.load(Ordering::Relaxed) == 0 {
+            true
+        } else {
+            unsafe { self.inner.write_unlock() };
+            false
+        }
+    }
+
+    /// Turns previously acquired upgradable shared access into exclusive
+    /// access, blocking the current thread until all other readers are gone.
+    ///
+    /// Behavior is undefined if the current thread does not have upgradable
+    /// shared access.
+    pub unsafe fn upgrade(&self) {
+        let upgrade = UpgradeGuard(self.upgrade_mutex());
+        let _transition = Transition::start(&self.transitions);
+        self.inner.read_unlock();
+        self.inner.write();
+        self.writer_holds_upgrade.store(true, Ordering::Relaxed);
+        mem::forget(upgrade);
+    }
+
+    /// Attempts to turn previously acquired upgradable shared access into
+    /// exclusive access, returning whether it succeeded or not. On failure,
+    /// upgradable shared access is retained.
+    ///
+    /// Behavior is undefined if the current thread does not have upgradable
+    /// shared access.
+    pub unsafe fn try_upgrade(&self) -> bool {
+        let upgrade = UpgradeGuard(self.upgrade_mutex());
+        let _transition = Transition::start(&self.transitions);
+        self.inner.read_unlock();
+        let upgraded = self.inner.try_write();
+        if upgraded {
+            self.writer_holds_upgrade.store(true, Ordering::Relaxed);
+        } else {
+            // Other readers are active. Writers that get the lock before we
+            // do back off again, so this returns promptly.
+            self.inner.read();
+        }
+        mem::forget(upgrade);
+        upgraded
+    }
+
+    /// Turns previously acquired exclusive access into shared access.
+    ///
+    /// Behavior is undefined if the current thread does not currently have
+    /// exclusive access.
+    pub unsafe fn downgrade(&self) {
+        // Hold `upgrade` while switching, so that writers backing off block
+        // on it instead of spinning.
+        let upgrade = self.lock_writer_upgrade();
+        let _transition = Transition::start(&self.transitions);
+        self.inner.write_unlock();
+        self.inner.read();
+        drop(upgrade);
+    }
+
+    /// Turns previously acquired exclusive access into upgradable shared
+    /// access.
+    ///
+    /// Behavior is undefined if the current thread does not currently have
+    /// exclusive access.
+    pub unsafe fn downgrade_to_upgradable(&self) {
+        let upgrade = self.lock_writer_upgrade();
+        let _transition = Transition::start(&self.transitions);
+        self.inner.write_unlock();
+        self.inner.read();
+        mem::forget(upgrade);
+    }
+
+    /// Turns previously acquired upgradable shared access into plain shared
+    /// access.
+    ///
+    /// Behavior is undefined if the current thread does not have upgradable
+    /// shared access.
+    #[inline]
+    pub unsafe fn downgrade_upgradable(&self) {
+        self.upgrade_mutex().raw_unlock();
     }
 
     /// Unlocks previously acquired shared access to this lock.
@@ -114,7 +281,17 @@ pub fn try_write(&self) -> bool {
     /// Behavior is undefined if the current thread does not have shared access.
     #[inline]
     pub unsafe fn read_unlock(&self) {
-        self.0.read_unlock()
+        self.inner.read_unlock()
+    }
+
+    /// Unlocks previously acquired upgradable shared access to this lock.
+    ///
+    /// Behavior is undefined if the current thread does not have upgradable
+    /// shared access.
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        let _upgrade = UpgradeGuard(self.upgrade_mutex());
+        self.inner.read_unlock();
     }
 
     /// Unlocks previously acquired exclusive access to this lock.
@@ -123,12 +300,104 @@ pub unsafe fn read_unlock(&self) {
     /// exclusive access.
     #[inline]
     pub unsafe fn write_unlock(&self) {
-        self.0.write_unlock()
+        let _upgrade = self.take_writer_upgrade();
+        self.inner.write_unlock();
+    }
+
+    /// Takes over `upgrade` if the current writer holds it, i.e. came from
+    /// upgrading.
+    ///
+    /// Behavior is undefined if the current thread does not currently have
+    /// exclusive access.
+    #[inline]
+    unsafe fn take_writer_upgrade(&self) -> Option<UpgradeGuard<'_>> {
+        if self.writer_holds_upgrade.load(Ordering::Relaxed) {
+            self.writer_holds_upgrade.store(false, Ordering::Relaxed);
+            Some(UpgradeGuard(self.upgrade_mutex()))
+        } else {
+            None
+        }
+    }
+
+    /// Takes over `upgrade` from the current writer, or locks it if the writer
+    /// doesn't hold it.
+    ///
+    /// Behavior is undefined if the current thread does not currently have
+    /// exclusive access.
+    unsafe fn lock_writer_upgrade(&self) -> UpgradeGuard<'_> {
+        // Anyone else holding `upgrade` does so only briefly, as there can be
+        // no upgradable reader while we have exclusive access.
+        match self.take_writer_upgrade() {
+            Some(upgrade) => upgrade,
+            None => UpgradeGuard::lock(self.upgrade_mutex()),
+        }
+    }
+
+    /// Returns the `upgrade` mutex, allocating it on first use.
+    fn upgrade_mutex(&self) -> &MovableMutex {
+        let mutex = self.upgrade.load(Ordering::Acquire);
+        if !mutex.is_null() {
+            return unsafe { &*mutex };
+        }
+        let new = Box::into_raw(Box::new(MovableMutex::new()));
+        match self.upgrade.compare_exchange(
+            ptr::null_mut(),
+            new,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => unsafe { &*new },
+            // Another thread beat us to it, use its mutex instead.
+            Err(mutex) => unsafe {
+                drop(Box::from_raw(new));
+                &*mutex
+            },
+        }
     }
 }
 
 impl Drop for MovableRWLock {
     fn drop(&mut self) {
-        unsafe { self.0.destroy() };
+        unsafe { self.inner.destroy() };
+        let mutex = *self.upgrade.get_mut();
+        if !mutex.is_null() {
+            drop(unsafe { Box::from_raw(mutex) });
+        }
+    }
+}
+
+/// Unlocks the `upgrade` mutex of a [`MovableRWLock`] when dropped, so that a
+/// panic in the platform lock cannot leave it locked.
+struct UpgradeGuard<'a>(&'a MovableMutex);
+
+impl<'a> UpgradeGuard<'a> {
+    fn lock(mutex: &'a MovableMutex) -> Self {
+        mutex.raw_lock();
+        Self(mutex)
+    }
+}
+
+impl Drop for UpgradeGuard<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { self.0.raw_unlock() };
+    }
+}
+
+/// Counts a thread switching between shared and exclusive access for as long
+/// as it is alive.
+struct Transition<'a>(&'a AtomicUsize);
+
+impl<'a> Transition<'a> {
+    fn start(transitions: &'a AtomicUsize) -> Self {
+        transitions.fetch_add(1, Ordering::Relaxed);
+        Self(transitions)
+    }
+}
+
+impl Drop for Transition<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
     }
 }
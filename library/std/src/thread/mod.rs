@@ -160,6 +160,7 @@
 use crate::panicking;
 use crate::str;
 use crate::sync::Arc;
+use crate::sync::mpsc;
 use crate::sys::thread as imp;
 use crate::sys_common::mutex;
 use crate::sys_common::thread;
@@ -259,6 +260,10 @@ pub struct Builder {
     name: Option<String>,
     // The size of the stack for the spawned thread in bytes
     stack_size: Option<usize>,
+    // The CPUs the spawned thread is allowed to run on
+    affinity: Option<Vec<usize>>,
+    // The scheduling priority of the spawned thread
+    priority: Option<Priority>,
 }
 
 impl Builder {
@@ -282,7 +287,7 @@ impl Builder {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn new() -> Builder {
-        Builder { name: None, stack_size: None }
+        Builder { name: None, stack_size: None, affinity: None, priority: None }
     }
 
     /// Names the thread-to-be. Currently the name is used for identification
@@ -338,6 +343,87 @@ pub fn stack_size(mut self, size: usize) -> Builder {
         self
     }
 
+    /// Restricts the thread-to-be to run only on the given CPUs.
+    ///
+    /// CPUs are identified by their zero-based index, as used by the
+    /// operating system. The affinity is applied by the new thread itself
+    /// before it runs any user code; if that fails, [`spawn`] returns the
+    /// error and the closure is never called.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This currently corresponds to `sched_setaffinity` on Linux and Android
+    /// and to `SetThreadAffinityMask` on Windows, where only the CPUs of the
+    /// thread's processor group (at most 64) can be selected. Spawning fails
+    /// with [`io::ErrorKind::Unsupported`] on other platforms. **Note that this
+    /// [may change in the future][changes]**.
+    ///
+    /// [`spawn`]: Builder::spawn
+    /// [changes]: crate::io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(thread_scheduling)]
+    /// use std::thread;
+    ///
+    /// let handler = thread::Builder::new()
+    ///     .affinity(&[0, 1])
+    ///     .spawn(|| {
+    ///         // thread code, only ever run on the first two CPUs
+    ///     })
+    ///     .unwrap();
+    ///
+    /// handler.join().unwrap();
+    /// ```
+    #[unstable(feature = "thread_scheduling", issue = "none")]
+    pub fn affinity(mut self, cpus: &[usize]) -> Builder {
+        self.affinity = Some(cpus.to_vec());
+        self
+    }
+
+    /// Sets the scheduling priority of the thread-to-be.
+    ///
+    /// Like [`affinity`], the priority is applied by the new thread before it
+    /// runs any user code, and a failure to apply it is returned by
+    /// [`spawn`]. Raising the priority above [`Priority::Normal`] usually
+    /// requires elevated privileges.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This currently adjusts the thread's nice value on Linux and Android,
+    /// relative to the one it inherits, so [`Priority::Normal`] keeps the
+    /// inherited value. It uses `pthread_setschedparam` with `SCHED_OTHER` on macOS, iOS, FreeBSD
+    /// and DragonFly BSD, and `SetThreadPriority` on Windows. Spawning fails
+    /// with [`io::ErrorKind::Unsupported`] on other platforms. **Note that this
+    /// [may change in the future][changes]**.
+    ///
+    /// [`affinity`]: Builder::affinity
+    /// [`spawn`]: Builder::spawn
+    /// [changes]: crate::io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(thread_scheduling)]
+    /// use std::thread::{self, Priority};
+    ///
+    /// let handler = thread::Builder::new()
+    ///     .name("compactor".into())
+    ///     .priority(Priority::Lowest)
+    ///     .spawn(|| {
+    ///         // background work
+    ///     })
+    ///     .unwrap();
+    ///
+    /// handler.join().unwrap();
+    /// ```
+    #[unstable(feature = "thread_scheduling", issue = "none")]
+    pub fn priority(mut self, priority: Priority) -> Builder {
+        self.priority = Some(priority);
+        self
+    }
+
     /// Spawns a new thread by taking ownership of the `Builder`, and returns an
     /// [`io::Result`] to its [`JoinHandle`].
     ///
@@ -356,6 +442,12 @@ pub fn stack_size(mut self, size: usize) -> Builder {
     ///
     /// [`io::Result`]: crate::io::Result
     ///
+    /// It also fails if the [affinity] or [priority] requested for the thread
+    /// could not be applied.
+    ///
+    /// [affinity]: Builder::affinity
+    /// [priority]: Builder::priority
+    ///
     /// # Panics
     ///
     /// Panics if a thread name was set and it contained null bytes.
@@ -448,7 +540,7 @@ pub unsafe fn spawn_unchecked<'a, F, T>(self, f: F) -> io::Result<JoinHandle<T>>
         F: Send + 'a,
         T: Send + 'a,
     {
-        let Builder { name, stack_size } = self;
+        let Builder { name, stack_size, affinity, priority } = self;
 
         let stack_size = stack_size.unwrap_or_else(thread::min_stack);
 
@@ -461,11 +553,37 @@ pub unsafe fn spawn_unchecked<'a, F, T>(self, f: F) -> io::Result<JoinHandle<T>>
         let output_capture = crate::io::set_output_capture(None);
         crate::io::set_output_capture(output_capture.clone());
 
+        // The scheduling options are applied by the new thread itself, which
+        // reports the outcome back before running any user code.
+        let (scheduled_tx, scheduled_rx) = if affinity.is_some() || priority.is_some() {
+            let (tx, rx) = mpsc::sync_channel(1);
+            (Some(tx), Some(rx))
+        } else {
+            (None, None)
+        };
+
         let main = move || {
             if let Some(name) = their_thread.cname() {
                 imp::Thread::set_name(name);
             }
 
+            if let Some(scheduled_tx) = scheduled_tx {
+                let result = (|| -> io::Result<()> {
+                    if let Some(cpus) = affinity {
+                        imp::Thread::set_affinity(&cpus)?;
+                    }
+                    if let Some(priority) = priority {
+                        imp::Thread::set_priority(priority)?;
+                    }
+                    Ok(())
+                })();
+                let failed = result.is_err();
+                let _ = scheduled_tx.send(result);
+                if failed {
+                    return;
+                }
+            }
+
             crate::io::set_output_capture(output_capture);
 
             // SAFETY: the stack guard passed is the one for the current thread.
@@ -482,34 +600,67 @@ pub unsafe fn spawn_unchecked<'a, F, T>(self, f: F) -> io::Result<JoinHandle<T>>
             unsafe { *their_packet.get() = Some(try_result) };
         };
 
+        // SAFETY:
+        //
+        // `imp::Thread::new` takes a closure with a `'static` lifetime, since it's passed
+        // through FFI or otherwise used with low-level threading primitives that have no
+        // notion of or way to enforce lifetimes.
+        //
+        // As mentioned in the `Safety` section of this function's documentation, the caller of
+        // this function needs to guarantee that the passed-in lifetime is sufficiently long
+        // for the lifetime of the thread.
+        //
+        // Similarly, the `sys` implementation must guarantee that no references to the closure
+        // exist after the thread has terminated, which is signaled by `Thread::join`
+        // returning.
+        let native = unsafe {
+            imp::Thread::new(
+                stack_size,
+                mem::transmute::<Box<dyn FnOnce() + 'a>, Box<dyn FnOnce() + 'static>>(Box::new(
+                    main,
+                )),
+            )?
+        };
+
+        if let Some(scheduled_rx) = scheduled_rx {
+            // The thread returns without calling `f` if it fails to apply its
+            // scheduling options, so it only needs to be reaped.
+            if let Ok(Err(e)) = scheduled_rx.recv() {
+                native.join();
+                return Err(e);
+            }
+        }
+
         Ok(JoinHandle(JoinInner {
-            // SAFETY:
-            //
-            // `imp::Thread::new` takes a closure with a `'static` lifetime, since it's passed
-            // through FFI or otherwise used with low-level threading primitives that have no
-            // notion of or way to enforce lifetimes.
-            //
-            // As mentioned in the `Safety` section of this function's documentation, the caller of
-            // this function needs to guarantee that the passed-in lifetime is sufficiently long
-            // for the lifetime of the thread.
-            //
-            // Similarly, the `sys` implementation must guarantee that no references to the closure
-            // exist after the thread has terminated, which is signaled by `Thread::join`
-            // returning.
-            native: unsafe {
-                Some(imp::Thread::new(
-                    stack_size,
-                    mem::transmute::<Box<dyn FnOnce() + 'a>, Box<dyn FnOnce() + 'static>>(
-                        Box::new(main),
-                    ),
-                )?)
-            },
+            native: Some(native),
             thread: my_thread,
             packet: Packet(my_packet),
         }))
     }
 }
 
+/// A portable scheduling priority class for a thread.
+///
+/// Used with [`Builder::priority`]. Each class is mapped to the closest
+/// native priority of the platform; see [`Builder::priority`] for details.
+#[unstable(feature = "thread_scheduling", issue = "none")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Priority {
+    /// The lowest priority, for work that should only run when nothing else
+    /// wants the CPU.
+    Lowest,
+    /// A priority below normal, for background work.
+    BelowNormal,
+    /// The priority threads get by default.
+    Normal,
+    /// A priority above normal, for latency-sensitive work.
+    AboveNormal,
+    /// The highest priority available without switching to a real-time
+    /// scheduling policy.
+    Highest,
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Free functions
 ////////////////////////////////////////////////////////////////////////////////
@@ -1,4 +1,4 @@
-use super::Builder;
+use super::{Builder, Priority};
 use crate::any::Any;
 use crate::mem;
 use crate::result;
@@ -37,6 +37,40 @@ fn test_invalid_named_thread() {
     let _ = Builder::new().name("ada l\0velace".to_string()).spawn(|| {});
 }
 
+#[test]
+#[cfg(any(windows, target_os = "linux", target_os = "android", target_os = "macos"))]
+fn test_normal_priority_thread() {
+    Builder::new().priority(Priority::Normal).spawn(|| {}).unwrap().join().unwrap();
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn test_priority_relative_to_inherited_nice() {
+    fn nice() -> i32 {
+        unsafe {
+            let tid = libc::syscall(libc::SYS_gettid);
+            20 - libc::syscall(libc::SYS_getpriority, libc::PRIO_PROCESS as libc::c_int, tid) as i32
+        }
+    }
+
+    let inherited = nice();
+    let normal = Builder::new().priority(Priority::Normal).spawn(nice).unwrap().join().unwrap();
+    assert_eq!(normal, inherited);
+    let below = Builder::new().priority(Priority::BelowNormal).spawn(nice).unwrap().join().unwrap();
+    assert_eq!(below, crate::cmp::min(inherited + 10, 19));
+}
+
+#[test]
+#[cfg(any(windows, target_os = "linux", target_os = "android"))]
+fn test_invalid_affinity_thread() {
+    let (tx, rx) = channel();
+    let err =
+        Builder::new().affinity(&[usize::MAX]).spawn(move || tx.send(()).unwrap()).unwrap_err();
+    assert_eq!(err.kind(), crate::io::ErrorKind::InvalidInput);
+    // The closure must not have run, and has been dropped with the thread.
+    assert!(rx.recv().is_err());
+}
+
 #[test]
 fn test_run_basic() {
     let (tx, rx) = channel();
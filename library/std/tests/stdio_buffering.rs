@@ -0,0 +1,25 @@
+//! The buffer mode of stdout is global to the process, so this is tested in a
+//! test binary of its own rather than next to the other stdio tests, which
+//! run in parallel with it.
+
+#![feature(is_terminal, stdio_buffering)]
+
+use std::io::{self, BufferMode, IsTerminal};
+
+#[test]
+fn stdout_buffer_mode_roundtrip() {
+    let stdout = io::stdout();
+    let default = stdout.buffer_mode();
+    if stdout.is_terminal() {
+        assert_eq!(default, BufferMode::Line);
+    } else if cfg!(any(unix, windows)) {
+        assert!(matches!(default, BufferMode::Block(_)));
+    }
+
+    stdout.set_buffer_mode(BufferMode::Block(4096)).unwrap();
+    assert_eq!(stdout.buffer_mode(), BufferMode::Block(4096));
+    stdout.set_buffer_mode(BufferMode::Line).unwrap();
+    assert_eq!(stdout.buffer_mode(), BufferMode::Line);
+    stdout.set_buffer_mode(default).unwrap();
+    assert_eq!(stdout.buffer_mode(), default);
+}
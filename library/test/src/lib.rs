@@ -20,12 +20,12 @@
 #![crate_name = "test"]
 #![unstable(feature = "test", issue = "50297")]
 #![doc(test(attr(deny(warnings))))]
-#![cfg_attr(unix, feature(libc))]
 #![feature(rustc_private)]
 #![feature(nll)]
 #![feature(available_concurrency)]
 #![feature(bench_black_box)]
 #![feature(internal_output_capture)]
+#![feature(is_terminal)]
 #![feature(panic_unwind)]
 #![feature(staged_api)]
 #![feature(termination_trait_lib)]
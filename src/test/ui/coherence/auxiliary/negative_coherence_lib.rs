@@ -0,0 +1,6 @@
+#![feature(negative_impls)]
+
+pub trait Remote {}
+
+impl !Remote for u32 {}
+impl<'a> !Remote for &'a str {}
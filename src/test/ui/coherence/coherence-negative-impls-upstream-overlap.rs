@@ -0,0 +1,22 @@
+// aux-build:negative_coherence_lib.rs
+
+// Negative coherence only helps for types with a negative impl, and only when
+// the obligation names a single type: a generic impl still overlaps.
+
+#![feature(with_negative_coherence)]
+
+extern crate negative_coherence_lib;
+
+use negative_coherence_lib::Remote;
+
+trait LocalRemote {}
+
+impl<T: Remote> LocalRemote for T {}
+
+impl LocalRemote for i16 {}
+//~^ ERROR conflicting implementations of trait `LocalRemote` for type `i16`
+
+impl<'a, T> LocalRemote for &'a [T] {}
+//~^ ERROR conflicting implementations of trait `LocalRemote` for type `&[_]`
+
+fn main() {}
@@ -0,0 +1,29 @@
+// check-pass
+// aux-build:negative_coherence_lib.rs
+
+// With `with_negative_coherence`, an upstream negative impl is a promise that
+// the trait will never be implemented for that type, so these impls don't
+// overlap.
+
+#![feature(with_negative_coherence)]
+
+extern crate negative_coherence_lib;
+
+use negative_coherence_lib::Remote;
+use std::error::Error;
+
+trait LocalRemote {}
+
+impl<T: Remote> LocalRemote for T {}
+
+impl LocalRemote for u32 {}
+
+impl<'a> LocalRemote for &'a str {}
+
+trait LocalError {}
+
+impl<E: Error> LocalError for E {}
+
+impl<'a> LocalError for &'a str {}
+
+fn main() {}
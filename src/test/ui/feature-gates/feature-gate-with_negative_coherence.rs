@@ -0,0 +1,13 @@
+// Without `with_negative_coherence`, coherence ignores `impl !Error for &str`
+// and assumes that upstream crates may still implement `Error` for `&str`.
+
+use std::error::Error;
+
+trait MyTrait {}
+
+impl<E: Error> MyTrait for E {}
+
+impl<'a> MyTrait for &'a str {}
+//~^ ERROR conflicting implementations of trait `MyTrait` for type `&str`
+
+fn main() {}
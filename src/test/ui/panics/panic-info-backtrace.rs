@@ -0,0 +1,78 @@
+// run-pass
+// ignore-emscripten no processes
+// ignore-sgx no processes
+
+#![feature(backtrace, panic_info_ext, panic_update_hook)]
+
+use std::backtrace::Backtrace;
+use std::env;
+use std::panic::{self, PanicInfo};
+use std::process::Command;
+
+fn report(info: &PanicInfo<'_>) {
+    let backtrace = info.backtrace().map_or(false, |b| b.is::<Backtrace>());
+    eprintln!("hook: nested={} backtrace={}", info.is_nested(), backtrace);
+}
+
+struct PanicOnDrop;
+
+impl Drop for PanicOnDrop {
+    fn drop(&mut self) {
+        panic!("second");
+    }
+}
+
+fn run(mode: &str, rust_backtrace: &str) -> (bool, String) {
+    let output = Command::new(env::current_exe().unwrap())
+        .arg(mode)
+        .env("RUST_BACKTRACE", rust_backtrace)
+        .output()
+        .unwrap();
+    (output.status.success(), String::from_utf8(output.stderr).unwrap())
+}
+
+fn main() {
+    match env::args().nth(1).as_deref() {
+        Some("custom") => {
+            panic::set_hook(Box::new(report));
+            let _ = panic::catch_unwind(|| panic!("first"));
+            return;
+        }
+        Some("nested") => {
+            panic::set_hook(Box::new(report));
+            let _guard = PanicOnDrop;
+            panic!("first");
+        }
+        Some("chained") => {
+            panic::update_hook(|prev, info| {
+                report(info);
+                prev(info);
+            });
+            let _ = panic::catch_unwind(|| panic!("first"));
+            return;
+        }
+        Some(mode) => unreachable!("{}", mode),
+        None => {}
+    }
+
+    // A custom hook is given a backtrace if backtraces are enabled...
+    let (ok, stderr) = run("custom", "0");
+    assert!(ok, "{}", stderr);
+    assert!(stderr.contains("hook: nested=false backtrace=false\n"), "{}", stderr);
+    let (ok, stderr) = run("custom", "1");
+    assert!(ok, "{}", stderr);
+    assert!(stderr.contains("hook: nested=false backtrace=true\n"), "{}", stderr);
+
+    // ...or if the panic is nested, which then aborts.
+    let (ok, stderr) = run("nested", "0");
+    assert!(!ok, "{}", stderr);
+    assert!(stderr.contains("hook: nested=false backtrace=false\n"), "{}", stderr);
+    assert!(stderr.contains("hook: nested=true backtrace=true\n"), "{}", stderr);
+
+    // The default hook gets the same `PanicInfo` when a custom hook forwards
+    // to it, backtrace included.
+    let (ok, stderr) = run("chained", "1");
+    assert!(ok, "{}", stderr);
+    assert!(stderr.contains("hook: nested=false backtrace=true\n"), "{}", stderr);
+    assert!(stderr.contains("panicked at 'first'"), "{}", stderr);
+}
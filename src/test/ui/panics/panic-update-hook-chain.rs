@@ -0,0 +1,51 @@
+// run-pass
+// ignore-emscripten no threads support
+
+#![feature(panic_info_ext, panic_update_hook)]
+
+use std::panic;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+fn panic_in_thread(msg: &'static str) {
+    let worker = thread::Builder::new().name("worker".to_string());
+    let _ = worker.spawn(move || panic!("{}", msg)).unwrap().join();
+}
+
+fn main() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let c = calls.clone();
+    panic::set_hook(Box::new(move |info| {
+        let msg = info.payload().downcast_ref::<String>().unwrap();
+        c.lock().unwrap().push(format!("inner {}", msg));
+    }));
+
+    // Each hook wraps the previous one, so the last one installed runs first.
+    let c = calls.clone();
+    panic::update_hook(move |prev, info| {
+        c.lock().unwrap().push("outer before".to_string());
+        prev(info);
+        c.lock().unwrap().push("outer after".to_string());
+    });
+    let c = calls.clone();
+    panic::update_hook(move |prev, info| {
+        if info.payload().downcast_ref::<String>().unwrap() == "skipped" {
+            c.lock().unwrap().push(format!("skipped on {}", info.thread_name().unwrap()));
+        } else {
+            prev(info);
+        }
+    });
+
+    panic_in_thread("first");
+    panic_in_thread("skipped");
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        ["outer before", "inner first", "outer after", "skipped on worker"]
+    );
+
+    // Taking the hook hands out the whole chain.
+    drop(panic::take_hook());
+    assert_eq!(Arc::strong_count(&calls), 1);
+}
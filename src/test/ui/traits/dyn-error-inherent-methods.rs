@@ -0,0 +1,41 @@
+// run-pass
+
+// `Error` is defined in libcore, but liballoc and libstd add inherent methods
+// to `dyn Error` and its `Send`/`Sync` variants through lang items. Check that
+// method probing finds all of them.
+
+#![feature(backtrace)]
+
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+struct MyError;
+
+impl fmt::Display for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("my error")
+    }
+}
+
+impl Error for MyError {}
+
+fn main() {
+    // Defined in libcore.
+    let e: Box<dyn Error + Send + Sync> = Box::new(MyError);
+    assert!(e.is::<MyError>());
+    assert!(e.downcast_ref::<MyError>().is_some());
+    // Defined in libstd.
+    assert!(e.backtrace().is_none());
+    // Defined in liballoc.
+    assert!(e.downcast::<MyError>().is_ok());
+
+    let e: Box<dyn Error + Send> = Box::new(MyError);
+    assert!(e.backtrace().is_none());
+    assert!(e.downcast::<fmt::Error>().is_err());
+
+    let e: Box<dyn Error> = Box::new(MyError);
+    assert!(e.backtrace().is_none());
+    let e: Box<MyError> = e.downcast().unwrap();
+    assert_eq!(e.to_string(), "my error");
+}